@@ -55,10 +55,8 @@ fn bench_sequential_read_sum(c: &mut Criterion) {
             |b, data| {
                 b.iter(|| {
                     let mut sum: usize = 0;
-                    for range in data.iter() {
-                        if let Some(r) = range {
-                            sum += r.end - r.start;
-                        }
+                    for r in data.iter().flatten() {
+                        sum += r.end - r.start;
                     }
                     black_box(sum)
                 })
@@ -74,10 +72,8 @@ fn bench_sequential_read_sum(c: &mut Criterion) {
             |b, data| {
                 b.iter(|| {
                     let mut sum: usize = 0;
-                    for range in data.iter() {
-                        if let Some(r) = range {
-                            sum += r.len();
-                        }
+                    for r in data.iter().flatten() {
+                        sum += r.len();
                     }
                     black_box(sum)
                 })
@@ -102,10 +98,8 @@ fn bench_sequential_read_starts(c: &mut Criterion) {
             |b, data| {
                 b.iter(|| {
                     let mut sum: usize = 0;
-                    for range in data.iter() {
-                        if let Some(r) = range {
-                            sum = sum.wrapping_add(r.start);
-                        }
+                    for r in data.iter().flatten() {
+                        sum = sum.wrapping_add(r.start);
                     }
                     black_box(sum)
                 })
@@ -120,10 +114,8 @@ fn bench_sequential_read_starts(c: &mut Criterion) {
             |b, data| {
                 b.iter(|| {
                     let mut sum: usize = 0;
-                    for range in data.iter() {
-                        if let Some(r) = range {
-                            sum = sum.wrapping_add(r.start());
-                        }
+                    for r in data.iter().flatten() {
+                        sum = sum.wrapping_add(r.start());
                     }
                     black_box(sum)
                 })
@@ -207,10 +199,8 @@ fn bench_large_sequential_scan(c: &mut Criterion) {
         |b, data| {
             b.iter(|| {
                 let mut sum: usize = 0;
-                for range in data.iter() {
-                    if let Some(r) = range {
-                        sum = sum.wrapping_add(r.end - r.start);
-                    }
+                for r in data.iter().flatten() {
+                    sum = sum.wrapping_add(r.end - r.start);
                 }
                 black_box(sum)
             })
@@ -234,10 +224,8 @@ fn bench_large_sequential_scan(c: &mut Criterion) {
         |b, data| {
             b.iter(|| {
                 let mut sum: usize = 0;
-                for range in data.iter() {
-                    if let Some(r) = range {
-                        sum = sum.wrapping_add(r.len());
-                    }
+                for r in data.iter().flatten() {
+                    sum = sum.wrapping_add(r.len());
                 }
                 black_box(sum)
             })
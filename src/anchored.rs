@@ -0,0 +1,190 @@
+use core::fmt;
+use core::ops::Range;
+
+use crate::SmallRange;
+
+/// A range inside a large address space: a 64-bit base plus a compact
+/// `SmallRange<u32>` relative to it.
+///
+/// This is the usual way to describe spans inside files larger than 4 GB:
+/// many spans share one absolute base, such as a chunk or section offset, and
+/// each stores only its small relative position. The offsets have the limits
+/// of `SmallRange<u32>`: start and length up to 65,534.
+///
+/// Equality compares the base and the offsets, so the same absolute range
+/// anchored at two different bases is not equal; compare
+/// [`to_range`](Self::to_range) for that.
+///
+/// # Examples
+/// ```
+/// use small_range::{AnchoredRange, SmallRange};
+///
+/// let section = 6_000_000_000u64;
+/// let span = AnchoredRange::new(section, SmallRange::new(100, 164));
+/// assert_eq!(span.absolute_start(), 6_000_000_100);
+/// assert_eq!(span.absolute_end(), 6_000_000_164);
+/// assert_eq!(span.len(), 64);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct AnchoredRange {
+    base: u64,
+    offsets: SmallRange<u32>,
+}
+
+impl AnchoredRange {
+    /// Creates a new `AnchoredRange` from a base and offsets relative to it.
+    ///
+    /// # Panics (debug only)
+    /// - If the absolute end overflows `u64`
+    #[inline]
+    pub fn new(base: u64, offsets: SmallRange<u32>) -> Self {
+        strict_assert!(
+            base.checked_add(u64::from(offsets.end())).is_some(),
+            "absolute end overflows u64"
+        );
+        Self { base, offsets }
+    }
+
+    /// Creates an `AnchoredRange` covering the absolute range `start..end`
+    /// relative to `base`, or returns `None` if it doesn't fit.
+    ///
+    /// Returns `None` if `start < base`, `start > end`, or if the relative
+    /// start or the length exceed the capacity of `SmallRange<u32>`.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::AnchoredRange;
+    ///
+    /// let span = AnchoredRange::from_absolute(1 << 40, (1 << 40) + 10, (1 << 40) + 20).unwrap();
+    /// assert_eq!(span.offsets().to_range(), 10..20);
+    ///
+    /// assert!(AnchoredRange::from_absolute(1 << 40, 0, 10).is_none());
+    /// assert!(AnchoredRange::from_absolute(0, 1 << 20, (1 << 20) + 1).is_none());
+    /// ```
+    #[inline]
+    pub fn from_absolute(base: u64, start: u64, end: u64) -> Option<Self> {
+        if start > end {
+            return None;
+        }
+        let relative_start = u32::try_from(start.checked_sub(base)?).ok()?;
+        let length = u32::try_from(end - start).ok()?;
+        Some(Self {
+            base,
+            offsets: SmallRange::try_from_start_len(relative_start, length)?,
+        })
+    }
+
+    /// Returns the absolute base the offsets are relative to.
+    #[inline]
+    pub fn base(&self) -> u64 {
+        self.base
+    }
+
+    /// Returns the offsets relative to the base.
+    #[inline]
+    pub fn offsets(&self) -> SmallRange<u32> {
+        self.offsets
+    }
+
+    /// Returns the absolute start, `base + offsets.start()`.
+    #[inline]
+    pub fn absolute_start(&self) -> u64 {
+        self.base + u64::from(self.offsets.start())
+    }
+
+    /// Returns the absolute end (exclusive), `base + offsets.end()`.
+    #[inline]
+    pub fn absolute_end(&self) -> u64 {
+        self.base + u64::from(self.offsets.end())
+    }
+
+    /// Returns the length of the range.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.offsets.len()
+    }
+
+    /// Returns `true` if the range is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.offsets.is_empty()
+    }
+
+    /// Returns `true` if the range contains the given absolute position.
+    #[inline]
+    pub fn contains(&self, position: u64) -> bool {
+        position >= self.absolute_start() && position < self.absolute_end()
+    }
+
+    /// Converts to the absolute `Range<u64>`.
+    #[inline]
+    pub fn to_range(&self) -> Range<u64> {
+        self.absolute_start()..self.absolute_end()
+    }
+
+    /// Re-expresses the same absolute range relative to `new_base`, or
+    /// returns `None` if it doesn't fit.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{AnchoredRange, SmallRange};
+    ///
+    /// let span = AnchoredRange::new(1_000, SmallRange::new(500, 600));
+    /// let rebased = span.rebase(1_400).unwrap();
+    /// assert_eq!(rebased.offsets(), SmallRange::new(100, 200));
+    /// assert_eq!(rebased.to_range(), span.to_range());
+    ///
+    /// // The start would be before the new base
+    /// assert!(span.rebase(1_600).is_none());
+    /// ```
+    #[inline]
+    pub fn rebase(&self, new_base: u64) -> Option<Self> {
+        Self::from_absolute(new_base, self.absolute_start(), self.absolute_end())
+    }
+
+    /// Re-expresses the range relative to its own start, so the offsets
+    /// become `0..len`. Always succeeds.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{AnchoredRange, SmallRange};
+    ///
+    /// let span = AnchoredRange::new(1_000, SmallRange::new(500, 600));
+    /// let anchored = span.anchor_at_start();
+    /// assert_eq!(anchored.base(), 1_500);
+    /// assert_eq!(anchored.offsets(), SmallRange::new(0, 100));
+    /// ```
+    #[inline]
+    pub fn anchor_at_start(&self) -> Self {
+        Self {
+            base: self.absolute_start(),
+            offsets: SmallRange::from_start_len(0, self.offsets.len() as u32),
+        }
+    }
+
+    /// Returns the same offsets relative to a different base, moving the
+    /// absolute position.
+    ///
+    /// # Panics (debug only)
+    /// - If the absolute end overflows `u64`
+    #[inline]
+    pub fn with_base(&self, base: u64) -> Self {
+        Self::new(base, self.offsets)
+    }
+}
+
+impl fmt::Debug for AnchoredRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AnchoredRange")
+            .field("base", &self.base)
+            .field("offsets", &self.offsets.to_range())
+            .finish()
+    }
+}
+
+impl From<AnchoredRange> for Range<u64> {
+    #[inline]
+    fn from(range: AnchoredRange) -> Self {
+        range.to_range()
+    }
+}
@@ -0,0 +1,30 @@
+//! `Arbitrary` for `SmallRange`, for fuzzing.
+//!
+//! The start and length are drawn separately from `0..=MAX_START` and
+//! `0..=MAX_LEN`, so every generated range is valid. Fewer input bytes give
+//! smaller values, and an exhausted input gives `0..0`, so as a fuzzer
+//! minimizes a failing input its ranges shrink toward the origin.
+
+use core::mem::size_of;
+
+use arbitrary::unstructured::Int;
+use arbitrary::{Arbitrary, Result, Unstructured};
+use num_traits::AsPrimitive;
+
+use crate::{SmallRange, SmallRangeStorage};
+
+impl<'a, T: SmallRangeStorage + Int> Arbitrary<'a> for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let start = u.int_in_range(T::zero()..=Self::MAX_START)?;
+        let len = u.int_in_range(T::zero()..=Self::MAX_LEN)?;
+        Ok(Self::from_start_len(start, len))
+    }
+
+    #[inline]
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, Some(2 * size_of::<T>()))
+    }
+}
@@ -0,0 +1,46 @@
+//! bincode 2 `Encode`, `Decode` and `BorrowDecode` for `SmallRange`.
+//!
+//! These use bincode's own traits rather than serde, so they work with
+//! bincode's `no_std` core. The wire form is the packed representation from
+//! [`SmallRange::to_bits`] as a single `T`, the same as the `serde` feature
+//! writes for binary formats. Decoding re-validates it, so bytes that no range
+//! produces are an error rather than a corrupt value.
+
+use bincode::de::{BorrowDecode, BorrowDecoder, Decode, Decoder};
+use bincode::enc::{Encode, Encoder};
+use bincode::error::{DecodeError, EncodeError};
+use num_traits::AsPrimitive;
+
+use crate::{SmallRange, SmallRangeStorage};
+
+impl<T: SmallRangeStorage + Encode> Encode for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        T::get_nonzero(self.to_bits()).encode(encoder)
+    }
+}
+
+impl<Context, T: SmallRangeStorage + Decode<Context>> Decode<Context> for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        let bits = T::decode(decoder)?;
+        SmallRange::from_bits(bits).ok_or(DecodeError::Other("invalid SmallRange encoding"))
+    }
+}
+
+impl<'de, Context, T: SmallRangeStorage + BorrowDecode<'de, Context>> BorrowDecode<'de, Context>
+    for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        let bits = T::borrow_decode(decoder)?;
+        SmallRange::from_bits(bits).ok_or(DecodeError::Other("invalid SmallRange encoding"))
+    }
+}
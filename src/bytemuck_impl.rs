@@ -0,0 +1,83 @@
+//! bytemuck `NoUninit`, `CheckedBitPattern` and `TransparentWrapper` for
+//! `SmallRange`, plus slice casts to and from the packed integers.
+//!
+//! A `SmallRange<T>` has the same layout as `T`, so whole tables of ranges can
+//! be viewed as `&[T]` for writing to GPU or disk buffers without copying.
+//! Casting back goes through `CheckedBitPattern`, which rejects zero and every
+//! other pattern that no range produces. `TransparentWrapper::wrap` doesn't
+//! validate: wrapping a `NonZero` that isn't a range's encoding gives
+//! meaningless bounds, so use the checked casts for untrusted data.
+
+use core::num::NonZero;
+
+use bytemuck::{AnyBitPattern, CheckedBitPattern, NoUninit, TransparentWrapper};
+use num_traits::AsPrimitive;
+
+use crate::{SmallRange, SmallRangeStorage};
+
+macro_rules! impl_bytemuck {
+    ($($t:ty),*) => {
+        $(
+            // SAFETY: SmallRange is a transparent wrapper around a non-zero
+            // integer, which has no padding or uninitialized bytes
+            unsafe impl NoUninit for SmallRange<$t> {}
+
+            // SAFETY: `$t` has the same size and alignment as SmallRange, and
+            // the check rejects zero and every other invalid encoding
+            unsafe impl CheckedBitPattern for SmallRange<$t> {
+                type Bits = $t;
+
+                #[inline]
+                fn is_valid_bit_pattern(bits: &$t) -> bool {
+                    SmallRange::<$t>::from_bits(*bits).is_some()
+                }
+            }
+
+            // SAFETY: SmallRange is `#[repr(transparent)]` over the non-zero
+            // integer
+            unsafe impl TransparentWrapper<NonZero<$t>> for SmallRange<$t> {}
+        )*
+    };
+}
+
+impl_bytemuck!(u8, u16, u32, u64, usize);
+
+impl<T: SmallRangeStorage + AnyBitPattern + NoUninit> SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+    Self: NoUninit + CheckedBitPattern<Bits = T>,
+{
+    /// Views a slice of ranges as their packed representations, without
+    /// copying.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let ranges = [SmallRange::<u64>::new(1, 4), SmallRange::new(7, 9)];
+    /// let bits = SmallRange::slice_to_bits(&ranges);
+    /// assert_eq!(bits[1], ranges[1].to_bits().get());
+    /// ```
+    #[inline]
+    pub fn slice_to_bits(ranges: &[Self]) -> &[T] {
+        bytemuck::cast_slice(ranges)
+    }
+
+    /// Views a slice of packed representations as ranges, without copying,
+    /// or returns `None` if any of them isn't a valid encoding.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let ranges = [SmallRange::<u64>::new(1, 4), SmallRange::new(7, 9)];
+    /// let bits = SmallRange::slice_to_bits(&ranges);
+    /// assert_eq!(SmallRange::try_slice_from_bits(bits), Some(&ranges[..]));
+    ///
+    /// assert_eq!(SmallRange::<u64>::try_slice_from_bits(&[bits[0], 0]), None);
+    /// ```
+    #[inline]
+    pub fn try_slice_from_bits(bits: &[T]) -> Option<&[Self]> {
+        bytemuck::checked::try_cast_slice(bits).ok()
+    }
+}
@@ -0,0 +1,241 @@
+use core::fmt;
+use core::iter::FusedIterator;
+use core::ops::RangeInclusive;
+
+use crate::{SmallRange, SmallRangeError};
+
+/// First and one-past-last code points of the surrogate block, which are not
+/// valid `char`s.
+const SURROGATES: core::ops::Range<u32> = 0xD800..0xE000;
+
+/// A compact half-open range of Unicode code points.
+///
+/// Stores the code points as a `SmallRange<u64>`, which covers all of
+/// Unicode in 8 bytes with the usual niche: `Option<CharRange>` is also 8
+/// bytes. Suited to character-class tables in lexers.
+///
+/// The start is always a valid `char`. The exclusive end is a code point that
+/// may not be one, such as `0x110000` for a range ending at `'\u{10FFFF}'`.
+/// Iteration skips the surrogate code points `U+D800..=U+DFFF`.
+///
+/// # Examples
+/// ```
+/// use small_range::CharRange;
+///
+/// let lower = CharRange::try_from('a'..='z').unwrap();
+/// assert!(lower.contains('q'));
+/// assert!(!lower.contains('A'));
+/// assert_eq!(lower.len(), 26);
+/// assert_eq!(lower.chars().last(), Some('z'));
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct CharRange {
+    inner: SmallRange<u64>,
+}
+
+impl CharRange {
+    /// Creates a new `CharRange` covering `start..end`.
+    ///
+    /// # Panics (debug only)
+    /// - If start exceeds end
+    #[inline]
+    pub fn new(start: char, end: char) -> Self {
+        Self {
+            inner: SmallRange::new(u64::from(start), u64::from(end)),
+        }
+    }
+
+    /// Returns the first code point of the range.
+    #[inline]
+    pub fn start(&self) -> u32 {
+        self.inner.start() as u32
+    }
+
+    /// Returns the code point one past the end of the range.
+    #[inline]
+    pub fn end(&self) -> u32 {
+        self.inner.end() as u32
+    }
+
+    /// Returns the number of code points in the range, including any
+    /// surrogates that [`chars`](Self::chars) skips.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns `true` if the range is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Returns `true` if the range contains the given character.
+    #[inline]
+    pub fn contains(&self, c: char) -> bool {
+        self.inner.contains(u64::from(c))
+    }
+
+    /// Returns the first character of the range, or `None` if it holds none.
+    #[inline]
+    pub fn first(&self) -> Option<char> {
+        self.chars().next()
+    }
+
+    /// Returns the last character of the range, or `None` if it holds none.
+    #[inline]
+    pub fn last(&self) -> Option<char> {
+        self.chars().next_back()
+    }
+
+    /// Returns an iterator over the characters in the range.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::CharRange;
+    ///
+    /// // Surrogates are skipped
+    /// let r = CharRange::new('\u{D7FE}', '\u{E001}');
+    /// assert_eq!(r.chars().collect::<Vec<_>>(), ['\u{D7FE}', '\u{D7FF}', '\u{E000}']);
+    /// ```
+    #[inline]
+    pub fn chars(&self) -> Chars {
+        Chars {
+            next: self.start(),
+            end: self.end(),
+        }
+    }
+
+    /// Converts to a `RangeInclusive<char>` over the same characters, or
+    /// `None` if the range holds none.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::CharRange;
+    ///
+    /// let r = CharRange::try_from('a'..='z').unwrap();
+    /// assert_eq!(r.to_range_inclusive(), Some('a'..='z'));
+    /// assert_eq!(CharRange::new('a', 'a').to_range_inclusive(), None);
+    /// ```
+    #[inline]
+    pub fn to_range_inclusive(&self) -> Option<RangeInclusive<char>> {
+        let mut chars = self.chars();
+        let first = chars.next()?;
+        Some(first..=chars.next_back().unwrap_or(first))
+    }
+
+    /// Returns the code points as a `SmallRange<u64>`.
+    #[inline]
+    pub fn as_small_range(&self) -> SmallRange<u64> {
+        self.inner
+    }
+
+    #[inline]
+    fn start_char(&self) -> char {
+        // The start is always built from a char
+        char::from_u32(self.start()).unwrap_or_default()
+    }
+}
+
+impl fmt::Debug for CharRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CharRange")
+            .field("start", &self.start_char())
+            .field("end", &format_args!("U+{:04X}", self.end()))
+            .finish()
+    }
+}
+
+/// Converts `a..=b`, whose end is stored as the code point after `b`.
+///
+/// # Errors
+/// Returns [`SmallRangeError::StartAfterEnd`] for an empty range, including
+/// an exhausted one.
+impl TryFrom<RangeInclusive<char>> for CharRange {
+    type Error = SmallRangeError;
+
+    fn try_from(range: RangeInclusive<char>) -> Result<Self, Self::Error> {
+        if range.is_empty() {
+            return Err(SmallRangeError::StartAfterEnd);
+        }
+        let (start, end) = range.into_inner();
+        Ok(Self {
+            inner: SmallRange::new(u64::from(start), u64::from(end) + 1),
+        })
+    }
+}
+
+impl IntoIterator for CharRange {
+    type Item = char;
+    type IntoIter = Chars;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.chars()
+    }
+}
+
+impl IntoIterator for &CharRange {
+    type Item = char;
+    type IntoIter = Chars;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.chars()
+    }
+}
+
+/// An iterator over the characters in a [`CharRange`].
+///
+/// Created by [`CharRange::chars`]. Skips the surrogate code points, which
+/// are not valid `char`s.
+#[derive(Debug, Clone)]
+pub struct Chars {
+    next: u32,
+    end: u32,
+}
+
+impl Iterator for Chars {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        if SURROGATES.contains(&self.next) {
+            self.next = SURROGATES.end;
+        }
+        if self.next >= self.end {
+            return None;
+        }
+        let c = char::from_u32(self.next);
+        self.next += 1;
+        c
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let total = self.end.saturating_sub(self.next);
+        let skipped = self
+            .end
+            .min(SURROGATES.end)
+            .saturating_sub(self.next.max(SURROGATES.start));
+        let len = (total - skipped) as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Chars {
+    #[inline]
+    fn next_back(&mut self) -> Option<char> {
+        if SURROGATES.contains(&(self.end.wrapping_sub(1))) {
+            self.end = SURROGATES.start;
+        }
+        if self.next >= self.end {
+            return None;
+        }
+        self.end -= 1;
+        char::from_u32(self.end)
+    }
+}
+
+impl ExactSizeIterator for Chars {}
+
+impl FusedIterator for Chars {}
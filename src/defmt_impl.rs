@@ -0,0 +1,17 @@
+//! defmt `Format` for `SmallRange`.
+//!
+//! Logs `start..end` like the `Display` impl, but the bounds go over the wire
+//! as integers and the host does the formatting.
+
+use num_traits::AsPrimitive;
+
+use crate::{SmallRange, SmallRangeStorage};
+
+impl<T: SmallRangeStorage + defmt::Format> defmt::Format for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{}..{}", self.start(), self.end());
+    }
+}
@@ -0,0 +1,68 @@
+use core::fmt;
+
+/// The reason a start/end pair can't be stored in a `SmallRange`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SmallRangeError {
+    /// The start is greater than the end.
+    StartAfterEnd,
+    /// The start exceeds the half-width capacity of the storage type.
+    StartTooLarge,
+    /// The length (`end - start`) exceeds the half-width capacity of the storage type.
+    LengthTooLarge,
+}
+
+impl SmallRangeError {
+    /// The error message, for formats whose errors only take a `&'static str`.
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Self::StartAfterEnd => "start must not exceed end",
+            Self::StartTooLarge => "start exceeds half-width capacity",
+            Self::LengthTooLarge => "length exceeds half-width capacity",
+        }
+    }
+}
+
+impl fmt::Display for SmallRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl core::error::Error for SmallRangeError {}
+
+/// The reason a string can't be parsed into a `SmallRange`.
+///
+/// Returned by [`SmallRange::parse`](crate::SmallRange::parse) and the
+/// `FromStr` impl.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseSmallRangeError {
+    /// The string has no `..` or `..=` separator.
+    MissingSeparator,
+    /// The start bound is not a valid integer.
+    InvalidStart,
+    /// The end bound is not a valid integer.
+    InvalidEnd,
+    /// The bounds parsed but can't be stored in a `SmallRange`.
+    Range(SmallRangeError),
+}
+
+impl fmt::Display for ParseSmallRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingSeparator => f.write_str("missing `..` separator"),
+            Self::InvalidStart => f.write_str("invalid start bound"),
+            Self::InvalidEnd => f.write_str("invalid end bound"),
+            Self::Range(err) => err.fmt(f),
+        }
+    }
+}
+
+// `Range` displays the inner error itself, so it isn't reported as the source
+// too, or error-chain reporters would print it twice
+impl core::error::Error for ParseSmallRangeError {}
+
+impl From<SmallRangeError> for ParseSmallRangeError {
+    fn from(err: SmallRangeError) -> Self {
+        Self::Range(err)
+    }
+}
@@ -0,0 +1,207 @@
+use core::fmt;
+use core::ops::Range;
+
+use num_traits::AsPrimitive;
+
+use crate::{SmallRange, SmallRangeStorage};
+
+/// A compact range that stores start and length raw, without a niche.
+///
+/// [`SmallRange`] encodes `start + 1` and `length + 1` so the packed value is
+/// never zero, which costs one value of capacity in each field. For tables
+/// that never wrap ranges in `Option`, `FullSmallRange` stores `start` and
+/// `length` directly and gets the full half-width domain back: starts and
+/// lengths up to 65,535 for `u32` instead of 65,534.
+///
+/// The trade-off is that `Option<FullSmallRange<T>>` is larger than
+/// `FullSmallRange<T>`.
+///
+/// # Constraints
+/// - Start must not exceed end
+/// - Start and length must each be at most [`MAX_START`](Self::MAX_START)
+///   and [`MAX_LEN`](Self::MAX_LEN) (`2^HALF_BITS - 1`)
+///
+/// # Examples
+/// ```
+/// use small_range::{FullSmallRange, SmallRange};
+/// use core::mem::size_of;
+///
+/// let r = FullSmallRange::<u32>::new(65_535, 131_070);
+/// assert_eq!(r.start(), 65_535);
+/// assert_eq!(r.len(), 65_535);
+/// assert!(SmallRange::<u32>::try_new(65_535, 131_070).is_none());
+///
+/// assert_eq!(size_of::<FullSmallRange<u32>>(), 4);
+/// assert_eq!(size_of::<Option<FullSmallRange<u32>>>(), 8);
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FullSmallRange<T: SmallRangeStorage = u64>
+where
+    usize: AsPrimitive<T>,
+{
+    // start in the high half, length in the low half: the derived ordering
+    // sorts by start, then by end
+    bits: T,
+}
+
+impl<T: SmallRangeStorage> FullSmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    /// Largest start value.
+    pub const MAX_START: T = T::LOW_MASK;
+
+    /// Largest length.
+    pub const MAX_LEN: T = T::LOW_MASK;
+
+    #[inline]
+    fn decode(&self) -> (T, T) {
+        (self.bits >> T::HALF_BITS as usize, self.bits & T::LOW_MASK)
+    }
+
+    /// Creates a new `FullSmallRange` with the given start and end values.
+    ///
+    /// # Panics (debug only)
+    /// - If start exceeds end
+    /// - If start or length exceed the half-width capacity
+    #[inline]
+    pub fn new(start: T, end: T) -> Self {
+        strict_assert!(start <= end, "start must not exceed end");
+        Self::from_start_len(start, end - start)
+    }
+
+    /// Creates a new `FullSmallRange` from a start value and a length.
+    ///
+    /// # Panics (debug only)
+    /// - If start or length exceed the half-width capacity
+    #[inline]
+    pub fn from_start_len(start: T, len: T) -> Self {
+        strict_assert!(start <= T::LOW_MASK, "start exceeds half-width capacity");
+        strict_assert!(len <= T::LOW_MASK, "length exceeds half-width capacity");
+        Self {
+            bits: (start << T::HALF_BITS as usize) | len,
+        }
+    }
+
+    /// Creates a new `FullSmallRange` if the values are valid, returns `None`
+    /// otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::FullSmallRange;
+    ///
+    /// assert!(FullSmallRange::<u16>::try_new(255, 510).is_some());
+    /// assert!(FullSmallRange::<u16>::try_new(256, 256).is_none());
+    /// assert!(FullSmallRange::<u16>::try_new(0, 256).is_none());
+    /// assert!(FullSmallRange::<u16>::try_new(20, 10).is_none());
+    /// ```
+    #[inline]
+    pub fn try_new(start: T, end: T) -> Option<Self> {
+        if start > end {
+            return None;
+        }
+        Self::try_from_start_len(start, end - start)
+    }
+
+    /// Creates a new `FullSmallRange` from a start and length, or returns
+    /// `None` if either exceeds the half-width capacity.
+    #[inline]
+    pub fn try_from_start_len(start: T, len: T) -> Option<Self> {
+        if start > T::LOW_MASK || len > T::LOW_MASK {
+            return None;
+        }
+        Some(Self::from_start_len(start, len))
+    }
+
+    /// Returns the start of the range.
+    #[inline]
+    pub fn start(&self) -> T {
+        self.decode().0
+    }
+
+    /// Returns the end of the range (exclusive).
+    #[inline]
+    pub fn end(&self) -> T {
+        let (start, length) = self.decode();
+        start + length
+    }
+
+    /// Returns the length of the range.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.decode().1.as_()
+    }
+
+    /// Returns `true` if the range is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.decode().1.is_zero()
+    }
+
+    /// Returns `true` if the range contains the given value.
+    #[inline]
+    pub fn contains(&self, value: T) -> bool {
+        let (start, length) = self.decode();
+        value >= start && value - start < length
+    }
+
+    /// Converts the `FullSmallRange` to a standard `Range<T>`.
+    #[inline]
+    pub fn to_range(&self) -> Range<T> {
+        let (start, length) = self.decode();
+        start..(start + length)
+    }
+
+    /// Converts to a `SmallRange`, if the start and length fit its
+    /// one-smaller capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{FullSmallRange, SmallRange};
+    ///
+    /// let r = FullSmallRange::<u16>::new(10, 20);
+    /// assert_eq!(r.to_small_range(), Some(SmallRange::new(10, 20)));
+    ///
+    /// let full = FullSmallRange::<u16>::new(255, 255);
+    /// assert_eq!(full.to_small_range(), None);
+    /// ```
+    #[inline]
+    pub fn to_small_range(&self) -> Option<SmallRange<T>> {
+        let (start, length) = self.decode();
+        SmallRange::try_from_start_len(start, length)
+    }
+}
+
+impl<T: SmallRangeStorage> Default for FullSmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    fn default() -> Self {
+        Self { bits: T::zero() }
+    }
+}
+
+impl<T: SmallRangeStorage + fmt::Debug> fmt::Debug for FullSmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FullSmallRange")
+            .field("start", &self.start())
+            .field("end", &self.end())
+            .finish()
+    }
+}
+
+/// Every `SmallRange` fits, since its capacity is one smaller in each field.
+impl<T: SmallRangeStorage> From<SmallRange<T>> for FullSmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    fn from(range: SmallRange<T>) -> Self {
+        let (start, length) = range.to_start_len();
+        Self::from_start_len(start, length)
+    }
+}
@@ -0,0 +1,119 @@
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Range;
+
+use num_traits::AsPrimitive;
+
+use crate::{SmallRange, SmallRangeStorage};
+
+/// A [`SmallRange`] whose `Hash` matches the equivalent `Range<T>`.
+///
+/// `SmallRange` hashes its packed bits, which is cheap but differs from
+/// `Range<T>`, which hashes `start` and then `end`. This wrapper hashes the
+/// decoded bounds exactly as `Range<T>` does, so for any hasher
+/// `hash(HashAsRange::new(r)) == hash(r.to_range())`. Use it as the key type
+/// while migrating a map from `Range<T>` keys, so entries agree whichever
+/// representation computed the hash. It has the same layout and niche as
+/// `SmallRange<T>`.
+///
+/// # Examples
+/// ```
+/// use core::hash::BuildHasher;
+/// use std::collections::hash_map::RandomState;
+/// use small_range::{HashAsRange, SmallRange};
+///
+/// let state = RandomState::new();
+/// let key = HashAsRange::new(SmallRange::<u32>::new(10, 20));
+/// assert_eq!(state.hash_one(key), state.hash_one(10u32..20));
+/// assert_eq!(key, 10..20);
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct HashAsRange<T: SmallRangeStorage = u64>
+where
+    usize: AsPrimitive<T>,
+{
+    inner: SmallRange<T>,
+}
+
+impl<T: SmallRangeStorage> HashAsRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    /// Wraps a range.
+    #[inline]
+    pub fn new(range: SmallRange<T>) -> Self {
+        Self { inner: range }
+    }
+
+    /// Returns the wrapped range.
+    #[inline]
+    pub fn get(&self) -> SmallRange<T> {
+        self.inner
+    }
+
+    /// Converts to the standard `Range<T>` with the same hash.
+    #[inline]
+    pub fn to_range(&self) -> Range<T> {
+        self.inner.to_range()
+    }
+}
+
+impl<T: SmallRangeStorage> Hash for HashAsRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.to_range().hash(state);
+    }
+}
+
+impl<T: SmallRangeStorage + fmt::Debug> fmt::Debug for HashAsRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<T: SmallRangeStorage> From<SmallRange<T>> for HashAsRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    fn from(range: SmallRange<T>) -> Self {
+        Self::new(range)
+    }
+}
+
+impl<T: SmallRangeStorage> From<HashAsRange<T>> for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    fn from(range: HashAsRange<T>) -> Self {
+        range.inner
+    }
+}
+
+impl<T: SmallRangeStorage> PartialEq<Range<T>> for HashAsRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    fn eq(&self, other: &Range<T>) -> bool {
+        self.inner == *other
+    }
+}
+
+impl<T: SmallRangeStorage> PartialEq<HashAsRange<T>> for Range<T>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    fn eq(&self, other: &HashAsRange<T>) -> bool {
+        *self == other.inner
+    }
+}
@@ -0,0 +1,380 @@
+extern crate alloc;
+
+use alloc::vec::{self, Vec};
+use core::fmt;
+use core::iter::FusedIterator;
+use core::slice;
+
+use num_traits::AsPrimitive;
+
+use crate::{SmallRange, SmallRangeStorage};
+
+/// A collection of possibly overlapping [`SmallRange`]s, each with a value,
+/// answering stabbing and overlap queries.
+///
+/// Unlike [`RangeMap`](crate::RangeMap), entries may overlap and repeat. They
+/// are kept sorted by range in a single `Vec`, read as an implicit balanced
+/// tree: the subtree over any slice is rooted at its midpoint, and each node
+/// records the largest end below it. A query skips every subtree that ends
+/// before it or starts after it, so it visits O(log n) nodes plus at most
+/// O(log n) per match.
+///
+/// Inserting one entry is O(n), since the array shifts and the recorded ends
+/// are rebuilt. Build large trees with `collect` or `extend`, which sort once.
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+/// ```
+/// use small_range::{IntervalTree, SmallRange};
+///
+/// let diagnostics: IntervalTree<u32, &str> = [
+///     (SmallRange::new(10, 30), "unused variable"),
+///     (SmallRange::new(20, 25), "type mismatch"),
+///     (SmallRange::new(40, 50), "missing semicolon"),
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// let at_22: Vec<_> = diagnostics.stabbing(22).map(|(_, message)| *message).collect();
+/// assert_eq!(at_22, ["unused variable", "type mismatch"]);
+///
+/// assert_eq!(diagnostics.overlapping(SmallRange::new(28, 45)).count(), 2);
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct IntervalTree<T: SmallRangeStorage, V>
+where
+    usize: AsPrimitive<T>,
+{
+    // Sorted by range; equal ranges keep their insertion order
+    entries: Vec<(SmallRange<T>, V)>,
+    // The largest end in the subtree rooted at each index
+    max_ends: Vec<T>,
+}
+
+impl<T: SmallRangeStorage, V> IntervalTree<T, V>
+where
+    usize: AsPrimitive<T>,
+{
+    /// Creates an empty tree.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            max_ends: Vec::new(),
+        }
+    }
+
+    /// Returns the number of entries.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the tree has no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes every entry.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.max_ends.clear();
+    }
+
+    /// Adds an entry, keeping any entries it overlaps or repeats.
+    ///
+    /// This is O(n); use `collect` or `extend` to add many entries at once.
+    pub fn insert(&mut self, range: SmallRange<T>, value: V) {
+        let index = self.entries.partition_point(|(entry, _)| *entry <= range);
+        self.entries.insert(index, (range, value));
+        self.rebuild();
+    }
+
+    /// Returns an iterator over the entries, sorted by range.
+    #[inline]
+    pub fn iter(&self) -> IntervalTreeIter<'_, T, V> {
+        IntervalTreeIter {
+            entries: self.entries.iter(),
+        }
+    }
+
+    /// Returns an iterator over the entries overlapping `query`, sorted by
+    /// range.
+    ///
+    /// As with [`SmallRange::overlaps`], an entry that only touches `query`
+    /// doesn't overlap it, and empty ranges overlap nothing.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{IntervalTree, SmallRange};
+    ///
+    /// let tree: IntervalTree<u32, char> = [
+    ///     (SmallRange::new(0, 10), 'a'),
+    ///     (SmallRange::new(5, 15), 'b'),
+    ///     (SmallRange::new(15, 20), 'c'),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let hits: Vec<_> = tree.overlapping(SmallRange::new(8, 15)).map(|(_, v)| *v).collect();
+    /// assert_eq!(hits, ['a', 'b']);
+    /// ```
+    #[inline]
+    pub fn overlapping(&self, query: SmallRange<T>) -> Overlapping<'_, T, V> {
+        Overlapping::new(self, query.start(), query.end())
+    }
+
+    /// Returns an iterator over the entries containing `point`, sorted by
+    /// range.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{IntervalTree, SmallRange};
+    ///
+    /// let tree: IntervalTree<u32, char> = [
+    ///     (SmallRange::new(0, 10), 'a'),
+    ///     (SmallRange::new(5, 15), 'b'),
+    ///     (SmallRange::new(5, 15), 'c'),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let hits: Vec<_> = tree.stabbing(10).map(|(_, v)| *v).collect();
+    /// assert_eq!(hits, ['b', 'c']);
+    /// ```
+    #[inline]
+    pub fn stabbing(&self, point: T) -> Overlapping<'_, T, V> {
+        Overlapping::new(self, point, point.saturating_add(T::one()))
+    }
+
+    /// Recomputes the largest end below every node.
+    fn rebuild(&mut self) {
+        self.max_ends.clear();
+        self.max_ends.resize(self.entries.len(), T::zero());
+        self.build(0, self.entries.len());
+    }
+
+    /// Records the largest end below each node of the subtree over `lo..hi`,
+    /// returning the largest end in it.
+    fn build(&mut self, lo: usize, hi: usize) -> T {
+        if lo >= hi {
+            return T::zero();
+        }
+        let mid = midpoint(lo, hi);
+        let max_end = self.entries[mid]
+            .0
+            .end()
+            .max(self.build(lo, mid))
+            .max(self.build(mid + 1, hi));
+        self.max_ends[mid] = max_end;
+        max_end
+    }
+}
+
+/// The root of the implicit subtree over `lo..hi`.
+#[inline]
+fn midpoint(lo: usize, hi: usize) -> usize {
+    lo + (hi - lo) / 2
+}
+
+impl<T: SmallRangeStorage, V> Default for IntervalTree<T, V>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: SmallRangeStorage + fmt::Debug, V: fmt::Debug> fmt::Debug for IntervalTree<T, V>
+where
+    usize: AsPrimitive<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<T: SmallRangeStorage, V> Extend<(SmallRange<T>, V)> for IntervalTree<T, V>
+where
+    usize: AsPrimitive<T>,
+{
+    fn extend<I: IntoIterator<Item = (SmallRange<T>, V)>>(&mut self, entries: I) {
+        self.entries.extend(entries);
+        // Stable, so equal ranges keep their insertion order
+        self.entries.sort_by_key(|(range, _)| *range);
+        self.rebuild();
+    }
+}
+
+impl<T: SmallRangeStorage, V> FromIterator<(SmallRange<T>, V)> for IntervalTree<T, V>
+where
+    usize: AsPrimitive<T>,
+{
+    fn from_iter<I: IntoIterator<Item = (SmallRange<T>, V)>>(entries: I) -> Self {
+        let mut tree = Self::new();
+        tree.extend(entries);
+        tree
+    }
+}
+
+impl<T: SmallRangeStorage, V> IntoIterator for IntervalTree<T, V>
+where
+    usize: AsPrimitive<T>,
+{
+    type Item = (SmallRange<T>, V);
+    type IntoIter = vec::IntoIter<(SmallRange<T>, V)>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<'a, T: SmallRangeStorage, V> IntoIterator for &'a IntervalTree<T, V>
+where
+    usize: AsPrimitive<T>,
+{
+    type Item = (SmallRange<T>, &'a V);
+    type IntoIter = IntervalTreeIter<'a, T, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the entries of an [`IntervalTree`], sorted by range.
+///
+/// Created by [`IntervalTree::iter`].
+#[derive(Debug, Clone)]
+pub struct IntervalTreeIter<'a, T: SmallRangeStorage, V>
+where
+    usize: AsPrimitive<T>,
+{
+    entries: slice::Iter<'a, (SmallRange<T>, V)>,
+}
+
+impl<'a, T: SmallRangeStorage, V> Iterator for IntervalTreeIter<'a, T, V>
+where
+    usize: AsPrimitive<T>,
+{
+    type Item = (SmallRange<T>, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries.next().map(|(range, value)| (*range, value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.entries.size_hint()
+    }
+}
+
+impl<T: SmallRangeStorage, V> DoubleEndedIterator for IntervalTreeIter<'_, T, V>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.entries
+            .next_back()
+            .map(|(range, value)| (*range, value))
+    }
+}
+
+impl<T: SmallRangeStorage, V> ExactSizeIterator for IntervalTreeIter<'_, T, V> where
+    usize: AsPrimitive<T>
+{
+}
+
+impl<T: SmallRangeStorage, V> FusedIterator for IntervalTreeIter<'_, T, V> where
+    usize: AsPrimitive<T>
+{
+}
+
+/// An iterator over the entries of an [`IntervalTree`] that overlap a query,
+/// sorted by range.
+///
+/// Created by [`IntervalTree::overlapping`] and [`IntervalTree::stabbing`].
+#[derive(Debug, Clone)]
+pub struct Overlapping<'a, T: SmallRangeStorage, V>
+where
+    usize: AsPrimitive<T>,
+{
+    tree: &'a IntervalTree<T, V>,
+    start: T,
+    end: T,
+    // Work left for an in-order walk, popped from the back
+    stack: Vec<Step>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Step {
+    /// The subtree over `lo..hi`, not yet visited.
+    Subtree(usize, usize),
+    /// A node already known to overlap the query.
+    Node(usize),
+}
+
+impl<'a, T: SmallRangeStorage, V> Overlapping<'a, T, V>
+where
+    usize: AsPrimitive<T>,
+{
+    fn new(tree: &'a IntervalTree<T, V>, start: T, end: T) -> Self {
+        let mut stack = Vec::new();
+        if start < end {
+            stack.push(Step::Subtree(0, tree.entries.len()));
+        }
+        Self {
+            tree,
+            start,
+            end,
+            stack,
+        }
+    }
+}
+
+impl<'a, T: SmallRangeStorage, V> Iterator for Overlapping<'a, T, V>
+where
+    usize: AsPrimitive<T>,
+{
+    type Item = (SmallRange<T>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(step) = self.stack.pop() {
+            let (lo, hi) = match step {
+                Step::Node(index) => {
+                    let (range, value) = &self.tree.entries[index];
+                    return Some((*range, value));
+                }
+                Step::Subtree(lo, hi) => (lo, hi),
+            };
+            if lo >= hi {
+                continue;
+            }
+            let mid = midpoint(lo, hi);
+            // Nothing below ends after the query starts
+            if self.tree.max_ends[mid] <= self.start {
+                continue;
+            }
+            // Entries from `mid` on start at or after the query ends
+            let range = self.tree.entries[mid].0;
+            if range.start() < self.end {
+                self.stack.push(Step::Subtree(mid + 1, hi));
+                if range.end() > self.start && !range.is_empty() {
+                    self.stack.push(Step::Node(mid));
+                }
+            }
+            self.stack.push(Step::Subtree(lo, mid));
+        }
+        None
+    }
+}
+
+impl<T: SmallRangeStorage, V> FusedIterator for Overlapping<'_, T, V> where usize: AsPrimitive<T> {}
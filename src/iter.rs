@@ -0,0 +1,636 @@
+use core::iter::FusedIterator;
+
+use num_traits::{AsPrimitive, Zero};
+
+use crate::{SmallRange, SmallRangeError, SmallRangeStorage};
+
+/// An iterator over the values of a range.
+///
+/// Created by iterating a [`SmallRange`]. Unlike `Range<u64>`, it implements
+/// `ExactSizeIterator` for every storage type, since a range's length always
+/// fits in a `usize`. `nth` and `nth_back` are O(1).
+#[derive(Debug, Clone)]
+pub struct SmallRangeIter<T: SmallRangeStorage>
+where
+    usize: AsPrimitive<T>,
+{
+    start: T,
+    end: T,
+}
+
+impl<T: SmallRangeStorage> SmallRangeIter<T>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    pub(crate) fn new(range: SmallRange<T>) -> Self {
+        let (start, end) = range.to_parts();
+        Self { start, end }
+    }
+
+    /// Iterates over `start..end`, which may lie beyond a range's start
+    /// capacity, as the tail of a range near `MAX_END` does.
+    #[cfg(feature = "rayon")]
+    #[inline]
+    pub(crate) fn from_bounds(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    #[inline]
+    fn remaining(&self) -> usize {
+        (self.end - self.start).as_()
+    }
+}
+
+impl<T: SmallRangeStorage> Iterator for SmallRangeIter<T>
+where
+    usize: AsPrimitive<T>,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        if self.start == self.end {
+            return None;
+        }
+        let value = self.start;
+        self.start = value + T::one();
+        Some(value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining();
+        (remaining, Some(remaining))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.remaining()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<T> {
+        if n >= self.remaining() {
+            self.start = self.end;
+            return None;
+        }
+        self.start = self.start + n.as_();
+        self.next()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<T> {
+        self.next_back()
+    }
+
+    #[inline]
+    fn min(mut self) -> Option<T> {
+        self.next()
+    }
+
+    #[inline]
+    fn max(mut self) -> Option<T> {
+        self.next_back()
+    }
+}
+
+impl<T: SmallRangeStorage> DoubleEndedIterator for SmallRangeIter<T>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        if self.start == self.end {
+            return None;
+        }
+        self.end = self.end - T::one();
+        Some(self.end)
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<T> {
+        if n >= self.remaining() {
+            self.end = self.start;
+            return None;
+        }
+        self.end = self.end - n.as_();
+        self.next_back()
+    }
+}
+
+impl<T: SmallRangeStorage> ExactSizeIterator for SmallRangeIter<T> where usize: AsPrimitive<T> {}
+
+impl<T: SmallRangeStorage> FusedIterator for SmallRangeIter<T> where usize: AsPrimitive<T> {}
+
+/// An iterator over `parts` contiguous sub-ranges of nearly equal length.
+///
+/// Created by [`SmallRange::partition`]. The first `len % parts` sub-ranges are
+/// one element longer than the rest, so lengths never differ by more than one.
+#[derive(Debug, Clone)]
+pub struct Partition<T: SmallRangeStorage>
+where
+    usize: AsPrimitive<T>,
+{
+    next_start: T,
+    base_len: usize,
+    longer: usize,
+    remaining: usize,
+}
+
+impl<T: SmallRangeStorage> Partition<T>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    pub(crate) fn new(range: SmallRange<T>, parts: usize) -> Self {
+        assert!(parts != 0, "parts must be non-zero");
+        let len = range.len();
+        Self {
+            next_start: range.start(),
+            base_len: len / parts,
+            longer: len % parts,
+            remaining: parts,
+        }
+    }
+}
+
+impl<T: SmallRangeStorage> Iterator for Partition<T>
+where
+    usize: AsPrimitive<T>,
+{
+    type Item = SmallRange<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let mut len = self.base_len;
+        if self.longer > 0 {
+            self.longer -= 1;
+            len += 1;
+        }
+        self.remaining -= 1;
+        let start = self.next_start;
+        self.next_start = start + len.as_();
+        Some(SmallRange::new(start, self.next_start))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: SmallRangeStorage> ExactSizeIterator for Partition<T> where usize: AsPrimitive<T> {}
+
+impl<T: SmallRangeStorage> FusedIterator for Partition<T> where usize: AsPrimitive<T> {}
+
+/// An iterator over consecutive sub-ranges of at most `chunk_len` values.
+///
+/// Created by [`SmallRange::chunks`]. Like `slice::chunks`, every chunk except
+/// possibly the last has exactly `chunk_len` values.
+#[derive(Debug, Clone)]
+pub struct Chunks<T: SmallRangeStorage>
+where
+    usize: AsPrimitive<T>,
+{
+    start: T,
+    end: T,
+    chunk_len: T,
+}
+
+impl<T: SmallRangeStorage> Chunks<T>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    pub(crate) fn new(range: SmallRange<T>, chunk_len: T) -> Self {
+        assert!(!chunk_len.is_zero(), "chunk_len must be non-zero");
+        Self {
+            start: range.start(),
+            end: range.end(),
+            chunk_len,
+        }
+    }
+}
+
+impl<T: SmallRangeStorage> Iterator for Chunks<T>
+where
+    usize: AsPrimitive<T>,
+{
+    type Item = SmallRange<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        let start = self.start;
+        self.start = if self.end - start > self.chunk_len {
+            start + self.chunk_len
+        } else {
+            self.end
+        };
+        Some(SmallRange::new(start, self.start))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.start;
+        let mut count = remaining / self.chunk_len;
+        if !(remaining % self.chunk_len).is_zero() {
+            count = count + T::one();
+        }
+        let count = count.as_();
+        (count, Some(count))
+    }
+}
+
+impl<T: SmallRangeStorage> DoubleEndedIterator for Chunks<T>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        let remainder = (self.end - self.start) % self.chunk_len;
+        let last_len = if remainder.is_zero() {
+            self.chunk_len
+        } else {
+            remainder
+        };
+        let end = self.end;
+        self.end = end - last_len;
+        Some(SmallRange::new(self.end, end))
+    }
+}
+
+impl<T: SmallRangeStorage> ExactSizeIterator for Chunks<T> where usize: AsPrimitive<T> {}
+
+impl<T: SmallRangeStorage> FusedIterator for Chunks<T> where usize: AsPrimitive<T> {}
+
+/// An iterator over all contiguous sub-ranges of exactly `size` values.
+///
+/// Created by [`SmallRange::windows`]. Like `slice::windows`, consecutive
+/// windows overlap and advance by one value at a time.
+#[derive(Debug, Clone)]
+pub struct Windows<T: SmallRangeStorage>
+where
+    usize: AsPrimitive<T>,
+{
+    next_start: T,
+    size: T,
+    remaining: usize,
+}
+
+impl<T: SmallRangeStorage> Windows<T>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    pub(crate) fn new(range: SmallRange<T>, size: T) -> Self {
+        assert!(!size.is_zero(), "size must be non-zero");
+        let size_len: usize = size.as_();
+        let len = range.len();
+        Self {
+            next_start: range.start(),
+            size,
+            remaining: if size_len <= len {
+                len - size_len + 1
+            } else {
+                0
+            },
+        }
+    }
+}
+
+impl<T: SmallRangeStorage> Iterator for Windows<T>
+where
+    usize: AsPrimitive<T>,
+{
+    type Item = SmallRange<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let start = self.next_start;
+        self.next_start = start + T::one();
+        Some(SmallRange::new(start, start + self.size))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: SmallRangeStorage> DoubleEndedIterator for Windows<T>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let start = self.next_start + self.remaining.as_();
+        Some(SmallRange::new(start, start + self.size))
+    }
+}
+
+impl<T: SmallRangeStorage> ExactSizeIterator for Windows<T> where usize: AsPrimitive<T> {}
+
+impl<T: SmallRangeStorage> FusedIterator for Windows<T> where usize: AsPrimitive<T> {}
+
+/// An iterator over every `step`-th value of a range.
+///
+/// Created by [`SmallRange::iter_step`]. Unlike `Range::step_by`, it knows its
+/// exact length for every storage type.
+#[derive(Debug, Clone)]
+pub struct StepBy<T: SmallRangeStorage>
+where
+    usize: AsPrimitive<T>,
+{
+    next: T,
+    step: T,
+    remaining: usize,
+}
+
+impl<T: SmallRangeStorage> StepBy<T>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    pub(crate) fn new(range: SmallRange<T>, step: T) -> Self {
+        assert!(!step.is_zero(), "step must be non-zero");
+        // Divide in `T`: the step may not fit in `usize`, but the count can't
+        // exceed `len`, which does
+        let (start, len) = range.to_start_len();
+        let mut count = len / step;
+        if !(len % step).is_zero() {
+            count = count + T::one();
+        }
+        Self {
+            next: start,
+            step,
+            remaining: count.as_(),
+        }
+    }
+}
+
+impl<T: SmallRangeStorage> Iterator for StepBy<T>
+where
+    usize: AsPrimitive<T>,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let value = self.next;
+        if self.remaining > 0 {
+            self.next = value + self.step;
+        }
+        Some(value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: SmallRangeStorage> DoubleEndedIterator for StepBy<T>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        Some(self.next + self.step * self.remaining.as_())
+    }
+}
+
+impl<T: SmallRangeStorage> ExactSizeIterator for StepBy<T> where usize: AsPrimitive<T> {}
+
+impl<T: SmallRangeStorage> FusedIterator for StepBy<T> where usize: AsPrimitive<T> {}
+
+/// An iterator over the pieces of a range split at page boundaries.
+///
+/// Created by [`SmallRange::pages`]. Yields a possibly partial head piece,
+/// then full pages, then a possibly partial tail piece.
+#[derive(Debug, Clone)]
+pub struct Pages<T: SmallRangeStorage>
+where
+    usize: AsPrimitive<T>,
+{
+    start: T,
+    end: T,
+    page_size: T,
+}
+
+impl<T: SmallRangeStorage> Pages<T>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    pub(crate) fn new(range: SmallRange<T>, page_size: T) -> Self {
+        assert!(!page_size.is_zero(), "page_size must be non-zero");
+        Self {
+            start: range.start(),
+            end: range.end(),
+            page_size,
+        }
+    }
+}
+
+impl<T: SmallRangeStorage> Iterator for Pages<T>
+where
+    usize: AsPrimitive<T>,
+{
+    type Item = SmallRange<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        let start = self.start;
+        // Distance to the next page boundary (a full page if already aligned)
+        let to_boundary = self.page_size - start % self.page_size;
+        self.start = if self.end - start > to_boundary {
+            start + to_boundary
+        } else {
+            self.end
+        };
+        Some(SmallRange::new(start, self.start))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count = if self.start == self.end {
+            0
+        } else {
+            let first_page = self.start / self.page_size;
+            let last_page = (self.end - T::one()) / self.page_size;
+            (last_page - first_page).as_() + 1
+        };
+        (count, Some(count))
+    }
+}
+
+impl<T: SmallRangeStorage> DoubleEndedIterator for Pages<T>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        let end = self.end;
+        // Distance back to the previous page boundary (a full page if aligned)
+        let remainder = end % self.page_size;
+        let from_boundary = if remainder.is_zero() {
+            self.page_size
+        } else {
+            remainder
+        };
+        self.end = if end - self.start > from_boundary {
+            end - from_boundary
+        } else {
+            self.start
+        };
+        Some(SmallRange::new(self.end, end))
+    }
+}
+
+impl<T: SmallRangeStorage> ExactSizeIterator for Pages<T> where usize: AsPrimitive<T> {}
+
+impl<T: SmallRangeStorage> FusedIterator for Pages<T> where usize: AsPrimitive<T> {}
+
+/// Groups maximal runs of consecutive values into ranges.
+///
+/// Feed it values sorted in ascending order and it yields one range per run,
+/// so `[1, 2, 3, 7, 8]` becomes `1..4` and `7..9`. It is streaming and
+/// allocation-free, holding at most one value of lookahead. Repeated values
+/// extend nothing and are skipped. Unsorted input still yields ranges that
+/// cover every value, but they are no longer maximal.
+///
+/// A run longer than `MAX_LEN` is split into several ranges from its end, as
+/// `RangeSet` stores it, so only the first can be short. This keeps every
+/// piece of a run that starts within the half-width capacity storable.
+///
+/// # Errors
+/// Each item is a `Result`. A value that would start a range beyond
+/// `MAX_START` yields [`SmallRangeError::StartTooLarge`], and iteration goes
+/// on with the next value. Since no range ends past `MAX_END`, neither can a
+/// run, so a value there starts a new one and fails the same way.
+///
+/// # Examples
+/// ```
+/// use small_range::{collapse_consecutive, SmallRange, SmallRangeError};
+///
+/// let ids = [1u32, 2, 3, 7, 8, 8, 12];
+/// let runs: Result<Vec<_>, _> = collapse_consecutive(ids).collect();
+/// assert_eq!(runs, Ok(vec![SmallRange::new(1, 4), SmallRange::new(7, 9), SmallRange::new(12, 13)]));
+///
+/// // u16 starts max out at 254
+/// let runs: Vec<_> = collapse_consecutive([250u16, 251, 300]).collect();
+/// assert_eq!(runs, [Ok(SmallRange::new(250, 252)), Err(SmallRangeError::StartTooLarge)]);
+/// ```
+pub fn collapse_consecutive<I>(values: I) -> CollapseConsecutive<I::IntoIter>
+where
+    I: IntoIterator,
+    I::Item: SmallRangeStorage,
+    usize: AsPrimitive<I::Item>,
+{
+    CollapseConsecutive {
+        iter: values.into_iter(),
+        pending: None,
+        piece_start: I::Item::zero(),
+        run_end: I::Item::zero(),
+    }
+}
+
+/// An iterator over the runs of consecutive values in another iterator.
+///
+/// Created by [`collapse_consecutive`].
+#[derive(Debug, Clone)]
+pub struct CollapseConsecutive<I: Iterator>
+where
+    I::Item: SmallRangeStorage,
+    usize: AsPrimitive<I::Item>,
+{
+    iter: I,
+    pending: Option<I::Item>,
+    // What is left of the current run, yielded piece by piece
+    piece_start: I::Item,
+    run_end: I::Item,
+}
+
+impl<T: SmallRangeStorage, I: Iterator<Item = T>> Iterator for CollapseConsecutive<I>
+where
+    usize: AsPrimitive<T>,
+{
+    type Item = Result<SmallRange<T>, SmallRangeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.piece_start >= self.run_end {
+            let start = self.pending.take().or_else(|| self.iter.next())?;
+            if start > SmallRange::<T>::MAX_START {
+                return Some(Err(SmallRangeError::StartTooLarge));
+            }
+            let mut end = start + T::one();
+            for value in self.iter.by_ref() {
+                if value == end && end < SmallRange::<T>::MAX_END {
+                    end = end + T::one();
+                } else if value < start || value >= end {
+                    self.pending = Some(value);
+                    break;
+                }
+            }
+            self.piece_start = start;
+            self.run_end = end;
+        }
+        // Every piece but the first is full, so the last one ends the run
+        let len =
+            (self.run_end - self.piece_start - T::one()) % SmallRange::<T>::MAX_LEN + T::one();
+        let piece = SmallRange::from_start_len(self.piece_start, len);
+        self.piece_start = piece.end();
+        Some(Ok(piece))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pieces = if self.piece_start < self.run_end {
+            ((self.run_end - self.piece_start - T::one()) / SmallRange::<T>::MAX_LEN).as_() + 1
+        } else {
+            0
+        };
+        let pending = usize::from(self.pending.is_some());
+        let (lower, upper) = self.iter.size_hint();
+        let lower = pieces + usize::from(pending + lower > 0);
+        let upper = upper.and_then(|upper| upper.checked_add(pieces + pending));
+        (lower, upper)
+    }
+}
+
+impl<T: SmallRangeStorage, I: FusedIterator<Item = T>> FusedIterator for CollapseConsecutive<I> where
+    usize: AsPrimitive<T>
+{
+}
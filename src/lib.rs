@@ -24,13 +24,17 @@
 //!
 //! # Storage Type Support
 //!
-//! `SmallRange` supports `u16`, `u32`, `u64`, and `usize` storage types.
+//! `SmallRange` supports `u8`, `u16`, `u32`, `u64`, and `usize` storage types.
 //! Each stores start and length in half the bits, achieving 50% space savings:
 //!
 //! ```rust
 //! use small_range::SmallRange;
 //! use core::mem::size_of;
 //!
+//! // SmallRange<u8>: 1 byte (vs 2 bytes for Range<u8>)
+//! let r8 = SmallRange::<u8>::new(0, 10);
+//! assert_eq!(size_of::<SmallRange<u8>>(), 1);
+//!
 //! // SmallRange<u16>: 2 bytes (vs 4 bytes for Range<u16>)
 //! let r16 = SmallRange::<u16>::new(0, 100);
 //! assert_eq!(size_of::<SmallRange<u16>>(), 2);
@@ -59,12 +63,251 @@
 //! assert_eq!(size_of::<SmallRange<u64>>(), size_of::<Option<SmallRange<u64>>>());
 //! assert_eq!(size_of::<SmallRange<u32>>(), size_of::<Option<SmallRange<u32>>>());
 //! assert_eq!(size_of::<SmallRange<u16>>(), size_of::<Option<SmallRange<u16>>>());
+//! assert_eq!(size_of::<SmallRange<u8>>(), size_of::<Option<SmallRange<u8>>>());
 //! ```
 
+/// Asserts an encoding invariant: always with the `strict` feature, otherwise
+/// only in debug builds.
+macro_rules! strict_assert {
+    ($($arg:tt)*) => {
+        if cfg!(feature = "strict") {
+            assert!($($arg)*);
+        } else {
+            debug_assert!($($arg)*);
+        }
+    };
+}
+
+mod anchored;
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl;
+#[cfg(feature = "bincode")]
+mod bincode_impl;
+#[cfg(feature = "bytemuck")]
+mod bytemuck_impl;
+mod char_range;
+#[cfg(feature = "defmt")]
+mod defmt_impl;
+mod error;
+mod full_range;
+mod hash_as_range;
+#[cfg(feature = "alloc")]
+mod interval_tree;
+mod iter;
+#[cfg(feature = "minicbor")]
+mod minicbor_impl;
+#[cfg(feature = "nohash-hasher")]
+mod nohash_impl;
+mod non_empty;
+mod packed_range;
+#[cfg(feature = "proptest")]
+mod proptest_impl;
+#[cfg(feature = "rand")]
+mod rand_impl;
+#[cfg(feature = "alloc")]
+mod range_map;
+#[cfg(feature = "alloc")]
+mod range_set;
+#[cfg(feature = "rayon")]
+mod rayon_impl;
+mod relation;
+#[cfg(feature = "rkyv")]
+mod rkyv_impl;
+#[cfg(feature = "schemars")]
+mod schemars_impl;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod signed;
 mod small_range;
+mod small_range24;
+mod small_range_inclusive;
+mod small_rect;
+mod spanned;
+#[cfg(feature = "speedy")]
+mod speedy_impl;
+#[cfg(feature = "text-size")]
+mod text_range;
+#[cfg(feature = "ufmt")]
+mod ufmt_impl;
+
+pub use anchored::AnchoredRange;
+pub use char_range::{CharRange, Chars};
+pub use error::{ParseSmallRangeError, SmallRangeError};
+pub use full_range::FullSmallRange;
+pub use hash_as_range::HashAsRange;
+#[cfg(feature = "alloc")]
+pub use interval_tree::{IntervalTree, IntervalTreeIter, Overlapping};
+pub use iter::{
+    collapse_consecutive, Chunks, CollapseConsecutive, Pages, Partition, SmallRangeIter, StepBy,
+    Windows,
+};
+pub use non_empty::NonEmptySmallRange;
+pub use packed_range::PackedRange;
+#[cfg(feature = "alloc")]
+pub use range_map::{RangeMap, RangeMapIter};
+#[cfg(feature = "alloc")]
+pub use range_set::{Gaps, RangeSet};
+#[cfg(feature = "rayon")]
+pub use rayon_impl::SmallRangeParIter;
+pub use relation::IntervalRelation;
+#[cfg(feature = "rkyv")]
+pub use rkyv_impl::ArchivedSmallRange;
+pub use signed::{SignedSmallRange, SignedSmallRangeStorage};
+pub use small_range::{CompactDebug, SmallRange, SmallRangeStorage};
+pub use small_range24::SmallRange24;
+pub use small_range_inclusive::SmallRangeInclusive;
+pub use small_rect::SmallRect;
+pub use spanned::Spanned;
+
+/// A [`SmallRange`] stored in a `u8`: 1 byte, start and length up to 14.
+pub type SmallRange8 = SmallRange<u8>;
+
+/// A [`SmallRange`] stored in a `u16`: 2 bytes, start and length up to 254.
+pub type SmallRange16 = SmallRange<u16>;
+
+/// A [`SmallRange`] stored in a `u32`: 4 bytes, start and length up to 65,534.
+pub type SmallRange32 = SmallRange<u32>;
 
-pub use small_range::{SmallRange, SmallRangeStorage};
+/// A [`SmallRange`] stored in a `u64`: 8 bytes, start and length up to
+/// 4,294,967,294.
+pub type SmallRange64 = SmallRange<u64>;
+
+/// A [`SmallRange`] stored in a `usize`, with platform-dependent capacity.
+pub type SmallRangeUsize = SmallRange<usize>;
+
+/// A [`PackedRange`] for large offsets with short spans: 8 bytes, starts up
+/// to 2^48 - 2 (about 281 TB) and lengths up to 65,534.
+///
+/// Suits tokens, log records and other short spans within files too large
+/// for `SmallRange<u64>`'s 4.29B start limit.
+///
+/// # Examples
+/// ```
+/// use small_range::SmallSpan;
+///
+/// // A record 6 GB into a file
+/// let record = SmallSpan::new(6_000_000_000, 6_000_000_512);
+/// assert_eq!(record.len(), 512);
+/// assert_eq!(core::mem::size_of::<Option<SmallSpan>>(), 8);
+/// ```
+pub type SmallSpan = PackedRange<u64, 48>;
 
 #[cfg(test)]
 #[path = "tests/small_range_tests.rs"]
 mod tests;
+
+#[cfg(test)]
+#[path = "tests/packed_range_tests.rs"]
+mod packed_range_tests;
+
+#[cfg(test)]
+#[path = "tests/small_range24_tests.rs"]
+mod small_range24_tests;
+
+#[cfg(test)]
+#[path = "tests/small_range_inclusive_tests.rs"]
+mod small_range_inclusive_tests;
+
+#[cfg(test)]
+#[path = "tests/non_empty_tests.rs"]
+mod non_empty_tests;
+
+#[cfg(test)]
+#[path = "tests/full_range_tests.rs"]
+mod full_range_tests;
+
+#[cfg(test)]
+#[path = "tests/signed_tests.rs"]
+mod signed_tests;
+
+#[cfg(test)]
+#[path = "tests/char_range_tests.rs"]
+mod char_range_tests;
+
+#[cfg(test)]
+#[path = "tests/small_rect_tests.rs"]
+mod small_rect_tests;
+
+#[cfg(test)]
+#[path = "tests/anchored_tests.rs"]
+mod anchored_tests;
+
+#[cfg(test)]
+#[path = "tests/spanned_tests.rs"]
+mod spanned_tests;
+
+#[cfg(all(test, feature = "text-size"))]
+#[path = "tests/text_range_tests.rs"]
+mod text_range_tests;
+
+#[cfg(all(test, feature = "serde"))]
+#[path = "tests/serde_tests.rs"]
+mod serde_tests;
+
+#[cfg(all(test, feature = "schemars"))]
+#[path = "tests/schemars_tests.rs"]
+mod schemars_tests;
+
+#[cfg(all(test, feature = "rkyv"))]
+#[path = "tests/rkyv_tests.rs"]
+mod rkyv_tests;
+
+#[cfg(all(test, feature = "bincode"))]
+#[path = "tests/bincode_tests.rs"]
+mod bincode_tests;
+
+#[cfg(all(test, feature = "minicbor"))]
+#[path = "tests/minicbor_tests.rs"]
+mod minicbor_tests;
+
+#[cfg(all(test, feature = "speedy"))]
+#[path = "tests/speedy_tests.rs"]
+mod speedy_tests;
+
+#[cfg(all(test, feature = "ufmt"))]
+#[path = "tests/ufmt_tests.rs"]
+mod ufmt_tests;
+
+#[cfg(all(test, feature = "bytemuck"))]
+#[path = "tests/bytemuck_tests.rs"]
+mod bytemuck_tests;
+
+#[cfg(all(test, feature = "zerocopy"))]
+#[path = "tests/zerocopy_tests.rs"]
+mod zerocopy_tests;
+
+#[cfg(all(test, feature = "arbitrary"))]
+#[path = "tests/arbitrary_tests.rs"]
+mod arbitrary_tests;
+
+#[cfg(all(test, feature = "proptest"))]
+#[path = "tests/strategy_tests.rs"]
+mod strategy_tests;
+
+#[cfg(all(test, feature = "rand"))]
+#[path = "tests/rand_tests.rs"]
+mod rand_tests;
+
+#[cfg(all(test, feature = "nohash-hasher"))]
+#[path = "tests/nohash_tests.rs"]
+mod nohash_tests;
+
+#[cfg(test)]
+#[path = "tests/hash_as_range_tests.rs"]
+mod hash_as_range_tests;
+
+#[cfg(all(test, feature = "rayon"))]
+#[path = "tests/rayon_tests.rs"]
+mod rayon_tests;
+
+#[cfg(all(test, feature = "alloc"))]
+#[path = "tests/range_set_tests.rs"]
+mod range_set_tests;
+
+#[cfg(all(test, feature = "alloc"))]
+#[path = "tests/range_map_tests.rs"]
+mod range_map_tests;
+
+#[cfg(all(test, feature = "alloc"))]
+#[path = "tests/interval_tree_tests.rs"]
+mod interval_tree_tests;
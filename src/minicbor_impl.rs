@@ -0,0 +1,54 @@
+//! minicbor `Encode`, `Decode` and `CborLen` for `SmallRange`.
+//!
+//! A range is a definite-length 2-element array `[start, end]`, like a tuple,
+//! so CBOR tools can read it without knowing the packed encoding. Decoding
+//! re-validates the bounds, so a range that can't be stored is an error
+//! rather than a corrupt value.
+
+use minicbor::decode::{self, Decoder};
+use minicbor::encode::{self, Encoder, Write};
+use minicbor::{CborLen, Decode, Encode};
+use num_traits::AsPrimitive;
+
+use crate::{SmallRange, SmallRangeStorage};
+
+impl<C, T: SmallRangeStorage + Encode<C>> Encode<C> for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    fn encode<W: Write>(
+        &self,
+        e: &mut Encoder<W>,
+        ctx: &mut C,
+    ) -> Result<(), encode::Error<W::Error>> {
+        e.array(2)?
+            .encode_with(self.start(), ctx)?
+            .encode_with(self.end(), ctx)?;
+        Ok(())
+    }
+}
+
+impl<'b, C, T: SmallRangeStorage + Decode<'b, C>> Decode<'b, C> for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, decode::Error> {
+        let p = d.position();
+        if d.array()? != Some(2) {
+            return Err(decode::Error::message("expected a [start, end] array").at(p));
+        }
+        let start = T::decode(d, ctx)?;
+        let end = T::decode(d, ctx)?;
+        SmallRange::try_new_checked(start, end)
+            .map_err(|err| decode::Error::message(err.as_str()).at(p))
+    }
+}
+
+impl<C, T: SmallRangeStorage + CborLen<C>> CborLen<C> for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    fn cbor_len(&self, ctx: &mut C) -> usize {
+        2.cbor_len(ctx) + self.start().cbor_len(ctx) + self.end().cbor_len(ctx)
+    }
+}
@@ -0,0 +1,13 @@
+//! nohash-hasher `IsEnabled` for `SmallRange`.
+//!
+//! `Hash` for `SmallRange` writes its packed representation as a single
+//! integer, which is exactly what `NoHashHasher` requires, so a
+//! `SmallRange` can key an `IntMap` or `IntSet` without going through
+//! SipHash.
+
+use nohash_hasher::IsEnabled;
+use num_traits::AsPrimitive;
+
+use crate::{SmallRange, SmallRangeStorage};
+
+impl<T: SmallRangeStorage> IsEnabled for SmallRange<T> where usize: AsPrimitive<T> {}
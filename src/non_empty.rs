@@ -0,0 +1,183 @@
+use core::fmt;
+use core::ops::Range;
+
+use num_traits::AsPrimitive;
+
+use crate::{SmallRange, SmallRangeStorage};
+
+/// A [`SmallRange`] that is guaranteed to hold at least one value.
+///
+/// Because the range is never empty, [`first`](Self::first),
+/// [`last`](Self::last) and [`midpoint`](Self::midpoint) return values
+/// directly instead of `Option`, and [`overlaps`](Self::overlaps) needs no
+/// empty-range special cases. It has the same layout and niche as
+/// `SmallRange<T>`.
+///
+/// # Examples
+/// ```
+/// use small_range::{NonEmptySmallRange, SmallRange};
+///
+/// let token = NonEmptySmallRange::<u32>::new(10, 15);
+/// assert_eq!(token.first(), 10);
+/// assert_eq!(token.last(), 14);
+/// assert_eq!(token.midpoint(), 12);
+///
+/// assert!(NonEmptySmallRange::from_small_range(SmallRange::<u32>::new(5, 5)).is_none());
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NonEmptySmallRange<T: SmallRangeStorage = u64>
+where
+    usize: AsPrimitive<T>,
+{
+    inner: SmallRange<T>,
+}
+
+impl<T: SmallRangeStorage> NonEmptySmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    /// Creates a new `NonEmptySmallRange` covering `start..end`.
+    ///
+    /// # Panics (debug only)
+    /// - If start is not less than end
+    /// - If start or length exceed the half-width capacity
+    #[inline]
+    pub fn new(start: T, end: T) -> Self {
+        strict_assert!(start < end, "range must not be empty");
+        Self {
+            inner: SmallRange::new(start, end),
+        }
+    }
+
+    /// Creates a new `NonEmptySmallRange` if `start < end` and the values fit,
+    /// returns `None` otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::NonEmptySmallRange;
+    ///
+    /// assert!(NonEmptySmallRange::<u16>::try_new(10, 11).is_some());
+    /// assert!(NonEmptySmallRange::<u16>::try_new(10, 10).is_none());
+    /// assert!(NonEmptySmallRange::<u16>::try_new(0, 255).is_none());
+    /// ```
+    #[inline]
+    pub fn try_new(start: T, end: T) -> Option<Self> {
+        if start >= end {
+            return None;
+        }
+        SmallRange::try_new(start, end).map(|inner| Self { inner })
+    }
+
+    /// Wraps a `SmallRange`, or returns `None` if it is empty.
+    #[inline]
+    pub fn from_small_range(range: SmallRange<T>) -> Option<Self> {
+        if range.is_empty() {
+            return None;
+        }
+        Some(Self { inner: range })
+    }
+
+    /// Returns the underlying `SmallRange`.
+    #[inline]
+    pub fn as_small_range(&self) -> SmallRange<T> {
+        self.inner
+    }
+
+    /// Returns the start of the range.
+    #[inline]
+    pub fn start(&self) -> T {
+        self.inner.start()
+    }
+
+    /// Returns the end of the range (exclusive).
+    #[inline]
+    pub fn end(&self) -> T {
+        self.inner.end()
+    }
+
+    /// Returns the length of the range, which is always at least 1.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Always returns `false`.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the first value in the range, which is its start.
+    #[inline]
+    pub fn first(&self) -> T {
+        self.inner.start()
+    }
+
+    /// Returns the last value in the range, `end - 1`.
+    #[inline]
+    pub fn last(&self) -> T {
+        self.inner.end() - T::one()
+    }
+
+    /// Returns the middle element of the range.
+    ///
+    /// Same as [`SmallRange::midpoint`]: `start + len / 2`, so for even
+    /// lengths it is the upper of the two middle elements.
+    #[inline]
+    pub fn midpoint(&self) -> T {
+        let (start, length) = self.inner.to_start_len();
+        start + (length >> 1)
+    }
+
+    /// Returns `true` if the range contains the given value.
+    #[inline]
+    pub fn contains(&self, value: T) -> bool {
+        self.inner.contains(value)
+    }
+
+    /// Returns `true` if the two ranges share at least one value.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::NonEmptySmallRange;
+    ///
+    /// let a = NonEmptySmallRange::<u32>::new(10, 20);
+    /// assert!(a.overlaps(&NonEmptySmallRange::new(19, 30)));
+    /// assert!(!a.overlaps(&NonEmptySmallRange::new(20, 30)));
+    /// ```
+    #[inline]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        let (start, end) = self.inner.to_parts();
+        let (other_start, other_end) = other.inner.to_parts();
+        start < other_end && other_start < end
+    }
+
+    /// Converts to a standard `Range<T>`.
+    #[inline]
+    pub fn to_range(&self) -> Range<T> {
+        self.inner.to_range()
+    }
+}
+
+impl<T: SmallRangeStorage + fmt::Debug> fmt::Debug for NonEmptySmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NonEmptySmallRange")
+            .field("start", &self.start())
+            .field("end", &self.end())
+            .finish()
+    }
+}
+
+impl<T: SmallRangeStorage> From<NonEmptySmallRange<T>> for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    fn from(range: NonEmptySmallRange<T>) -> Self {
+        range.inner
+    }
+}
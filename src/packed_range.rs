@@ -0,0 +1,292 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::Hash;
+use core::ops::Range;
+
+use num_traits::AsPrimitive;
+
+use crate::{SmallRange, SmallRangeStorage};
+
+/// A compact range with a configurable split between start and length bits.
+///
+/// [`SmallRange`] gives the start and the length half of the storage each.
+/// `PackedRange` lets the split be chosen at compile time: the start gets
+/// `START_BITS` bits and the length gets the rest. This suits workloads such
+/// as huge offsets with tiny lengths, where a 50/50 split wastes capacity.
+///
+/// The encoding is the same `(start+1, length+1)` scheme, so neither field is
+/// ever zero and `Option<PackedRange<T, START_BITS>>` is the same size as
+/// `PackedRange<T, START_BITS>`.
+///
+/// # Type Parameters
+/// - `T`: The storage type, as for `SmallRange`.
+/// - `START_BITS`: Bits for the start. Must be between 1 and the storage
+///   width minus 1; other values fail to compile.
+///
+/// # Capacity
+/// - Max start: `2^START_BITS - 2`
+/// - Max length: `2^LEN_BITS - 2`, where `LEN_BITS` is the storage width
+///   minus `START_BITS`
+///
+/// # Examples
+/// ```
+/// use small_range::PackedRange;
+///
+/// // 40-bit offsets with 24-bit lengths
+/// type Span = PackedRange<u64, 40>;
+///
+/// let span = Span::new(10_000_000_000, 10_000_000_100);
+/// assert_eq!(span.start(), 10_000_000_000);
+/// assert_eq!(span.len(), 100);
+/// assert_eq!(core::mem::size_of::<Option<Span>>(), 8);
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PackedRange<T: SmallRangeStorage, const START_BITS: u32>
+where
+    usize: AsPrimitive<T>,
+{
+    bits: T::NonZeroStorage,
+}
+
+impl<T: SmallRangeStorage, const START_BITS: u32> PackedRange<T, START_BITS>
+where
+    usize: AsPrimitive<T>,
+{
+    /// Bits for the length: the storage width minus `START_BITS`.
+    pub const LEN_BITS: u32 = {
+        assert!(
+            START_BITS > 0 && START_BITS < T::HALF_BITS * 2,
+            "START_BITS must leave at least one bit for each field"
+        );
+        T::HALF_BITS * 2 - START_BITS
+    };
+
+    #[inline]
+    fn len_mask() -> T {
+        (T::one() << Self::LEN_BITS as usize) - T::one()
+    }
+
+    #[inline]
+    fn start_mask() -> T {
+        (T::one() << START_BITS as usize) - T::one()
+    }
+
+    /// Returns the largest start this configuration can hold.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::PackedRange;
+    ///
+    /// assert_eq!(PackedRange::<u16, 12>::max_start(), 4094);
+    /// assert_eq!(PackedRange::<u16, 12>::max_len(), 14);
+    /// ```
+    #[inline]
+    pub fn max_start() -> T {
+        Self::start_mask() - T::one()
+    }
+
+    /// Returns the largest length this configuration can hold.
+    #[inline]
+    pub fn max_len() -> T {
+        Self::len_mask() - T::one()
+    }
+
+    #[inline]
+    fn encode_start_length(start: T, length: T) -> T::NonZeroStorage {
+        strict_assert!(
+            start < Self::start_mask(),
+            "start exceeds START_BITS capacity"
+        );
+        strict_assert!(
+            length < Self::len_mask(),
+            "length exceeds LEN_BITS capacity"
+        );
+        let hi = start + T::one();
+        let lo = length + T::one();
+        let packed = (hi << Self::LEN_BITS as usize) | lo;
+        // SAFETY: packed is never zero because lo >= 1
+        unsafe { T::new_nonzero_unchecked(packed) }
+    }
+
+    #[inline]
+    fn decode_start_length(bits: T::NonZeroStorage) -> (T, T) {
+        let packed = T::get_nonzero(bits);
+        let hi = packed >> Self::LEN_BITS as usize;
+        let lo = packed & Self::len_mask();
+        (hi - T::one(), lo - T::one())
+    }
+
+    /// Creates a new `PackedRange` with the given start and end values.
+    ///
+    /// # Panics (debug only)
+    /// - If start exceeds end
+    /// - If start or length exceed their field's capacity
+    #[inline]
+    pub fn new(start: T, end: T) -> Self {
+        strict_assert!(start <= end, "start must not exceed end");
+        Self::from_start_len(start, end - start)
+    }
+
+    /// Creates a new `PackedRange` from a start value and a length.
+    ///
+    /// # Panics (debug only)
+    /// - If start or length exceed their field's capacity
+    #[inline]
+    pub fn from_start_len(start: T, len: T) -> Self {
+        Self {
+            bits: Self::encode_start_length(start, len),
+        }
+    }
+
+    /// Creates a new `PackedRange` if the values are valid, returns `None`
+    /// otherwise.
+    ///
+    /// Returns `None` if `start > end`, or if the start or length exceed their
+    /// field's capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::PackedRange;
+    ///
+    /// assert!(PackedRange::<u16, 12>::try_new(4000, 4010).is_some());
+    /// assert!(PackedRange::<u16, 12>::try_new(4000, 4020).is_none());
+    /// ```
+    #[inline]
+    pub fn try_new(start: T, end: T) -> Option<Self> {
+        if start > end {
+            return None;
+        }
+        Self::try_from_start_len(start, end - start)
+    }
+
+    /// Creates a new `PackedRange` from a start and length, or returns `None`
+    /// if either exceeds its field's capacity.
+    #[inline]
+    pub fn try_from_start_len(start: T, len: T) -> Option<Self> {
+        // Compare before adding 1 so that values near T::MAX can't overflow
+        if start >= Self::start_mask() || len >= Self::len_mask() {
+            return None;
+        }
+        Some(Self::from_start_len(start, len))
+    }
+
+    /// Returns the start of the range.
+    #[inline]
+    pub fn start(&self) -> T {
+        Self::decode_start_length(self.bits).0
+    }
+
+    /// Returns the end of the range (exclusive).
+    #[inline]
+    pub fn end(&self) -> T {
+        let (start, length) = Self::decode_start_length(self.bits);
+        start + length
+    }
+
+    /// Returns the length of the range.
+    #[inline]
+    pub fn len(&self) -> usize {
+        Self::decode_start_length(self.bits).1.as_()
+    }
+
+    /// Returns `true` if the range is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        Self::decode_start_length(self.bits).1.is_zero()
+    }
+
+    /// Returns `true` if the range contains the given value.
+    #[inline]
+    pub fn contains(&self, value: T) -> bool {
+        let (start, length) = Self::decode_start_length(self.bits);
+        value >= start && value - start < length
+    }
+
+    /// Converts the `PackedRange` to a standard `Range<T>`.
+    #[inline]
+    pub fn to_range(&self) -> Range<T> {
+        let (start, length) = Self::decode_start_length(self.bits);
+        start..(start + length)
+    }
+
+    /// Converts to a `SmallRange` with the same storage, if it fits the
+    /// 50/50 split.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{PackedRange, SmallRange};
+    ///
+    /// let packed = PackedRange::<u32, 24>::new(100, 110);
+    /// assert_eq!(packed.to_small_range(), Some(SmallRange::new(100, 110)));
+    /// ```
+    #[inline]
+    pub fn to_small_range(&self) -> Option<SmallRange<T>> {
+        let (start, length) = Self::decode_start_length(self.bits);
+        SmallRange::try_from_start_len(start, length)
+    }
+
+    /// Converts from a `SmallRange` with the same storage, if it fits this
+    /// split.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{PackedRange, SmallRange};
+    ///
+    /// let small = SmallRange::<u32>::new(100, 120);
+    /// assert_eq!(PackedRange::<u32, 24>::from_small_range(small), Some(PackedRange::new(100, 120)));
+    ///
+    /// // Only 4 bits, so at most 14 values, for the length
+    /// assert_eq!(PackedRange::<u32, 28>::from_small_range(small), None);
+    /// ```
+    #[inline]
+    pub fn from_small_range(range: SmallRange<T>) -> Option<Self> {
+        let (start, length) = range.to_start_len();
+        Self::try_from_start_len(start, length)
+    }
+}
+
+impl<T: SmallRangeStorage, const START_BITS: u32> Default for PackedRange<T, START_BITS>
+where
+    usize: AsPrimitive<T>,
+{
+    fn default() -> Self {
+        Self::from_start_len(T::zero(), T::zero())
+    }
+}
+
+impl<T: SmallRangeStorage + fmt::Debug, const START_BITS: u32> fmt::Debug
+    for PackedRange<T, START_BITS>
+where
+    usize: AsPrimitive<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PackedRange")
+            .field("start", &self.start())
+            .field("end", &self.end())
+            .finish()
+    }
+}
+
+/// Orders ranges by start, then by end, like [`SmallRange`].
+impl<T: SmallRangeStorage, const START_BITS: u32> Ord for PackedRange<T, START_BITS>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        // start+1 occupies the high bits, and for equal starts length+1
+        // orders the same as the end.
+        T::get_nonzero(self.bits).cmp(&T::get_nonzero(other.bits))
+    }
+}
+
+impl<T: SmallRangeStorage, const START_BITS: u32> PartialOrd for PackedRange<T, START_BITS>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
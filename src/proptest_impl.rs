@@ -0,0 +1,82 @@
+//! proptest `Arbitrary` for `SmallRange`, plus strategy helpers.
+//!
+//! Every strategy draws the start and length separately, so generated ranges
+//! are always valid and shrink toward `0..0` or the low end of the bounds.
+
+use core::fmt;
+use core::ops::{Range, RangeInclusive};
+
+use num_traits::AsPrimitive;
+use proptest::arbitrary::Arbitrary;
+use proptest::strategy::{Map, Strategy};
+
+use crate::{SmallRange, SmallRangeStorage};
+
+impl<T: SmallRangeStorage + fmt::Debug> Arbitrary for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+    RangeInclusive<T>: Strategy<Value = T>,
+{
+    type Parameters = ();
+    type Strategy = Map<(RangeInclusive<T>, RangeInclusive<T>), fn((T, T)) -> Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (T::zero()..=Self::MAX_START, T::zero()..=Self::MAX_LEN)
+            .prop_map(|(start, len)| Self::from_start_len(start, len))
+    }
+}
+
+impl<T: SmallRangeStorage + fmt::Debug> SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+    RangeInclusive<T>: Strategy<Value = T>,
+{
+    /// Returns a strategy for ranges that lie inside `bounds`, so every
+    /// generated `range` has `bounds.start <= range.start()` and
+    /// `range.end() <= bounds.end`.
+    ///
+    /// # Panics
+    /// - If `bounds` is reversed or its start exceeds `MAX_START`
+    ///
+    /// # Examples
+    /// ```
+    /// use proptest::prelude::*;
+    /// use small_range::SmallRange;
+    ///
+    /// proptest!(|(range in SmallRange::<u32>::strategy_within(100..200))| {
+    ///     prop_assert!(range.start() >= 100 && range.end() <= 200);
+    /// });
+    /// ```
+    pub fn strategy_within(bounds: Range<T>) -> impl Strategy<Value = Self> {
+        assert!(bounds.start <= bounds.end, "bounds must not be reversed");
+        assert!(
+            bounds.start <= Self::MAX_START,
+            "bounds start exceeds half-width capacity"
+        );
+        let Range { start: lo, end: hi } = bounds;
+        (lo..=hi.min(Self::MAX_START)).prop_flat_map(move |start| {
+            let max_len = (hi - start).min(Self::MAX_LEN);
+            (T::zero()..=max_len).prop_map(move |len| Self::from_start_len(start, len))
+        })
+    }
+
+    /// Returns a strategy for ranges anywhere in the storage's capacity that
+    /// are at most `max_len` long.
+    ///
+    /// # Examples
+    /// ```
+    /// use proptest::prelude::*;
+    /// use small_range::SmallRange;
+    ///
+    /// proptest!(|(range in SmallRange::<u16>::strategy_with_max_len(8))| {
+    ///     prop_assert!(range.len() <= 8);
+    /// });
+    /// ```
+    pub fn strategy_with_max_len(max_len: T) -> impl Strategy<Value = Self> {
+        (
+            T::zero()..=Self::MAX_START,
+            T::zero()..=max_len.min(Self::MAX_LEN),
+        )
+            .prop_map(|(start, len)| Self::from_start_len(start, len))
+    }
+}
@@ -0,0 +1,55 @@
+//! rand `SampleRange` for `SmallRange`, plus [`SmallRange::sample`].
+//!
+//! Sampling goes through the uniform sampler for `Range<T>`, so a
+//! `SmallRange` gives exactly the distribution of its `to_range()`.
+
+use num_traits::AsPrimitive;
+use rand::distr::uniform::{Error, SampleRange, SampleUniform};
+use rand::{Rng, RngCore};
+
+use crate::{SmallRange, SmallRangeStorage};
+
+impl<T: SmallRangeStorage + SampleUniform> SampleRange<T> for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    fn sample_single<R: RngCore + ?Sized>(self, rng: &mut R) -> Result<T, Error> {
+        self.to_range().sample_single(rng)
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        SmallRange::is_empty(self)
+    }
+}
+
+impl<T: SmallRangeStorage + SampleUniform> SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    /// Returns a value drawn uniformly from the range, or `None` if the range
+    /// is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use rand::rngs::SmallRng;
+    /// use rand::{Rng, SeedableRng};
+    /// use small_range::SmallRange;
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(7);
+    /// let bucket = SmallRange::<u32>::new(100, 200);
+    ///
+    /// let value = bucket.sample(&mut rng).unwrap();
+    /// assert!(bucket.contains(value));
+    ///
+    /// // SmallRange also works wherever rand takes a range
+    /// assert!(bucket.contains(rng.random_range(bucket)));
+    ///
+    /// assert_eq!(SmallRange::<u32>::new(5, 5).sample(&mut rng), None);
+    /// ```
+    #[inline]
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<T> {
+        self.sample_single(rng).ok()
+    }
+}
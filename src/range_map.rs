@@ -0,0 +1,420 @@
+extern crate alloc;
+
+use alloc::vec::{self, Vec};
+use core::fmt;
+use core::iter::{self, FusedIterator};
+use core::slice;
+
+use num_traits::AsPrimitive;
+
+use crate::{SmallRange, SmallRangeError, SmallRangeStorage};
+
+type Entry<T, V> = (SmallRange<T>, V);
+
+/// A map from non-overlapping [`SmallRange`]s to values.
+///
+/// Inserting a range overwrites whatever it covers: entries it overlaps are
+/// trimmed, or split in two when it lands in their middle, which is why
+/// insertion needs `V: Clone`. Touching entries stay separate even when their
+/// values are equal. The entries are kept sorted in a single `Vec`, so point
+/// lookups are a binary search.
+///
+/// A piece of an entry left after a new or removed range starts where that
+/// range ends, which may be past `MAX_START`. No range can hold such a piece,
+/// so [`insert`](Self::insert) and [`remove`](Self::remove) fail and leave
+/// the map unchanged instead, while `extend` and `collect` panic.
+///
+/// Since touching entries aren't merged, `==` and `Hash` compare the entries
+/// rather than the mapping they describe: a map built from `0..10 => 'a'`
+/// differs from one built from `0..5 => 'a'` and `5..10 => 'a'`, although
+/// both give every value the same `get` result.
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+/// ```
+/// use small_range::{RangeMap, SmallRange};
+///
+/// let mut regions = RangeMap::<u32, &str>::new();
+/// regions.insert(SmallRange::new(0, 100), "rw").unwrap();
+/// regions.insert(SmallRange::new(40, 60), "ro").unwrap();
+///
+/// assert_eq!(regions.get(10), Some(&"rw"));
+/// assert_eq!(regions.get(50), Some(&"ro"));
+/// assert_eq!(regions.get(80), Some(&"rw"));
+/// assert_eq!(regions.get(100), None);
+/// assert_eq!(regions.len(), 3);
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct RangeMap<T: SmallRangeStorage, V>
+where
+    usize: AsPrimitive<T>,
+{
+    // Sorted by start, with non-empty and disjoint ranges
+    entries: Vec<Entry<T, V>>,
+}
+
+impl<T: SmallRangeStorage, V> RangeMap<T, V>
+where
+    usize: AsPrimitive<T>,
+{
+    /// Creates an empty map.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Returns the number of entries.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the map has no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes every entry.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns the value of the entry containing `point`.
+    #[inline]
+    pub fn get(&self, point: T) -> Option<&V> {
+        self.get_key_value(point).map(|(_, value)| value)
+    }
+
+    /// Returns a mutable reference to the value of the entry containing
+    /// `point`.
+    #[inline]
+    pub fn get_mut(&mut self, point: T) -> Option<&mut V> {
+        let index = self.index_of(point)?;
+        Some(&mut self.entries[index].1)
+    }
+
+    /// Returns the range and value of the entry containing `point`.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{RangeMap, SmallRange};
+    ///
+    /// let mut symbols = RangeMap::<u32, &str>::new();
+    /// symbols.insert(SmallRange::new(0x100, 0x180), "main").unwrap();
+    /// assert_eq!(
+    ///     symbols.get_key_value(0x120),
+    ///     Some((SmallRange::new(0x100, 0x180), &"main"))
+    /// );
+    /// ```
+    #[inline]
+    pub fn get_key_value(&self, point: T) -> Option<(SmallRange<T>, &V)> {
+        let (range, value) = &self.entries[self.index_of(point)?];
+        Some((*range, value))
+    }
+
+    /// Returns `true` if an entry contains `point`.
+    #[inline]
+    pub fn contains(&self, point: T) -> bool {
+        self.index_of(point).is_some()
+    }
+
+    /// Returns an iterator over the entries in ascending order.
+    #[inline]
+    pub fn iter(&self) -> RangeMapIter<'_, T, V> {
+        RangeMapIter {
+            entries: self.entries.iter(),
+        }
+    }
+
+    /// Returns an iterator over the entries overlapping `query`, in
+    /// ascending order.
+    ///
+    /// The entries are found by binary search, so this takes O(log n) plus
+    /// the number of entries yielded. As with [`SmallRange::overlaps`], an
+    /// entry that only touches `query` doesn't overlap it, and an empty
+    /// `query` overlaps nothing.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{RangeMap, SmallRange};
+    ///
+    /// let map: RangeMap<u32, &str> = [
+    ///     (SmallRange::new(0, 10), "a"),
+    ///     (SmallRange::new(10, 20), "b"),
+    ///     (SmallRange::new(30, 40), "c"),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let hits: Vec<_> = map.overlapping(SmallRange::new(5, 30)).map(|(_, v)| *v).collect();
+    /// assert_eq!(hits, ["a", "b"]);
+    /// ```
+    pub fn overlapping(&self, query: SmallRange<T>) -> RangeMapIter<'_, T, V> {
+        let (lo, hi) = if query.is_empty() {
+            (0, 0)
+        } else {
+            self.overlapping_indices(&query)
+        };
+        RangeMapIter {
+            entries: self.entries[lo..hi].iter(),
+        }
+    }
+
+    /// Returns the index of the entry containing `point`.
+    fn index_of(&self, point: T) -> Option<usize> {
+        let index = self
+            .entries
+            .partition_point(|(range, _)| range.end() <= point);
+        let (range, _) = self.entries.get(index)?;
+        (range.start() <= point).then_some(index)
+    }
+
+    /// Returns the indices of the entries overlapping a non-empty `range`.
+    fn overlapping_indices(&self, range: &SmallRange<T>) -> (usize, usize) {
+        let lo = self
+            .entries
+            .partition_point(|(entry, _)| entry.end() <= range.start());
+        let hi = self
+            .entries
+            .partition_point(|(entry, _)| entry.start() < range.end());
+        (lo, hi)
+    }
+}
+
+impl<T: SmallRangeStorage, V: Clone> RangeMap<T, V>
+where
+    usize: AsPrimitive<T>,
+{
+    /// Maps every value of `range` to `value`, trimming or splitting the
+    /// entries it overlaps.
+    ///
+    /// Inserting an empty range does nothing.
+    ///
+    /// # Errors
+    /// - [`SmallRangeError::StartTooLarge`] if the piece of an entry left
+    ///   after `range` would start beyond the half-width capacity, which can
+    ///   happen when `range` ends past `MAX_START`. The map is left unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{RangeMap, SmallRange, SmallRangeError};
+    ///
+    /// let mut map = RangeMap::<u32, char>::new();
+    /// map.insert(SmallRange::new(0, 10), 'a').unwrap();
+    /// map.insert(SmallRange::new(8, 20), 'b').unwrap();
+    /// assert_eq!(
+    ///     map.iter().collect::<Vec<_>>(),
+    ///     [(SmallRange::new(0, 8), &'a'), (SmallRange::new(8, 20), &'b')]
+    /// );
+    ///
+    /// // 300..400 would stay 'a', but u16 starts max out at 254
+    /// let mut wide = RangeMap::<u16, char>::new();
+    /// wide.insert(SmallRange::new(200, 400), 'a').unwrap();
+    /// assert_eq!(
+    ///     wide.insert(SmallRange::new(200, 300), 'b'),
+    ///     Err(SmallRangeError::StartTooLarge)
+    /// );
+    /// ```
+    pub fn insert(&mut self, range: SmallRange<T>, value: V) -> Result<(), SmallRangeError> {
+        if range.is_empty() {
+            return Ok(());
+        }
+        let (lo, hi) = self.overlapping_indices(&range);
+        let (before, after) = if lo < hi {
+            (
+                self.piece_before(lo, &range),
+                self.piece_after(hi - 1, &range)?,
+            )
+        } else {
+            (None, None)
+        };
+        self.entries.splice(
+            lo..hi,
+            before
+                .into_iter()
+                .chain(iter::once((range, value)))
+                .chain(after),
+        );
+        Ok(())
+    }
+
+    /// Removes every value of `range` from the map, trimming or splitting
+    /// the entries it overlaps.
+    ///
+    /// Returns `Ok(true)` if any entry was changed.
+    ///
+    /// # Errors
+    /// - [`SmallRangeError::StartTooLarge`] if the piece of an entry left
+    ///   after `range` would start beyond the half-width capacity, which can
+    ///   happen when `range` ends past `MAX_START`. The map is left unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{RangeMap, SmallRange};
+    ///
+    /// let mut map = RangeMap::<u32, char>::new();
+    /// map.insert(SmallRange::new(0, 20), 'a').unwrap();
+    /// assert_eq!(map.remove(SmallRange::new(5, 10)), Ok(true));
+    /// assert_eq!(
+    ///     map.iter().collect::<Vec<_>>(),
+    ///     [(SmallRange::new(0, 5), &'a'), (SmallRange::new(10, 20), &'a')]
+    /// );
+    /// ```
+    pub fn remove(&mut self, range: SmallRange<T>) -> Result<bool, SmallRangeError> {
+        if range.is_empty() {
+            return Ok(false);
+        }
+        let (lo, hi) = self.overlapping_indices(&range);
+        if lo == hi {
+            return Ok(false);
+        }
+        let before = self.piece_before(lo, &range);
+        let after = self.piece_after(hi - 1, &range)?;
+        self.entries.splice(lo..hi, before.into_iter().chain(after));
+        Ok(true)
+    }
+
+    /// Returns the piece of the entry at `index` that starts before `range`.
+    fn piece_before(&self, index: usize, range: &SmallRange<T>) -> Option<Entry<T, V>> {
+        let (entry, value) = self.entries.get(index)?;
+        (entry.start() < range.start())
+            .then(|| (SmallRange::new(entry.start(), range.start()), value.clone()))
+    }
+
+    /// Returns the piece of the entry at `index` that ends after `range`, or
+    /// an error if it starts beyond the half-width capacity.
+    fn piece_after(
+        &self,
+        index: usize,
+        range: &SmallRange<T>,
+    ) -> Result<Option<Entry<T, V>>, SmallRangeError> {
+        let Some((entry, value)) = self.entries.get(index) else {
+            return Ok(None);
+        };
+        if entry.end() <= range.end() {
+            return Ok(None);
+        }
+        let piece = SmallRange::try_new_checked(range.end(), entry.end())?;
+        Ok(Some((piece, value.clone())))
+    }
+}
+
+impl<T: SmallRangeStorage, V> Default for RangeMap<T, V>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: SmallRangeStorage + fmt::Debug, V: fmt::Debug> fmt::Debug for RangeMap<T, V>
+where
+    usize: AsPrimitive<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<T: SmallRangeStorage, V: Clone> Extend<(SmallRange<T>, V)> for RangeMap<T, V>
+where
+    usize: AsPrimitive<T>,
+{
+    fn extend<I: IntoIterator<Item = (SmallRange<T>, V)>>(&mut self, entries: I) {
+        for (range, value) in entries {
+            self.insert(range, value)
+                .expect("entry piece starts beyond the half-width capacity");
+        }
+    }
+}
+
+impl<T: SmallRangeStorage, V: Clone> FromIterator<(SmallRange<T>, V)> for RangeMap<T, V>
+where
+    usize: AsPrimitive<T>,
+{
+    fn from_iter<I: IntoIterator<Item = (SmallRange<T>, V)>>(entries: I) -> Self {
+        let mut map = Self::new();
+        map.extend(entries);
+        map
+    }
+}
+
+impl<T: SmallRangeStorage, V> IntoIterator for RangeMap<T, V>
+where
+    usize: AsPrimitive<T>,
+{
+    type Item = (SmallRange<T>, V);
+    type IntoIter = vec::IntoIter<(SmallRange<T>, V)>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<'a, T: SmallRangeStorage, V> IntoIterator for &'a RangeMap<T, V>
+where
+    usize: AsPrimitive<T>,
+{
+    type Item = (SmallRange<T>, &'a V);
+    type IntoIter = RangeMapIter<'a, T, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the entries of a [`RangeMap`] in ascending order.
+///
+/// Created by [`RangeMap::iter`].
+#[derive(Debug, Clone)]
+pub struct RangeMapIter<'a, T: SmallRangeStorage, V>
+where
+    usize: AsPrimitive<T>,
+{
+    entries: slice::Iter<'a, (SmallRange<T>, V)>,
+}
+
+impl<'a, T: SmallRangeStorage, V> Iterator for RangeMapIter<'a, T, V>
+where
+    usize: AsPrimitive<T>,
+{
+    type Item = (SmallRange<T>, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries.next().map(|(range, value)| (*range, value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.entries.size_hint()
+    }
+}
+
+impl<T: SmallRangeStorage, V> DoubleEndedIterator for RangeMapIter<'_, T, V>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.entries
+            .next_back()
+            .map(|(range, value)| (*range, value))
+    }
+}
+
+impl<T: SmallRangeStorage, V> ExactSizeIterator for RangeMapIter<'_, T, V> where
+    usize: AsPrimitive<T>
+{
+}
+
+impl<T: SmallRangeStorage, V> FusedIterator for RangeMapIter<'_, T, V> where usize: AsPrimitive<T> {}
@@ -0,0 +1,714 @@
+extern crate alloc;
+
+use alloc::vec::{self, Vec};
+use core::fmt;
+use core::iter::{Copied, FusedIterator};
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign};
+use core::slice;
+
+use num_traits::AsPrimitive;
+
+use crate::{SmallRange, SmallRangeError, SmallRangeStorage};
+
+/// A set of values stored as sorted, non-overlapping [`SmallRange`]s.
+///
+/// Inserting a range merges it with every range it overlaps or touches, so
+/// the set always holds the fewest ranges covering its values. The ranges are
+/// packed in a single `Vec`, so lookups are a binary search over half the
+/// memory a `Vec<Range<T>>` would take.
+///
+/// A run of consecutive values longer than `MAX_LEN` doesn't fit in one
+/// `SmallRange`, so it is stored as several adjacent ranges. This only shows
+/// in what [`iter`](Self::iter) yields; every query treats the run as a whole.
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+/// ```
+/// use small_range::{RangeSet, SmallRange};
+///
+/// let mut received = RangeSet::<u32>::new();
+/// received.insert(SmallRange::new(0, 100));
+/// received.insert(SmallRange::new(200, 300));
+/// received.insert(SmallRange::new(100, 150));
+///
+/// assert!(received.contains(120));
+/// assert!(!received.contains(170));
+/// assert_eq!(received.len(), 250);
+/// assert_eq!(
+///     received.iter().collect::<Vec<_>>(),
+///     [SmallRange::new(0, 150), SmallRange::new(200, 300)]
+/// );
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct RangeSet<T: SmallRangeStorage = u64>
+where
+    usize: AsPrimitive<T>,
+{
+    // Sorted, non-empty and disjoint. Two ranges only touch when they are
+    // pieces of one run too long for a single range; such a run is split
+    // from its end in `MAX_LEN` pieces, so every set has one representation.
+    ranges: Vec<SmallRange<T>>,
+}
+
+impl<T: SmallRangeStorage> RangeSet<T>
+where
+    usize: AsPrimitive<T>,
+{
+    /// Creates an empty set.
+    #[inline]
+    pub fn new() -> Self {
+        Self { ranges: Vec::new() }
+    }
+
+    /// Returns the number of values in the set.
+    pub fn len(&self) -> usize {
+        self.ranges.iter().map(SmallRange::len).sum()
+    }
+
+    /// Returns `true` if the set holds no values.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Returns the number of ranges [`iter`](Self::iter) yields.
+    #[inline]
+    pub fn range_count(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// Removes every value from the set.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.ranges.clear();
+    }
+
+    /// Returns `true` if the set contains the value.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{RangeSet, SmallRange};
+    ///
+    /// let set: RangeSet<u32> = [SmallRange::new(10, 20)].into_iter().collect();
+    /// assert!(set.contains(10));
+    /// assert!(!set.contains(20));
+    /// ```
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.ranges.partition_point(|range| range.end() <= value);
+        self.ranges
+            .get(index)
+            .is_some_and(|range| range.start() <= value)
+    }
+
+    /// Returns `true` if every value of `range` is in the set.
+    ///
+    /// An empty range contains no values, so it is contained in every set.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{RangeSet, SmallRange};
+    ///
+    /// let set: RangeSet<u32> = [SmallRange::new(0, 10), SmallRange::new(20, 30)]
+    ///     .into_iter()
+    ///     .collect();
+    /// assert!(set.contains_range(&SmallRange::new(2, 8)));
+    /// assert!(!set.contains_range(&SmallRange::new(5, 25)));
+    /// ```
+    pub fn contains_range(&self, range: &SmallRange<T>) -> bool {
+        if range.is_empty() {
+            return true;
+        }
+        let (start, end) = range.to_parts();
+        let index = self.ranges.partition_point(|range| range.end() <= start);
+        let Some(first) = self.ranges.get(index) else {
+            return false;
+        };
+        if first.start() > start {
+            return false;
+        }
+        // Walk the pieces of a run too long for a single range
+        let mut covered = first.end();
+        for next in &self.ranges[index + 1..] {
+            if covered >= end || next.start() != covered {
+                break;
+            }
+            covered = next.end();
+        }
+        covered >= end
+    }
+
+    /// Adds every value of `range` to the set, merging it with the ranges it
+    /// overlaps or touches.
+    ///
+    /// Returns `true` if the set gained any values.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{RangeSet, SmallRange};
+    ///
+    /// let mut set = RangeSet::<u32>::new();
+    /// assert!(set.insert(SmallRange::new(0, 10)));
+    /// assert!(set.insert(SmallRange::new(10, 20)));
+    /// assert!(!set.insert(SmallRange::new(5, 15)));
+    /// assert_eq!(set.iter().collect::<Vec<_>>(), [SmallRange::new(0, 20)]);
+    /// ```
+    pub fn insert(&mut self, range: SmallRange<T>) -> bool {
+        if self.contains_range(&range) {
+            return false;
+        }
+        let (start, end) = range.to_parts();
+        let lo = self.ranges.partition_point(|range| range.end() < start);
+        let hi = self.ranges.partition_point(|range| range.start() <= end);
+        if lo == hi {
+            self.ranges.insert(lo, range);
+            return true;
+        }
+        let (lo, hi) = self.extend_to_runs(lo, hi);
+        let run_start = start.min(self.ranges[lo].start());
+        let run_end = end.max(self.ranges[hi - 1].end());
+        self.ranges
+            .splice(lo..hi, RunPieces::new(run_start, run_end));
+        true
+    }
+
+    /// Removes every value of `range` from the set, splitting the ranges it
+    /// cuts through.
+    ///
+    /// Returns `Ok(true)` if the set lost any values.
+    ///
+    /// # Errors
+    /// - [`SmallRangeError::StartTooLarge`] if the values left after `range`
+    ///   would start beyond the half-width capacity, where no range can hold
+    ///   them. This can happen when `range` ends past `MAX_START` but the run
+    ///   it cuts continues after it. The set is left unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{RangeSet, SmallRange, SmallRangeError};
+    ///
+    /// let mut set: RangeSet<u32> = [SmallRange::new(0, 20)].into_iter().collect();
+    /// assert_eq!(set.remove(SmallRange::new(5, 10)), Ok(true));
+    /// assert_eq!(set.remove(SmallRange::new(5, 10)), Ok(false));
+    /// assert_eq!(
+    ///     set.iter().collect::<Vec<_>>(),
+    ///     [SmallRange::new(0, 5), SmallRange::new(10, 20)]
+    /// );
+    ///
+    /// // 300..400 would remain, but u16 starts max out at 254
+    /// let mut wide = RangeSet::<u16>::from(SmallRange::new(200, 400));
+    /// assert_eq!(
+    ///     wide.remove(SmallRange::new(200, 300)),
+    ///     Err(SmallRangeError::StartTooLarge)
+    /// );
+    /// ```
+    pub fn remove(&mut self, range: SmallRange<T>) -> Result<bool, SmallRangeError> {
+        if range.is_empty() {
+            return Ok(false);
+        }
+        let (start, end) = range.to_parts();
+        let lo = self.ranges.partition_point(|range| range.end() <= start);
+        let hi = self.ranges.partition_point(|range| range.start() < end);
+        if lo >= hi {
+            return Ok(false);
+        }
+        let (lo, hi) = self.extend_to_runs(lo, hi);
+        let run_start = self.ranges[lo].start();
+        let run_end = self.ranges[hi - 1].end();
+        let left = RunPieces::new(run_start, start);
+        let right = RunPieces::try_new(end, run_end)?;
+        self.ranges.splice(lo..hi, left.chain(right));
+        Ok(true)
+    }
+
+    /// Returns an iterator over the ranges in ascending order.
+    #[inline]
+    pub fn iter(&self) -> Copied<slice::Iter<'_, SmallRange<T>>> {
+        self.ranges.iter().copied()
+    }
+
+    /// Returns the values in either set.
+    ///
+    /// Also available as `&a | &b`, or as `a |= &b`, which replaces `a` with the
+    /// result.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{RangeSet, SmallRange};
+    ///
+    /// let a = RangeSet::<u32>::from(SmallRange::new(0, 10));
+    /// let b: RangeSet<u32> = [SmallRange::new(5, 15), SmallRange::new(20, 25)]
+    ///     .into_iter()
+    ///     .collect();
+    /// assert_eq!(
+    ///     a.union(&b).iter().collect::<Vec<_>>(),
+    ///     [SmallRange::new(0, 15), SmallRange::new(20, 25)]
+    /// );
+    /// ```
+    pub fn union(&self, other: &Self) -> Self {
+        self.combine(other, |in_self, in_other| in_self || in_other)
+            .expect("union runs start where an operand's run starts")
+    }
+
+    /// Returns the values in both sets.
+    ///
+    /// Also available as `&a & &b`, or as `a &= &b`, which replaces `a` with the
+    /// result.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{RangeSet, SmallRange};
+    ///
+    /// let a = RangeSet::<u32>::from(SmallRange::new(0, 10));
+    /// let b: RangeSet<u32> = [SmallRange::new(5, 15), SmallRange::new(20, 25)]
+    ///     .into_iter()
+    ///     .collect();
+    /// assert_eq!(a.intersection(&b).iter().collect::<Vec<_>>(), [SmallRange::new(5, 10)]);
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.combine(other, |in_self, in_other| in_self && in_other)
+            .expect("intersection runs start where an operand's run starts")
+    }
+
+    /// Returns the values in this set but not in `other`.
+    ///
+    /// Unlike [`union`](Self::union), this has no operator form, since it
+    /// can fail.
+    ///
+    /// # Errors
+    /// - [`SmallRangeError::StartTooLarge`] if a resulting run would start
+    ///   beyond the half-width capacity, where no range can hold it. This can
+    ///   happen when a run of `other` ends past `MAX_START` inside a run of
+    ///   this set.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{RangeSet, SmallRange, SmallRangeError};
+    ///
+    /// let a = RangeSet::<u32>::from(SmallRange::new(0, 10));
+    /// let b = RangeSet::<u32>::from(SmallRange::new(3, 5));
+    /// assert_eq!(
+    ///     a.difference(&b).unwrap().iter().collect::<Vec<_>>(),
+    ///     [SmallRange::new(0, 3), SmallRange::new(5, 10)]
+    /// );
+    ///
+    /// // 300..400 would remain, but u16 starts max out at 254
+    /// let wide = RangeSet::<u16>::from(SmallRange::new(200, 400));
+    /// let cut = RangeSet::<u16>::from(SmallRange::new(200, 300));
+    /// assert_eq!(wide.difference(&cut), Err(SmallRangeError::StartTooLarge));
+    /// ```
+    pub fn difference(&self, other: &Self) -> Result<Self, SmallRangeError> {
+        self.combine(other, |in_self, in_other| in_self && !in_other)
+    }
+
+    /// Returns the values in exactly one of the sets.
+    ///
+    /// Unlike [`union`](Self::union), this has no operator form, since it
+    /// can fail.
+    ///
+    /// # Errors
+    /// - [`SmallRangeError::StartTooLarge`] if a resulting run would start
+    ///   beyond the half-width capacity, where no range can hold it. This can
+    ///   happen when the sets' runs end at different values past `MAX_START`.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{RangeSet, SmallRange};
+    ///
+    /// let a = RangeSet::<u32>::from(SmallRange::new(0, 10));
+    /// let b = RangeSet::<u32>::from(SmallRange::new(5, 15));
+    /// assert_eq!(
+    ///     a.symmetric_difference(&b).unwrap().iter().collect::<Vec<_>>(),
+    ///     [SmallRange::new(0, 5), SmallRange::new(10, 15)]
+    /// );
+    /// ```
+    pub fn symmetric_difference(&self, other: &Self) -> Result<Self, SmallRangeError> {
+        self.combine(other, |in_self, in_other| in_self != in_other)
+    }
+
+    /// Returns an iterator over the values in `within` that are not in the
+    /// set, as ranges in ascending order.
+    ///
+    /// A gap never needs more than one range, since it fits inside `within`.
+    /// But a gap following a run that ends past `MAX_START` starts beyond the
+    /// half-width capacity, where no range can hold it, so the iterator
+    /// yields [`SmallRangeError::StartTooLarge`] for it instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{RangeSet, SmallRange, SmallRangeError};
+    ///
+    /// let downloaded: RangeSet<u32> = [SmallRange::new(0, 100), SmallRange::new(150, 200)]
+    ///     .into_iter()
+    ///     .collect();
+    /// let missing: Vec<_> = downloaded.gaps(SmallRange::new(0, 250)).collect();
+    /// assert_eq!(missing, [Ok(SmallRange::new(100, 150)), Ok(SmallRange::new(200, 250))]);
+    ///
+    /// // u16 starts max out at 254, so the gap 300..400 can't be stored
+    /// let head = RangeSet::<u16>::from(SmallRange::new(200, 300));
+    /// let missing: Vec<_> = head.gaps(SmallRange::new(150, 400)).collect();
+    /// assert_eq!(
+    ///     missing,
+    ///     [Ok(SmallRange::new(150, 200)), Err(SmallRangeError::StartTooLarge)]
+    /// );
+    /// ```
+    pub fn gaps(&self, within: SmallRange<T>) -> Gaps<'_, T> {
+        let (start, end) = within.to_parts();
+        let first = self.ranges.partition_point(|range| range.end() <= start);
+        Gaps {
+            runs: Runs {
+                ranges: self.ranges[first..].iter(),
+            },
+            cursor: start,
+            end,
+        }
+    }
+
+    /// Returns the set of values in `within` that are not in this set.
+    ///
+    /// # Errors
+    /// - [`SmallRangeError::StartTooLarge`] if a gap starts beyond the
+    ///   half-width capacity, as described for [`gaps`](Self::gaps)
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{RangeSet, SmallRange};
+    ///
+    /// let dirty = RangeSet::<u32>::from(SmallRange::new(10, 20));
+    /// let clean = dirty.complement(SmallRange::new(0, 30)).unwrap();
+    /// assert_eq!(
+    ///     clean.iter().collect::<Vec<_>>(),
+    ///     [SmallRange::new(0, 10), SmallRange::new(20, 30)]
+    /// );
+    /// assert_eq!(clean.complement(SmallRange::new(0, 30)), Ok(dirty));
+    /// ```
+    pub fn complement(&self, within: SmallRange<T>) -> Result<Self, SmallRangeError> {
+        let ranges = self.gaps(within).collect::<Result<_, _>>()?;
+        Ok(Self { ranges })
+    }
+
+    /// Sweeps the run boundaries of both sets in order, keeping the values
+    /// for which `keep` holds.
+    fn combine(&self, other: &Self, keep: fn(bool, bool) -> bool) -> Result<Self, SmallRangeError> {
+        let mut self_bounds = self.runs().flat_map(|(start, end)| [start, end]);
+        let mut other_bounds = other.runs().flat_map(|(start, end)| [start, end]);
+        let (mut next_self, mut next_other) = (self_bounds.next(), other_bounds.next());
+        let (mut in_self, mut in_other) = (false, false);
+        let mut run_start = None;
+        let mut ranges = Vec::new();
+        loop {
+            let point = match (next_self, next_other) {
+                (Some(a), Some(b)) => a.min(b),
+                (Some(a), None) => a,
+                (None, Some(b)) => b,
+                (None, None) => break,
+            };
+            if next_self == Some(point) {
+                in_self = !in_self;
+                next_self = self_bounds.next();
+            }
+            if next_other == Some(point) {
+                in_other = !in_other;
+                next_other = other_bounds.next();
+            }
+            match (run_start, keep(in_self, in_other)) {
+                (None, true) => run_start = Some(point),
+                (Some(start), false) => {
+                    ranges.extend(RunPieces::try_new(start, point)?);
+                    run_start = None;
+                }
+                _ => {}
+            }
+        }
+        Ok(Self { ranges })
+    }
+
+    /// Returns the bounds of each run, joining the pieces of runs too long
+    /// for a single range.
+    fn runs(&self) -> Runs<'_, T> {
+        Runs {
+            ranges: self.ranges.iter(),
+        }
+    }
+
+    /// Builds a set from ranges that are already sorted and disjoint, or
+    /// returns `None` if they aren't or any is empty.
+    ///
+    /// Touching ranges are accepted and joined.
+    #[cfg(feature = "serde")]
+    pub(crate) fn from_sorted_ranges(
+        ranges: impl IntoIterator<Item = SmallRange<T>>,
+    ) -> Option<Self> {
+        let mut set = Self::new();
+        let mut run: Option<(T, T)> = None;
+        for range in ranges {
+            if range.is_empty() {
+                return None;
+            }
+            let (start, end) = range.to_parts();
+            run = match run {
+                None => Some((start, end)),
+                Some((run_start, run_end)) if start == run_end => Some((run_start, end)),
+                Some((run_start, run_end)) if start > run_end => {
+                    set.ranges.extend(RunPieces::new(run_start, run_end));
+                    Some((start, end))
+                }
+                Some(_) => return None,
+            };
+        }
+        if let Some((run_start, run_end)) = run {
+            set.ranges.extend(RunPieces::new(run_start, run_end));
+        }
+        Some(set)
+    }
+
+    /// Widens `lo..hi` to take in every piece of the runs at either end.
+    fn extend_to_runs(&self, mut lo: usize, mut hi: usize) -> (usize, usize) {
+        while lo > 0 && self.ranges[lo - 1].end() == self.ranges[lo].start() {
+            lo -= 1;
+        }
+        while hi < self.ranges.len() && self.ranges[hi].start() == self.ranges[hi - 1].end() {
+            hi += 1;
+        }
+        (lo, hi)
+    }
+}
+
+impl<T: SmallRangeStorage + fmt::Debug> fmt::Debug for RangeSet<T>
+where
+    usize: AsPrimitive<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T: SmallRangeStorage> Extend<SmallRange<T>> for RangeSet<T>
+where
+    usize: AsPrimitive<T>,
+{
+    fn extend<I: IntoIterator<Item = SmallRange<T>>>(&mut self, ranges: I) {
+        for range in ranges {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: SmallRangeStorage> FromIterator<SmallRange<T>> for RangeSet<T>
+where
+    usize: AsPrimitive<T>,
+{
+    fn from_iter<I: IntoIterator<Item = SmallRange<T>>>(ranges: I) -> Self {
+        let mut set = Self::new();
+        set.extend(ranges);
+        set
+    }
+}
+
+impl<T: SmallRangeStorage> From<SmallRange<T>> for RangeSet<T>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    fn from(range: SmallRange<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: SmallRangeStorage> IntoIterator for RangeSet<T>
+where
+    usize: AsPrimitive<T>,
+{
+    type Item = SmallRange<T>;
+    type IntoIter = vec::IntoIter<SmallRange<T>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.ranges.into_iter()
+    }
+}
+
+impl<'a, T: SmallRangeStorage> IntoIterator for &'a RangeSet<T>
+where
+    usize: AsPrimitive<T>,
+{
+    type Item = SmallRange<T>;
+    type IntoIter = Copied<slice::Iter<'a, SmallRange<T>>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Implements an operator and its assigning form for an infallible set
+/// method.
+macro_rules! impl_set_op {
+    ($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident, $method:ident) => {
+        impl<T: SmallRangeStorage> $Op<&RangeSet<T>> for &RangeSet<T>
+        where
+            usize: AsPrimitive<T>,
+        {
+            type Output = RangeSet<T>;
+
+            #[inline]
+            fn $op(self, other: &RangeSet<T>) -> RangeSet<T> {
+                self.$method(other)
+            }
+        }
+
+        impl<T: SmallRangeStorage> $OpAssign<&RangeSet<T>> for RangeSet<T>
+        where
+            usize: AsPrimitive<T>,
+        {
+            #[inline]
+            fn $op_assign(&mut self, other: &RangeSet<T>) {
+                *self = self.$method(other);
+            }
+        }
+    };
+}
+
+impl_set_op!(BitOr, bitor, BitOrAssign, bitor_assign, union);
+impl_set_op!(BitAnd, bitand, BitAndAssign, bitand_assign, intersection);
+
+/// An iterator over the values in a window that are not in a [`RangeSet`].
+///
+/// Yields an error for each gap that starts beyond the half-width capacity.
+/// Created by [`RangeSet::gaps`].
+#[derive(Debug, Clone)]
+pub struct Gaps<'a, T: SmallRangeStorage>
+where
+    usize: AsPrimitive<T>,
+{
+    runs: Runs<'a, T>,
+    // Start of the next possible gap; the window is exhausted once it
+    // reaches `end`
+    cursor: T,
+    end: T,
+}
+
+impl<T: SmallRangeStorage> Iterator for Gaps<'_, T>
+where
+    usize: AsPrimitive<T>,
+{
+    type Item = Result<SmallRange<T>, SmallRangeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.cursor < self.end {
+            let gap_start = self.cursor;
+            let gap_end = match self.runs.next() {
+                Some((start, end)) if start < self.end => {
+                    self.cursor = end.max(gap_start);
+                    start
+                }
+                _ => {
+                    self.cursor = self.end;
+                    self.end
+                }
+            };
+            if gap_start < gap_end {
+                return Some(SmallRange::try_new_checked(gap_start, gap_end));
+            }
+        }
+        None
+    }
+}
+
+impl<T: SmallRangeStorage> FusedIterator for Gaps<'_, T> where usize: AsPrimitive<T> {}
+
+/// The bounds of each run in a set, in ascending order.
+#[derive(Debug, Clone)]
+struct Runs<'a, T: SmallRangeStorage>
+where
+    usize: AsPrimitive<T>,
+{
+    ranges: slice::Iter<'a, SmallRange<T>>,
+}
+
+impl<T: SmallRangeStorage> Iterator for Runs<'_, T>
+where
+    usize: AsPrimitive<T>,
+{
+    type Item = (T, T);
+
+    fn next(&mut self) -> Option<(T, T)> {
+        let (start, mut end) = self.ranges.next()?.to_parts();
+        while let Some(next) = self.ranges.as_slice().first() {
+            if next.start() != end {
+                break;
+            }
+            end = next.end();
+            self.ranges.next();
+        }
+        Some((start, end))
+    }
+}
+
+/// The pieces a run of values is stored as: a shorter first piece, then
+/// `MAX_LEN` pieces up to the run's end.
+///
+/// Splitting from the end keeps every full piece storable, since no end
+/// exceeds `MAX_END` and a full piece ending there starts at `MAX_START`. The
+/// first piece starts where the run does, which must be checked separately.
+#[derive(Debug, Clone)]
+struct RunPieces<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: SmallRangeStorage> RunPieces<T>
+where
+    usize: AsPrimitive<T>,
+{
+    /// Empty if `start >= end`.
+    ///
+    /// Only for runs known to start within the half-width capacity, such as
+    /// one starting where a stored range starts.
+    #[inline]
+    fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    /// Like [`new`](Self::new), but checks that the first piece can start at
+    /// `start`, as a run cut out of a longer one may not.
+    #[inline]
+    fn try_new(start: T, end: T) -> Result<Self, SmallRangeError> {
+        if start < end && start > SmallRange::<T>::MAX_START {
+            return Err(SmallRangeError::StartTooLarge);
+        }
+        Ok(Self::new(start, end))
+    }
+}
+
+impl<T: SmallRangeStorage> Iterator for RunPieces<T>
+where
+    usize: AsPrimitive<T>,
+{
+    type Item = SmallRange<T>;
+
+    fn next(&mut self) -> Option<SmallRange<T>> {
+        if self.start >= self.end {
+            return None;
+        }
+        let max_len = SmallRange::<T>::MAX_LEN;
+        let len = (self.end - self.start - T::one()) % max_len + T::one();
+        let piece = SmallRange::from_start_len(self.start, len);
+        self.start = piece.end();
+        Some(piece)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count = if self.start >= self.end {
+            0
+        } else {
+            ((self.end - self.start - T::one()) / SmallRange::<T>::MAX_LEN).as_() + 1
+        };
+        (count, Some(count))
+    }
+}
+
+impl<T: SmallRangeStorage> FusedIterator for RunPieces<T> where usize: AsPrimitive<T> {}
@@ -0,0 +1,132 @@
+//! rayon `IntoParallelIterator` for `SmallRange`.
+//!
+//! The parallel iterator is indexed: rayon splits the range at midpoints, and
+//! each thread walks its piece with a [`SmallRangeIter`].
+
+use num_traits::AsPrimitive;
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+
+use crate::{SmallRange, SmallRangeIter, SmallRangeStorage};
+
+/// A parallel iterator over the values of a range.
+///
+/// Created by `into_par_iter` on a [`SmallRange`] with the `rayon` feature.
+///
+/// # Examples
+/// ```
+/// use rayon::prelude::*;
+/// use small_range::SmallRange;
+///
+/// let range = SmallRange::<u64>::new(1, 1_001);
+/// assert_eq!(range.into_par_iter().sum::<u64>(), 500_500);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SmallRangeParIter<T: SmallRangeStorage>
+where
+    usize: AsPrimitive<T>,
+{
+    range: SmallRange<T>,
+}
+
+impl<T: SmallRangeStorage + Send> IntoParallelIterator for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+    T::NonZeroStorage: Send,
+{
+    type Item = T;
+    type Iter = SmallRangeParIter<T>;
+
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        SmallRangeParIter { range: self }
+    }
+}
+
+impl<T: SmallRangeStorage + Send> IntoParallelIterator for &SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+    T::NonZeroStorage: Send,
+{
+    type Item = T;
+    type Iter = SmallRangeParIter<T>;
+
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        SmallRangeParIter { range: *self }
+    }
+}
+
+impl<T: SmallRangeStorage + Send> ParallelIterator for SmallRangeParIter<T>
+where
+    usize: AsPrimitive<T>,
+    T::NonZeroStorage: Send,
+{
+    type Item = T;
+
+    fn drive_unindexed<C: UnindexedConsumer<T>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    #[inline]
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.range.len())
+    }
+}
+
+impl<T: SmallRangeStorage + Send> IndexedParallelIterator for SmallRangeParIter<T>
+where
+    usize: AsPrimitive<T>,
+    T::NonZeroStorage: Send,
+{
+    fn drive<C: Consumer<T>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.range.len()
+    }
+
+    fn with_producer<CB: ProducerCallback<T>>(self, callback: CB) -> CB::Output {
+        let (start, end) = self.range.to_parts();
+        callback.callback(RangeProducer { start, end })
+    }
+}
+
+/// Splits a range into pieces for rayon's work stealing.
+///
+/// Holds plain bounds rather than a `SmallRange`, since the upper piece of a
+/// range near `MAX_END` can start past `MAX_START`.
+struct RangeProducer<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: SmallRangeStorage + Send> Producer for RangeProducer<T>
+where
+    usize: AsPrimitive<T>,
+{
+    type Item = T;
+    type IntoIter = SmallRangeIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        SmallRangeIter::from_bounds(self.start, self.end)
+    }
+
+    #[inline]
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let mid = self.start + index.as_();
+        (
+            Self {
+                start: self.start,
+                end: mid,
+            },
+            Self {
+                start: mid,
+                end: self.end,
+            },
+        )
+    }
+}
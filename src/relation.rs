@@ -0,0 +1,78 @@
+/// The relationship between two ranges, following Allen's interval algebra.
+///
+/// Every ordered pair of ranges falls into exactly one of these thirteen
+/// relations. Each variant describes how the first range (`self` in
+/// [`SmallRange::relation`](crate::SmallRange::relation)) relates to the second.
+///
+/// | Relation   | Condition (`a` vs `b`)                           |
+/// |------------|--------------------------------------------------|
+/// | `Before`   | `a.end < b.start`                                |
+/// | `Meets`    | `a.end == b.start`                               |
+/// | `Overlaps` | `a.start < b.start < a.end < b.end`              |
+/// | `Starts`   | `a.start == b.start` and `a.end < b.end`         |
+/// | `During`   | `b.start < a.start` and `a.end < b.end`          |
+/// | `Finishes` | `b.start < a.start` and `a.end == b.end`         |
+/// | `Equals`   | `a.start == b.start` and `a.end == b.end`        |
+///
+/// The remaining six variants are the inverses: `a.relation(&b)` is `After`
+/// exactly when `b.relation(&a)` is `Before`, and so on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntervalRelation {
+    /// The first range ends before the second starts, with a gap between.
+    Before,
+    /// The first range ends exactly where the second starts.
+    Meets,
+    /// The first range starts first and ends inside the second.
+    Overlaps,
+    /// Both start together; the first range ends first.
+    Starts,
+    /// The first range lies strictly inside the second.
+    During,
+    /// Both end together; the first range starts later.
+    Finishes,
+    /// Both ranges have the same start and end.
+    Equals,
+    /// Both end together; the first range starts earlier.
+    FinishedBy,
+    /// The second range lies strictly inside the first.
+    Contains,
+    /// Both start together; the first range ends later.
+    StartedBy,
+    /// The second range starts first and ends inside the first.
+    OverlappedBy,
+    /// The second range ends exactly where the first starts.
+    MetBy,
+    /// The first range starts after the second ends, with a gap between.
+    After,
+}
+
+impl IntervalRelation {
+    /// Returns the relation with the roles of the two ranges swapped.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::IntervalRelation;
+    ///
+    /// assert_eq!(IntervalRelation::Before.inverse(), IntervalRelation::After);
+    /// assert_eq!(IntervalRelation::During.inverse(), IntervalRelation::Contains);
+    /// assert_eq!(IntervalRelation::Equals.inverse(), IntervalRelation::Equals);
+    /// ```
+    #[inline]
+    pub fn inverse(self) -> Self {
+        match self {
+            Self::Before => Self::After,
+            Self::Meets => Self::MetBy,
+            Self::Overlaps => Self::OverlappedBy,
+            Self::Starts => Self::StartedBy,
+            Self::During => Self::Contains,
+            Self::Finishes => Self::FinishedBy,
+            Self::Equals => Self::Equals,
+            Self::FinishedBy => Self::Finishes,
+            Self::Contains => Self::During,
+            Self::StartedBy => Self::Starts,
+            Self::OverlappedBy => Self::Overlaps,
+            Self::MetBy => Self::Meets,
+            Self::After => Self::Before,
+        }
+    }
+}
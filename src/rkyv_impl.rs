@@ -0,0 +1,165 @@
+//! rkyv `Archive`, `Serialize`, `Deserialize` and `CheckBytes` for
+//! `SmallRange`.
+//!
+//! The archived form is the packed representation from
+//! [`SmallRange::to_bits`], stored in rkyv's fixed-endian non-zero integer, so
+//! `Option<ArchivedSmallRange<T>>` keeps the niche. Validation rejects bit
+//! patterns that no range produces.
+//!
+//! Implemented for `u8`, `u16`, `u32` and `u64` storage. `usize` is left out
+//! since its packed value depends on the target's pointer width.
+
+use core::error::Error;
+use core::fmt;
+use core::num::NonZero;
+
+use num_traits::AsPrimitive;
+use rkyv::bytecheck::CheckBytes;
+use rkyv::rancor::{Fallible, Source};
+use rkyv::{Archive, Archived, Deserialize, Place, Portable, Serialize};
+
+use crate::{SmallRange, SmallRangeStorage};
+
+/// The archived form of a [`SmallRange`]: its packed representation with a
+/// fixed byte order.
+///
+/// Created by the `rkyv` feature's `Archive` impl. Use
+/// [`to_native`](Self::to_native) to get the range back without a
+/// deserializer.
+#[repr(transparent)]
+pub struct ArchivedSmallRange<T: SmallRangeStorage>
+where
+    usize: AsPrimitive<T>,
+    T::NonZeroStorage: Archive,
+{
+    bits: Archived<T::NonZeroStorage>,
+}
+
+/// A bit pattern that no `SmallRange` produces.
+#[derive(Debug)]
+struct InvalidEncoding;
+
+impl fmt::Display for InvalidEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid SmallRange encoding")
+    }
+}
+
+impl Error for InvalidEncoding {}
+
+macro_rules! impl_rkyv {
+    ($($t:ty),*) => {
+        $(
+            impl ArchivedSmallRange<$t> {
+                /// Returns the archived range.
+                #[inline]
+                pub fn to_native(&self) -> SmallRange<$t> {
+                    // SAFETY: archives hold bits from a valid range, checked
+                    // by `CheckBytes` unless the caller skipped validation
+                    // with an unsafe access
+                    unsafe { SmallRange::from_bits_unchecked(self.bits.get()) }
+                }
+
+                /// Returns the start of the archived range.
+                #[inline]
+                pub fn start(&self) -> $t {
+                    self.to_native().start()
+                }
+
+                /// Returns the end of the archived range (exclusive).
+                #[inline]
+                pub fn end(&self) -> $t {
+                    self.to_native().end()
+                }
+
+                /// Returns the length of the archived range.
+                #[inline]
+                pub fn len(&self) -> usize {
+                    self.to_native().len()
+                }
+
+                /// Returns `true` if the archived range is empty.
+                #[inline]
+                pub fn is_empty(&self) -> bool {
+                    self.to_native().is_empty()
+                }
+            }
+
+            // SAFETY: a transparent wrapper around a portable non-zero integer
+            unsafe impl Portable for ArchivedSmallRange<$t> {}
+
+            impl fmt::Debug for ArchivedSmallRange<$t> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    self.to_native().fmt(f)
+                }
+            }
+
+            impl PartialEq for ArchivedSmallRange<$t> {
+                #[inline]
+                fn eq(&self, other: &Self) -> bool {
+                    self.bits == other.bits
+                }
+            }
+
+            impl Eq for ArchivedSmallRange<$t> {}
+
+            impl PartialEq<SmallRange<$t>> for ArchivedSmallRange<$t> {
+                #[inline]
+                fn eq(&self, other: &SmallRange<$t>) -> bool {
+                    self.to_native() == *other
+                }
+            }
+
+            impl Archive for SmallRange<$t> {
+                type Archived = ArchivedSmallRange<$t>;
+                type Resolver = ();
+
+                #[inline]
+                fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+                    // SAFETY: ArchivedSmallRange is a transparent wrapper
+                    // around the archived bits
+                    let bits = unsafe { out.cast_unchecked::<Archived<NonZero<$t>>>() };
+                    self.to_bits().resolve(resolver, bits);
+                }
+            }
+
+            impl<S: Fallible + ?Sized> Serialize<S> for SmallRange<$t> {
+                #[inline]
+                fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+                    Ok(())
+                }
+            }
+
+            impl<D: Fallible + ?Sized> Deserialize<SmallRange<$t>, D> for ArchivedSmallRange<$t> {
+                #[inline]
+                fn deserialize(&self, _: &mut D) -> Result<SmallRange<$t>, D::Error> {
+                    Ok(self.to_native())
+                }
+            }
+
+            // SAFETY: checks the bits as a non-zero integer, then rejects
+            // patterns with a zero half
+            unsafe impl<C> CheckBytes<C> for ArchivedSmallRange<$t>
+            where
+                C: Fallible + ?Sized,
+                C::Error: Source,
+                Archived<NonZero<$t>>: CheckBytes<C>,
+            {
+                unsafe fn check_bytes(value: *const Self, context: &mut C) -> Result<(), C::Error> {
+                    let bits = value.cast::<Archived<NonZero<$t>>>();
+                    // SAFETY: the caller guarantees `value` is aligned and
+                    // points to enough bytes, and the layouts match
+                    unsafe { <Archived<NonZero<$t>>>::check_bytes(bits, context)? };
+                    // SAFETY: just checked to be a valid non-zero integer
+                    let bits = unsafe { &*bits }.get();
+                    if SmallRange::<$t>::from_bits(bits).is_none() {
+                        return Err(C::Error::new(InvalidEncoding));
+                    }
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_rkyv!(u8, u16, u32, u64);
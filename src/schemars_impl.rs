@@ -0,0 +1,67 @@
+//! `JsonSchema` for `SmallRange`.
+//!
+//! The schema accepts every form that the `serde` feature reads from
+//! human-readable formats: the `"10..20"` string it writes, a
+//! `{ start, end }` object, and a `[start, end]` array. The object's and
+//! array's bounds are limited by the storage capacity.
+
+extern crate alloc;
+
+use alloc::borrow::Cow;
+use alloc::format;
+
+use num_traits::AsPrimitive;
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::{SmallRange, SmallRangeStorage};
+
+impl<T: SmallRangeStorage + JsonSchema> JsonSchema for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    fn schema_name() -> Cow<'static, str> {
+        format!("SmallRange_of_{}", T::schema_name()).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("small_range::SmallRange<{}>", T::schema_id()).into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        let max_start: usize = Self::MAX_START.as_();
+        let max_end: usize = Self::MAX_END.as_();
+        let start = json_schema!({
+            "type": "integer",
+            "minimum": 0,
+            "maximum": max_start
+        });
+        let end = json_schema!({
+            "type": "integer",
+            "minimum": 0,
+            "maximum": max_end
+        });
+        json_schema!({
+            "anyOf": [
+                {
+                    "type": "string",
+                    "pattern": "^[0-9]+\\.\\.=?[0-9]+$"
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "start": start,
+                        "end": end
+                    },
+                    "required": ["start", "end"],
+                    "additionalProperties": false
+                },
+                {
+                    "type": "array",
+                    "prefixItems": [start, end],
+                    "minItems": 2,
+                    "maxItems": 2
+                }
+            ]
+        })
+    }
+}
@@ -0,0 +1,185 @@
+//! `Serialize` and `Deserialize` for `SmallRange`.
+//!
+//! Human-readable formats such as JSON get the string `"10..20"`. Binary
+//! formats such as bincode get the packed representation from
+//! [`SmallRange::to_bits`] as a single `T`. Human-readable input may also be
+//! a `{ start, end }` map or a `[start, end]` sequence.
+//!
+//! Deserialization re-validates every form, so a range that can't be stored,
+//! such as one with its start after its end, is an error rather than a
+//! corrupt value.
+//!
+//! With the `alloc` feature, a `RangeSet` is a sequence of its ranges in
+//! ascending order, each in the form above. Deserialization rejects ranges
+//! that are empty, out of order or overlapping.
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+use num_traits::AsPrimitive;
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::{SmallRange, SmallRangeStorage};
+
+const FIELDS: &[&str] = &["start", "end"];
+
+impl<T: SmallRangeStorage + fmt::Display + Serialize> Serialize for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            T::get_nonzero(self.to_bits()).serialize(serializer)
+        }
+    }
+}
+
+impl<'de, T: SmallRangeStorage + Deserialize<'de>> Deserialize<'de> for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(RangeVisitor(PhantomData))
+        } else {
+            let bits = T::deserialize(deserializer)?;
+            SmallRange::from_bits(bits)
+                .ok_or_else(|| de::Error::custom("invalid SmallRange encoding"))
+        }
+    }
+}
+
+enum Field {
+    Start,
+    End,
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldVisitor;
+
+        impl Visitor<'_> for FieldVisitor {
+            type Value = Field;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("`start` or `end`")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Field, E> {
+                match value {
+                    "start" => Ok(Field::Start),
+                    "end" => Ok(Field::End),
+                    _ => Err(de::Error::unknown_field(value, FIELDS)),
+                }
+            }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+struct RangeVisitor<T>(PhantomData<T>);
+
+impl<'de, T: SmallRangeStorage + Deserialize<'de>> Visitor<'de> for RangeVisitor<T>
+where
+    usize: AsPrimitive<T>,
+{
+    type Value = SmallRange<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a range like \"10..20\" or a struct with start and end")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        SmallRange::from_str(value).map_err(de::Error::custom)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let start = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let end = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        SmallRange::try_new_checked(start, end).map_err(de::Error::custom)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut start = None;
+        let mut end = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                Field::Start => {
+                    if start.is_some() {
+                        return Err(de::Error::duplicate_field("start"));
+                    }
+                    start = Some(map.next_value()?);
+                }
+                Field::End => {
+                    if end.is_some() {
+                        return Err(de::Error::duplicate_field("end"));
+                    }
+                    end = Some(map.next_value()?);
+                }
+            }
+        }
+        let start = start.ok_or_else(|| de::Error::missing_field("start"))?;
+        let end = end.ok_or_else(|| de::Error::missing_field("end"))?;
+        SmallRange::try_new_checked(start, end).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod range_set {
+    extern crate alloc;
+
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::RangeSet;
+
+    impl<T: SmallRangeStorage + fmt::Display + Serialize> Serialize for RangeSet<T>
+    where
+        usize: AsPrimitive<T>,
+    {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(self.iter())
+        }
+    }
+
+    impl<'de, T: SmallRangeStorage + Deserialize<'de>> Deserialize<'de> for RangeSet<T>
+    where
+        usize: AsPrimitive<T>,
+    {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_seq(RangeSetVisitor(PhantomData))
+        }
+    }
+
+    struct RangeSetVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: SmallRangeStorage + Deserialize<'de>> Visitor<'de> for RangeSetVisitor<T>
+    where
+        usize: AsPrimitive<T>,
+    {
+        type Value = RangeSet<T>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a sequence of ranges")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut ranges = Vec::new();
+            while let Some(range) = seq.next_element::<SmallRange<T>>()? {
+                ranges.push(range);
+            }
+            RangeSet::from_sorted_ranges(ranges).ok_or_else(|| {
+                de::Error::custom("ranges must be non-empty, sorted and non-overlapping")
+            })
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use core::fmt;
+use core::hash::Hash;
+use core::ops::Range;
+
+use num_traits::{AsPrimitive, PrimInt, Signed, Zero};
+
+use crate::{SmallRange, SmallRangeStorage};
+
+/// Sealed trait module to prevent external implementations.
+mod private {
+    pub trait Sealed {}
+    impl Sealed for i8 {}
+    impl Sealed for i16 {}
+    impl Sealed for i32 {}
+    impl Sealed for i64 {}
+    impl Sealed for isize {}
+}
+
+/// Trait for signed types that can be used as storage in a
+/// [`SignedSmallRange`].
+///
+/// This trait is sealed and only implemented for `i8`, `i16`, `i32`, `i64`,
+/// and `isize`. Each is paired with the unsigned type of the same width,
+/// which holds the packed value.
+///
+/// | Storage | Start range              | Max Length | Size     |
+/// |---------|--------------------------|------------|----------|
+/// | `i8`    | -7 ..= 7                 | 14         | 1 byte   |
+/// | `i16`   | -127 ..= 127             | 254        | 2 bytes  |
+/// | `i32`   | -32,767 ..= 32,767       | 65,534     | 4 bytes  |
+/// | `i64`   | ~-2.15B ..= ~2.15B       | ~4.29B     | 8 bytes  |
+/// | `isize` | ~-2.15B ..= ~2.15B*      | ~4.29B*    | 8 bytes* |
+///
+/// *On 64-bit platforms. On 32-bit, same as i32.
+pub trait SignedSmallRangeStorage: private::Sealed + PrimInt + Signed + Hash + 'static
+where
+    usize: AsPrimitive<Self::Unsigned>,
+{
+    /// The unsigned storage type of the same width.
+    type Unsigned: SmallRangeStorage;
+
+    /// Smallest start value, `-MAX_START`.
+    const MIN_START: Self;
+
+    /// Largest start value, `MAX_HALF / 2` of the unsigned type.
+    ///
+    /// Starts are packed as `start + MAX_START`, which maps
+    /// `MIN_START..=MAX_START` onto the unsigned start field `0..=MAX_HALF`.
+    const MAX_START: Self;
+
+    /// Reinterprets a non-negative value as the unsigned type.
+    fn to_unsigned(self) -> Self::Unsigned;
+
+    /// Converts an unsigned value that fits in `Self` back.
+    fn from_unsigned(value: Self::Unsigned) -> Self;
+}
+
+macro_rules! impl_signed_storage {
+    ($($signed:ty => $unsigned:ty),*) => {
+        $(
+            impl SignedSmallRangeStorage for $signed {
+                type Unsigned = $unsigned;
+                const MIN_START: Self = -Self::MAX_START;
+                const MAX_START: Self = (<$unsigned as SmallRangeStorage>::MAX_HALF / 2) as Self;
+
+                #[inline]
+                fn to_unsigned(self) -> $unsigned {
+                    self as $unsigned
+                }
+
+                #[inline]
+                fn from_unsigned(value: $unsigned) -> Self {
+                    value as Self
+                }
+            }
+        )*
+    };
+}
+
+impl_signed_storage!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, isize => usize);
+
+/// A compact range whose start may be negative.
+///
+/// Useful for relative offsets such as jump targets and delta windows. The
+/// start is stored with a bias of
+/// [`MAX_START`](SignedSmallRangeStorage::MAX_START), so the range packs
+/// into a `SmallRange` of the unsigned type of the same width and keeps its
+/// niche: `Option<SignedSmallRange<T>>` is the same size as
+/// `SignedSmallRange<T>`.
+///
+/// # Constraints
+/// - Start must not exceed end
+/// - Start must be between [`MIN_START`](Self::MIN_START) and
+///   [`MAX_START`](Self::MAX_START) (`-MAX_START..=MAX_START`)
+/// - Length must be at most [`MAX_LEN`](Self::MAX_LEN), the same as for the
+///   unsigned `SmallRange`
+///
+/// # Examples
+/// ```
+/// use small_range::SignedSmallRange;
+/// use core::mem::size_of;
+///
+/// // A jump 100 bytes backwards, covering 16 bytes
+/// let target = SignedSmallRange::<i32>::new(-100, -84);
+/// assert_eq!(target.start(), -100);
+/// assert_eq!(target.len(), 16);
+/// assert!(target.contains(-90));
+///
+/// assert_eq!(size_of::<Option<SignedSmallRange<i32>>>(), 4);
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SignedSmallRange<T: SignedSmallRangeStorage = i64>
+where
+    usize: AsPrimitive<T::Unsigned>,
+{
+    // The bias preserves order, so the derived ordering sorts by start, then
+    // by end
+    inner: SmallRange<T::Unsigned>,
+}
+
+impl<T: SignedSmallRangeStorage> SignedSmallRange<T>
+where
+    usize: AsPrimitive<T::Unsigned>,
+{
+    /// Smallest start value.
+    pub const MIN_START: T = T::MIN_START;
+
+    /// Largest start value.
+    pub const MAX_START: T = T::MAX_START;
+
+    /// Largest length.
+    pub const MAX_LEN: T::Unsigned = <T::Unsigned as SmallRangeStorage>::MAX_HALF;
+
+    /// Creates a new `SignedSmallRange` with the given start and end values.
+    ///
+    /// # Panics (debug only)
+    /// - If start exceeds end
+    /// - If start is outside `MIN_START..=MAX_START` or the length exceeds
+    ///   the half-width capacity
+    #[inline]
+    pub fn new(start: T, end: T) -> Self {
+        strict_assert!(start <= end, "start must not exceed end");
+        Self::from_start_len(start, (end - start).to_unsigned())
+    }
+
+    /// Creates a new `SignedSmallRange` from a start value and a length.
+    ///
+    /// # Panics (debug only)
+    /// - If start is outside `MIN_START..=MAX_START` or the length exceeds
+    ///   the half-width capacity
+    #[inline]
+    pub fn from_start_len(start: T, len: T::Unsigned) -> Self {
+        strict_assert!(
+            start >= Self::MIN_START && start <= Self::MAX_START,
+            "start exceeds signed half-width capacity"
+        );
+        Self {
+            inner: SmallRange::from_start_len((start + T::MAX_START).to_unsigned(), len),
+        }
+    }
+
+    /// Creates a new `SignedSmallRange` if the values are valid, returns
+    /// `None` otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SignedSmallRange;
+    ///
+    /// assert!(SignedSmallRange::<i16>::try_new(-127, 127).is_some());
+    /// assert!(SignedSmallRange::<i16>::try_new(-128, 0).is_none());
+    /// assert!(SignedSmallRange::<i16>::try_new(0, 255).is_none());
+    /// assert!(SignedSmallRange::<i16>::try_new(10, -10).is_none());
+    /// ```
+    #[inline]
+    pub fn try_new(start: T, end: T) -> Option<Self> {
+        if start > end || start < Self::MIN_START || start > Self::MAX_START {
+            return None;
+        }
+        // start is small, but end can be anything up to T::MAX
+        let length = end.checked_sub(&start)?;
+        Self::try_from_start_len(start, length.to_unsigned())
+    }
+
+    /// Creates a new `SignedSmallRange` from a start and length, or returns
+    /// `None` if either exceeds its capacity.
+    #[inline]
+    pub fn try_from_start_len(start: T, len: T::Unsigned) -> Option<Self> {
+        if start < Self::MIN_START || start > Self::MAX_START || len > Self::MAX_LEN {
+            return None;
+        }
+        Some(Self::from_start_len(start, len))
+    }
+
+    /// Returns the start of the range.
+    #[inline]
+    pub fn start(&self) -> T {
+        T::from_unsigned(self.inner.start()) - T::MAX_START
+    }
+
+    /// Returns the end of the range (exclusive).
+    #[inline]
+    pub fn end(&self) -> T {
+        let (start, length) = self.inner.to_start_len();
+        T::from_unsigned(start) - T::MAX_START + T::from_unsigned(length)
+    }
+
+    /// Returns the length of the range.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns `true` if the range is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Returns `true` if the range contains the given value.
+    #[inline]
+    pub fn contains(&self, value: T) -> bool {
+        value >= self.start() && value < self.end()
+    }
+
+    /// Converts the `SignedSmallRange` to a standard `Range<T>`.
+    #[inline]
+    pub fn to_range(&self) -> Range<T> {
+        self.start()..self.end()
+    }
+}
+
+impl<T: SignedSmallRangeStorage> Default for SignedSmallRange<T>
+where
+    usize: AsPrimitive<T::Unsigned>,
+{
+    fn default() -> Self {
+        Self::from_start_len(T::zero(), T::Unsigned::zero())
+    }
+}
+
+impl<T: SignedSmallRangeStorage + fmt::Debug> fmt::Debug for SignedSmallRange<T>
+where
+    usize: AsPrimitive<T::Unsigned>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SignedSmallRange")
+            .field("start", &self.start())
+            .field("end", &self.end())
+            .finish()
+    }
+}
@@ -1,33 +1,66 @@
+use core::cmp::Ordering;
 use core::fmt;
 use core::hash::Hash;
+use core::iter::Rev;
 use core::num::NonZero;
-use core::ops::Range;
+use core::ops::{Add, AddAssign, Bound, Range, RangeBounds, RangeInclusive, Sub, SubAssign};
+use core::str::FromStr;
 
-use num_traits::{AsPrimitive, PrimInt, Unsigned};
+use num_traits::{AsPrimitive, NumCast, PrimInt, Unsigned};
+
+use crate::{
+    Chunks, IntervalRelation, Pages, ParseSmallRangeError, Partition, SmallRangeError,
+    SmallRangeIter, StepBy, Windows,
+};
 
 /// Sealed trait module to prevent external implementations.
+///
+/// The `unsealed` feature implements `Sealed` for every type, opening
+/// `SmallRangeStorage` to downstream crates.
 mod private {
     pub trait Sealed {}
+    #[cfg(not(feature = "unsealed"))]
+    impl Sealed for u8 {}
+    #[cfg(not(feature = "unsealed"))]
     impl Sealed for u16 {}
+    #[cfg(not(feature = "unsealed"))]
     impl Sealed for u32 {}
+    #[cfg(not(feature = "unsealed"))]
     impl Sealed for u64 {}
+    #[cfg(not(feature = "unsealed"))]
     impl Sealed for usize {}
+    #[cfg(feature = "unsealed")]
+    impl<T: ?Sized> Sealed for T {}
 }
 
 /// Trait for types that can be used as storage in a `SmallRange`.
 ///
-/// This trait is sealed and only implemented for `u16`, `u32`, `u64`, and `usize`.
+/// This trait is sealed and only implemented for `u8`, `u16`, `u32`, `u64`, and `usize`,
+/// unless the `unsealed` feature is enabled (see below).
 /// The storage type determines how much space the range uses and the maximum
 /// values for start and length (each limited to half the storage width minus 1).
 ///
 /// | Storage | Max Start | Max Length | Size     |
 /// |---------|-----------|------------|----------|
+/// | `u8`    | 14        | 14         | 1 byte   |
 /// | `u16`   | 254       | 254        | 2 bytes  |
 /// | `u32`   | 65,534    | 65,534     | 4 bytes  |
 /// | `u64`   | ~4.29B    | ~4.29B     | 8 bytes  |
 /// | `usize` | ~4.29B*   | ~4.29B*    | 8 bytes* |
 ///
 /// *On 64-bit platforms. On 32-bit, same as u32.
+///
+/// # Custom storage
+/// With the `unsealed` feature, other crates can implement this trait for
+/// their own unsigned integer types, such as a newtype holding a 40-bit file
+/// offset. Implementations must uphold:
+/// - `2 * HALF_BITS` does not exceed the bit width of `Self`
+/// - `LOW_MASK` is `(1 << HALF_BITS) - 1`
+/// - `MAX_HALF` is `LOW_MASK - 1` and `MAX_END` is `2 * MAX_HALF`
+/// - `new_nonzero_unchecked` and `get_nonzero` round-trip every non-zero value
+///
+/// `Option<SmallRange<T>>` only stays the same size as `SmallRange<T>` if
+/// `NonZeroStorage` has a niche, as `core::num::NonZero` does.
 pub trait SmallRangeStorage:
     private::Sealed + PrimInt + Unsigned + Hash + AsPrimitive<usize> + 'static
 where
@@ -42,6 +75,12 @@ where
     /// Mask for extracting lower half (all bits set for half-width).
     const LOW_MASK: Self;
 
+    /// Largest start or length a range can hold (`LOW_MASK - 1`).
+    const MAX_HALF: Self;
+
+    /// Largest end a range can have (`2 * MAX_HALF`).
+    const MAX_END: Self;
+
     /// Create a NonZero from storage value.
     ///
     /// # Safety
@@ -52,10 +91,30 @@ where
     fn get_nonzero(nz: Self::NonZeroStorage) -> Self;
 }
 
+impl SmallRangeStorage for u8 {
+    type NonZeroStorage = NonZero<u8>;
+    const HALF_BITS: u32 = 4;
+    const LOW_MASK: Self = 0xF;
+    const MAX_HALF: Self = Self::LOW_MASK - 1;
+    const MAX_END: Self = 2 * Self::MAX_HALF;
+
+    #[inline]
+    unsafe fn new_nonzero_unchecked(val: Self) -> Self::NonZeroStorage {
+        NonZero::new_unchecked(val)
+    }
+
+    #[inline]
+    fn get_nonzero(nz: Self::NonZeroStorage) -> Self {
+        nz.get()
+    }
+}
+
 impl SmallRangeStorage for u16 {
     type NonZeroStorage = NonZero<u16>;
     const HALF_BITS: u32 = 8;
     const LOW_MASK: Self = 0xFF;
+    const MAX_HALF: Self = Self::LOW_MASK - 1;
+    const MAX_END: Self = 2 * Self::MAX_HALF;
 
     #[inline]
     unsafe fn new_nonzero_unchecked(val: Self) -> Self::NonZeroStorage {
@@ -72,6 +131,8 @@ impl SmallRangeStorage for u32 {
     type NonZeroStorage = NonZero<u32>;
     const HALF_BITS: u32 = 16;
     const LOW_MASK: Self = 0xFFFF;
+    const MAX_HALF: Self = Self::LOW_MASK - 1;
+    const MAX_END: Self = 2 * Self::MAX_HALF;
 
     #[inline]
     unsafe fn new_nonzero_unchecked(val: Self) -> Self::NonZeroStorage {
@@ -88,6 +149,8 @@ impl SmallRangeStorage for u64 {
     type NonZeroStorage = NonZero<u64>;
     const HALF_BITS: u32 = 32;
     const LOW_MASK: Self = 0xFFFF_FFFF;
+    const MAX_HALF: Self = Self::LOW_MASK - 1;
+    const MAX_END: Self = 2 * Self::MAX_HALF;
 
     #[inline]
     unsafe fn new_nonzero_unchecked(val: Self) -> Self::NonZeroStorage {
@@ -106,6 +169,8 @@ impl SmallRangeStorage for usize {
     const HALF_BITS: u32 = (core::mem::size_of::<usize>() * 8 / 2) as u32;
     // On 64-bit: 0xFFFF_FFFF, on 32-bit: 0xFFFF
     const LOW_MASK: Self = (1usize << Self::HALF_BITS) - 1;
+    const MAX_HALF: Self = Self::LOW_MASK - 1;
+    const MAX_END: Self = 2 * Self::MAX_HALF;
 
     #[inline]
     unsafe fn new_nonzero_unchecked(val: Self) -> Self::NonZeroStorage {
@@ -118,6 +183,28 @@ impl SmallRangeStorage for usize {
     }
 }
 
+/// Adds, panicking on overflow with the `strict` feature or in debug builds.
+#[inline]
+fn strict_add<T: PrimInt>(a: T, b: T) -> T {
+    if cfg!(feature = "strict") {
+        a.checked_add(&b).expect("attempt to add with overflow")
+    } else {
+        a + b
+    }
+}
+
+/// Subtracts, panicking on underflow with the `strict` feature or in debug
+/// builds.
+#[inline]
+fn strict_sub<T: PrimInt>(a: T, b: T) -> T {
+    if cfg!(feature = "strict") {
+        a.checked_sub(&b)
+            .expect("attempt to subtract with overflow")
+    } else {
+        a - b
+    }
+}
+
 /// A compact range that packs start and length into a single storage value.
 ///
 /// This type stores a range's start position and length in a single value,
@@ -125,9 +212,10 @@ impl SmallRangeStorage for usize {
 /// optimization so `Option<SmallRange<T>>` is the same size as `SmallRange<T>`.
 ///
 /// # Type Parameters
-/// - `T`: The storage type (`u16`, `u32`, `u64`, or `usize`). Defaults to `u64`.
+/// - `T`: The storage type (`u8`, `u16`, `u32`, `u64`, or `usize`). Defaults to `u64`.
 ///
 /// # Storage Layout
+/// - `SmallRange<u8>`: 1 byte (vs 2 bytes for `Range<u8>`)
 /// - `SmallRange<u16>`: 2 bytes (vs 4 bytes for `Range<u16>`)
 /// - `SmallRange<u32>`: 4 bytes (vs 8 bytes for `Range<u32>`)
 /// - `SmallRange<u64>`: 8 bytes (vs 16 bytes for `Range<u64>`)
@@ -136,13 +224,23 @@ impl SmallRangeStorage for usize {
 /// # Encoding
 /// Uses `(start+1, length+1)` encoding where start is in the high bits and
 /// length is in the low bits. Since both halves are always >= 1, the packed
-/// value is never zero, allowing `Option` to use 0 for `None`.
+/// value is never zero, allowing `Option` to use 0 for `None`. This layout
+/// is stable; see [`to_bits`](SmallRange::to_bits).
 ///
 /// # Constraints
 /// - Start must not exceed end
 /// - Start and length must each fit in half the storage width minus 1
 #[repr(transparent)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::TryFromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
 pub struct SmallRange<T: SmallRangeStorage = u64>
 where
     usize: AsPrimitive<T>,
@@ -156,13 +254,18 @@ where
 {
     #[inline]
     fn encode(start: T, end: T) -> T::NonZeroStorage {
-        debug_assert!(start <= end, "start must not exceed end");
-        let length = end - start;
+        strict_assert!(start <= end, "start must not exceed end");
+        Self::encode_start_length(start, end - start)
+    }
+
+    #[inline]
+    fn encode_start_length(start: T, length: T) -> T::NonZeroStorage {
+        // Compare before adding 1 so that values near T::MAX can't overflow
+        strict_assert!(start < T::LOW_MASK, "start+1 exceeds half-width capacity");
+        strict_assert!(length < T::LOW_MASK, "length+1 exceeds half-width capacity");
         // Add 1 to both, ensuring neither half is ever 0
         let hi = start + T::one();
         let lo = length + T::one();
-        debug_assert!(hi <= T::LOW_MASK, "start+1 exceeds half-width capacity");
-        debug_assert!(lo <= T::LOW_MASK, "length+1 exceeds half-width capacity");
         let packed = (hi << T::HALF_BITS as usize) | lo;
         // SAFETY: packed is NEVER zero because both hi >= 1 and lo >= 1
         unsafe { T::new_nonzero_unchecked(packed) }
@@ -178,11 +281,51 @@ where
         (start, length)
     }
 
+    /// Largest start value this storage type can hold.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// assert_eq!(SmallRange::<u16>::MAX_START, 254);
+    /// assert_eq!(SmallRange::<u32>::MAX_START, 0xFFFE);
+    /// ```
+    pub const MAX_START: T = T::MAX_HALF;
+
+    /// Largest length this storage type can hold.
+    pub const MAX_LEN: T = T::MAX_HALF;
+
+    /// Largest end value any range can have: `MAX_START + MAX_LEN`.
+    ///
+    /// Not every range ending here fits; the length limit still applies.
+    pub const MAX_END: T = T::MAX_END;
+
+    /// Returns `true` if `start..end` can be stored in a `SmallRange<T>`.
+    ///
+    /// Equivalent to `SmallRange::try_new(start, end).is_some()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// assert!(SmallRange::<u16>::fits(254, 508));
+    /// assert!(!SmallRange::<u16>::fits(255, 256));
+    /// assert!(!SmallRange::<u16>::fits(0, 255));
+    /// assert!(!SmallRange::<u16>::fits(20, 10));
+    /// ```
+    #[inline]
+    pub fn fits(start: T, end: T) -> bool {
+        Self::try_encode(start, end).is_ok()
+    }
+
     /// Creates a new `SmallRange` with the given start and end values.
     ///
     /// # Panics (debug only)
     /// - If start exceeds end
     /// - If start or length exceed the half-width capacity
+    ///
+    /// With the `strict` feature enabled, these checks (and every other
+    /// "Panics (debug only)" check in this crate) also run in release builds.
     #[inline]
     pub fn new(start: T, end: T) -> Self {
         Self {
@@ -190,6 +333,272 @@ where
         }
     }
 
+    /// Creates a new `SmallRange` without any validation, not even the
+    /// debug-only checks in [`new`](Self::new).
+    ///
+    /// # Safety
+    /// - `start` must not exceed `end`
+    /// - `start` and `end - start` must not exceed
+    ///   [`MAX_START`](Self::MAX_START) and [`MAX_LEN`](Self::MAX_LEN)
+    ///
+    /// Violating these produces a packed value of zero (undefined behavior)
+    /// or a range that decodes to different bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// // SAFETY: 10 <= 20 and both fit in u32's half-width
+    /// let span = unsafe { SmallRange::<u32>::new_unchecked(10, 20) };
+    /// assert_eq!(span, SmallRange::new(10, 20));
+    /// ```
+    #[inline]
+    pub unsafe fn new_unchecked(start: T, end: T) -> Self {
+        let hi = start + T::one();
+        let lo = end - start + T::one();
+        Self {
+            // SAFETY: the caller guarantees hi and lo fit their halves, and
+            // both are >= 1, so packed is non-zero
+            bits: unsafe { T::new_nonzero_unchecked((hi << T::HALF_BITS as usize) | lo) },
+        }
+    }
+
+    /// Creates a new `SmallRange` from a start value and a length.
+    ///
+    /// This matches the internal encoding, so no `end` is computed and
+    /// `start + len` can't overflow along the way.
+    ///
+    /// # Panics (debug only)
+    /// - If start or length exceed the half-width capacity
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let span = SmallRange::<u32>::from_start_len(10, 5);
+    /// assert_eq!(span, SmallRange::new(10, 15));
+    /// ```
+    #[inline]
+    pub fn from_start_len(start: T, len: T) -> Self {
+        Self {
+            bits: Self::encode_start_length(start, len),
+        }
+    }
+
+    /// Creates a new `SmallRange` from a start value and a length if both fit.
+    ///
+    /// Returns `None` if `start` or `len` exceed the half-width capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// assert_eq!(
+    ///     SmallRange::<u16>::try_from_start_len(200, 254),
+    ///     Some(SmallRange::new(200, 454))
+    /// );
+    /// assert_eq!(SmallRange::<u16>::try_from_start_len(200, 255), None);
+    /// ```
+    #[inline]
+    pub fn try_from_start_len(start: T, len: T) -> Option<Self> {
+        Self::try_encode_start_length(start, len)
+            .ok()
+            .map(|bits| Self { bits })
+    }
+
+    /// Creates a range from arbitrary values, adjusting them to fit instead
+    /// of failing.
+    ///
+    /// - If `start > end`, the bounds are swapped.
+    /// - The start is clamped to the largest representable start.
+    /// - The length is truncated to the largest representable length, keeping
+    ///   the start.
+    ///
+    /// This is lossy but total, which suits ingesting untrusted input.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// assert_eq!(SmallRange::<u16>::new_clamped(10, 20), SmallRange::new(10, 20));
+    /// assert_eq!(SmallRange::<u16>::new_clamped(20, 10), SmallRange::new(10, 20));
+    /// assert_eq!(SmallRange::<u16>::new_clamped(0, 1000), SmallRange::new(0, 254));
+    /// assert_eq!(SmallRange::<u16>::new_clamped(300, 400), SmallRange::new(254, 400));
+    /// ```
+    pub fn new_clamped(start: T, end: T) -> Self {
+        let (start, end) = if start <= end {
+            (start, end)
+        } else {
+            (end, start)
+        };
+        let start = start.min(T::MAX_HALF);
+        let length = (end - start).min(T::MAX_HALF);
+        Self::from_start_len(start, length)
+    }
+
+    /// Creates a range extending `radius` values on each side of `center`.
+    ///
+    /// The result is `center - radius..center + radius`, with the start
+    /// clamped at zero. Returns `None` if the end overflows the storage type or
+    /// the range exceeds the half-width capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// // 16 bytes of context around offset 100
+    /// assert_eq!(SmallRange::<u32>::centered_at(100, 16), Some(SmallRange::new(84, 116)));
+    ///
+    /// // Clamped at zero on the low side
+    /// assert_eq!(SmallRange::<u32>::centered_at(5, 16), Some(SmallRange::new(0, 21)));
+    /// ```
+    #[inline]
+    pub fn centered_at(center: T, radius: T) -> Option<Self> {
+        Self::try_new(center.saturating_sub(radius), center.checked_add(&radius)?)
+    }
+
+    /// Creates a range from any [`RangeBounds`], resolving unbounded ends
+    /// against the domain `0..domain_end`.
+    ///
+    /// This lets APIs accept `..`, `a..`, `..=b`, `a..b` and friends the way
+    /// slice indexing does. An unbounded start resolves to zero and an
+    /// unbounded end to `domain_end`.
+    ///
+    /// Returns `None` if:
+    /// - an inclusive end or exclusive start overflows `T`
+    /// - the resolved end exceeds `domain_end`
+    /// - the resolved range is invalid or exceeds half-width capacity
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let len = 100u32;
+    /// assert_eq!(SmallRange::try_from_bounds(.., len), Some(SmallRange::new(0, 100)));
+    /// assert_eq!(SmallRange::try_from_bounds(10.., len), Some(SmallRange::new(10, 100)));
+    /// assert_eq!(SmallRange::try_from_bounds(..=9, len), Some(SmallRange::new(0, 10)));
+    /// assert_eq!(SmallRange::try_from_bounds(10..20, len), Some(SmallRange::new(10, 20)));
+    ///
+    /// // Past the end of the domain
+    /// assert_eq!(SmallRange::try_from_bounds(90..=100, len), None);
+    /// ```
+    pub fn try_from_bounds(bounds: impl RangeBounds<T>, domain_end: T) -> Option<Self> {
+        let start = match bounds.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(&T::one())?,
+            Bound::Unbounded => T::zero(),
+        };
+        let end = match bounds.end_bound() {
+            Bound::Included(&end) => end.checked_add(&T::one())?,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => domain_end,
+        };
+        if end > domain_end {
+            return None;
+        }
+        Self::try_new(start, end)
+    }
+
+    /// Creates the smallest range covering every value, or an error if it
+    /// can't be stored.
+    ///
+    /// This is the fallible form of collecting values into a `SmallRange`.
+    /// The values don't need to be sorted. No values gives the empty range
+    /// `0..0`.
+    ///
+    /// # Errors
+    /// Returns [`SmallRangeError`] if the covering range exceeds the
+    /// half-width capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{SmallRange, SmallRangeError};
+    ///
+    /// let token_offsets = [12u32, 4, 9];
+    /// assert_eq!(SmallRange::try_from_values(token_offsets), Ok(SmallRange::new(4, 13)));
+    /// assert_eq!(SmallRange::<u32>::try_from_values([]), Ok(SmallRange::default()));
+    ///
+    /// // u16 ranges hold at most 254 values
+    /// assert_eq!(
+    ///     SmallRange::<u16>::try_from_values([0, 300]),
+    ///     Err(SmallRangeError::LengthTooLarge)
+    /// );
+    /// ```
+    pub fn try_from_values(values: impl IntoIterator<Item = T>) -> Result<Self, SmallRangeError> {
+        match min_max(values) {
+            Some((min, max)) => Self::try_from(min..=max),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Returns the smallest range covering every range, or an error if it
+    /// can't be stored.
+    ///
+    /// This is the fallible form of collecting ranges into their
+    /// [`hull`](Self::hull). Empty ranges contribute no values and are
+    /// skipped. No non-empty ranges gives the empty range `0..0`.
+    ///
+    /// # Errors
+    /// Returns [`SmallRangeError::LengthTooLarge`] if the covering range
+    /// exceeds the half-width capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{SmallRange, SmallRangeError};
+    ///
+    /// let children = [SmallRange::<u32>::new(10, 14), SmallRange::new(20, 25)];
+    /// assert_eq!(SmallRange::try_hull_of(children), Ok(SmallRange::new(10, 25)));
+    ///
+    /// // u16 ranges hold at most 254 values
+    /// let far_apart = [SmallRange::<u16>::new(0, 10), SmallRange::new(250, 260)];
+    /// assert_eq!(SmallRange::try_hull_of(far_apart), Err(SmallRangeError::LengthTooLarge));
+    /// ```
+    pub fn try_hull_of(ranges: impl IntoIterator<Item = Self>) -> Result<Self, SmallRangeError> {
+        match hull_bounds(ranges) {
+            Some((start, end)) => Self::try_new_checked(start, end),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Parses a range written as `start..end` or `start..=end`.
+    ///
+    /// Both bounds are decimal integers; no whitespace is allowed. The
+    /// inclusive form is converted to the equivalent half-open range.
+    ///
+    /// # Errors
+    /// Returns [`ParseSmallRangeError`] if the separator is missing, a bound
+    /// isn't a valid integer, or the range can't be stored.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{ParseSmallRangeError, SmallRange, SmallRangeError};
+    ///
+    /// assert_eq!(SmallRange::<u32>::parse("10..20"), Ok(SmallRange::new(10, 20)));
+    /// assert_eq!(SmallRange::<u32>::parse("10..=19"), Ok(SmallRange::new(10, 20)));
+    ///
+    /// assert_eq!(SmallRange::<u32>::parse("10-20"), Err(ParseSmallRangeError::MissingSeparator));
+    /// assert_eq!(
+    ///     SmallRange::<u32>::parse("20..10"),
+    ///     Err(ParseSmallRangeError::Range(SmallRangeError::StartAfterEnd))
+    /// );
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, ParseSmallRangeError> {
+        let (start, rest) = s
+            .split_once("..")
+            .ok_or(ParseSmallRangeError::MissingSeparator)?;
+        let (end, inclusive) = match rest.strip_prefix('=') {
+            Some(end) => (end, true),
+            None => (rest, false),
+        };
+        let start = T::from_str_radix(start, 10).map_err(|_| ParseSmallRangeError::InvalidStart)?;
+        let end = T::from_str_radix(end, 10).map_err(|_| ParseSmallRangeError::InvalidEnd)?;
+        if inclusive {
+            Ok(Self::try_from(start..=end)?)
+        } else {
+            Ok(Self::try_new_checked(start, end)?)
+        }
+    }
+
     /// Returns the start of the range.
     #[inline]
     pub fn start(&self) -> T {
@@ -220,112 +629,1785 @@ where
         lo == T::one() // length + 1 == 1 means length == 0
     }
 
-    /// Converts the `SmallRange` to a standard `Range<T>`.
+    /// Returns the middle element of the range, or `None` if it is empty.
+    ///
+    /// The midpoint is `start + len / 2`, so for even lengths it is the upper
+    /// of the two middle elements. It is always contained in the range.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// assert_eq!(SmallRange::<u32>::new(10, 15).midpoint(), Some(12));
+    /// assert_eq!(SmallRange::<u32>::new(10, 14).midpoint(), Some(12));
+    /// assert_eq!(SmallRange::<u32>::new(10, 11).midpoint(), Some(10));
+    /// assert_eq!(SmallRange::<u32>::new(10, 10).midpoint(), None);
+    /// ```
     #[inline]
-    pub fn to_range(&self) -> Range<T> {
+    pub fn midpoint(&self) -> Option<T> {
         let (start, length) = Self::decode_start_length(self.bits);
-        start..(start + length)
+        if length.is_zero() {
+            return None;
+        }
+        Some(start + (length >> 1))
     }
 
-    /// Creates a new `SmallRange` if the values are valid, returns `None` otherwise.
+    /// Returns the `index`-th value of the range, or `None` if out of bounds.
     ///
-    /// Returns `None` if:
-    /// - `start > end`
-    /// - `start` or `length` exceed half-width capacity
+    /// This treats the range as a virtual slice of consecutive values:
+    /// `get(i)` is `start + i` whenever `i < len()`.
     ///
     /// # Examples
     /// ```
     /// use small_range::SmallRange;
     ///
-    /// // Valid range
-    /// assert!(SmallRange::<u32>::try_new(10, 20).is_some());
+    /// let range = SmallRange::<u32>::new(10, 15);
+    /// assert_eq!(range.get(0), Some(10));
+    /// assert_eq!(range.get(4), Some(14));
+    /// assert_eq!(range.get(5), None);
+    /// ```
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<T> {
+        if index < self.len() {
+            Some(self.start() + index.as_())
+        } else {
+            None
+        }
+    }
+
+    /// Returns an integer key whose natural ordering matches the range's
+    /// `(start, end)` ordering.
     ///
-    /// // Invalid: start > end
-    /// assert!(SmallRange::<u32>::try_new(20, 10).is_none());
+    /// For any two ranges of the same storage type,
+    /// `a.sort_key().cmp(&b.sort_key()) == a.cmp(&b)`. This is a stable
+    /// guarantee, so the key can drive `sort_unstable_by_key` or a radix sort
+    /// without decoding either field. The key is never zero.
     ///
-    /// // Invalid: values exceed capacity
-    /// assert!(SmallRange::<u16>::try_new(255, 300).is_none());
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let mut spans = vec![SmallRange::<u32>::new(10, 20), SmallRange::new(5, 8)];
+    /// spans.sort_unstable_by_key(|r| r.sort_key());
+    /// assert_eq!(spans, [SmallRange::new(5, 8), SmallRange::new(10, 20)]);
     /// ```
     #[inline]
-    pub fn try_new(start: T, end: T) -> Option<Self> {
-        if start > end {
-            return None;
-        }
-        let length = end - start;
-        let hi = start + T::one();
-        let lo = length + T::one();
-        if hi > T::LOW_MASK || lo > T::LOW_MASK {
-            return None;
-        }
-        let packed = (hi << T::HALF_BITS as usize) | lo;
-        // SAFETY: packed is never zero because both hi >= 1 and lo >= 1
-        Some(Self {
-            bits: unsafe { T::new_nonzero_unchecked(packed) },
-        })
+    pub fn sort_key(&self) -> T {
+        // start+1 occupies the high half, and for equal starts length+1
+        // orders the same as the end.
+        T::get_nonzero(self.bits)
     }
 
-    /// Returns `true` if the range contains the given value.
+    /// Returns the packed representation widened to `u64`, a key that is
+    /// unique per range and never zero.
     ///
-    /// A value is contained if `start <= value < end`.
+    /// Use it to key an identity-hashed map such as nohash-hasher's
+    /// `IntMap<u64, V>`, or a map shared between storage types.
     ///
     /// # Examples
     /// ```
     /// use small_range::SmallRange;
     ///
-    /// let range = SmallRange::<u32>::new(5, 10);
-    /// assert!(range.contains(5));   // start is included
-    /// assert!(range.contains(7));
-    /// assert!(!range.contains(10)); // end is excluded
-    /// assert!(!range.contains(4));
+    /// let span = SmallRange::<u32>::new(10, 20);
+    /// assert_eq!(span.to_u64_key(), u64::from(span.to_bits().get()));
+    /// assert_ne!(span.to_u64_key(), SmallRange::<u32>::new(10, 21).to_u64_key());
     /// ```
     #[inline]
-    pub fn contains(&self, value: T) -> bool {
-        value >= self.start() && value < self.end()
+    pub fn to_u64_key(&self) -> u64
+    where
+        T: AsPrimitive<u64>,
+    {
+        T::get_nonzero(self.bits).as_()
     }
 
-    /// Returns `true` if this range overlaps with `other`.
+    /// Converts the `SmallRange` to a standard `Range<T>`.
     ///
-    /// Two ranges overlap if they share at least one common value.
-    /// Empty ranges never overlap with anything (including themselves).
+    /// `SmallRange` can't implement `RangeBounds<T>` since it has no stored
+    /// endpoints to borrow, so use this to pass it to APIs such as
+    /// `Vec::drain` or `BTreeMap::range`.
     ///
     /// # Examples
     /// ```
     /// use small_range::SmallRange;
     ///
-    /// let a = SmallRange::<u32>::new(0, 10);
-    /// let b = SmallRange::<u32>::new(5, 15);
-    /// let c = SmallRange::<u32>::new(10, 20);
+    /// let mut v: Vec<u32> = (0..10).collect();
+    /// v.drain(SmallRange::<usize>::new(2, 8).to_range());
+    /// assert_eq!(v, [0, 1, 8, 9]);
+    /// ```
+    #[inline]
+    pub fn to_range(&self) -> Range<T> {
+        let (start, length) = Self::decode_start_length(self.bits);
+        start..(start + length)
+    }
+
+    /// Returns `(start, end)` with a single decode.
     ///
-    /// assert!(a.overlaps(&b));   // overlap at 5..10
-    /// assert!(!a.overlaps(&c));  // a ends where c starts (no overlap)
-    /// assert!(b.overlaps(&c));   // overlap at 10..15
+    /// Prefer this over separate [`start`](Self::start) and
+    /// [`end`](Self::end) calls in hot loops.
     ///
-    /// // Empty ranges never overlap
-    /// let empty = SmallRange::<u32>::new(5, 5);
-    /// assert!(!empty.overlaps(&a));
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let (start, end) = SmallRange::<u32>::new(10, 20).to_parts();
+    /// assert_eq!((start, end), (10, 20));
     /// ```
     #[inline]
-    pub fn overlaps(&self, other: &Self) -> bool {
-        // Empty ranges never overlap with anything
-        !self.is_empty()
-            && !other.is_empty()
-            && self.start() < other.end()
-            && other.start() < self.end()
+    pub fn to_parts(&self) -> (T, T) {
+        let (start, length) = Self::decode_start_length(self.bits);
+        (start, start + length)
     }
-}
 
-impl<T: SmallRangeStorage> Default for SmallRange<T>
-where
-    usize: AsPrimitive<T>,
-{
-    fn default() -> Self {
-        Self::new(T::zero(), T::zero())
+    /// Returns `(start, len)` with a single decode.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let (start, len) = SmallRange::<u32>::new(10, 20).to_start_len();
+    /// assert_eq!((start, len), (10, 10));
+    /// ```
+    #[inline]
+    pub fn to_start_len(&self) -> (T, T) {
+        Self::decode_start_length(self.bits)
     }
-}
 
-impl<T: SmallRangeStorage + fmt::Debug> fmt::Debug for SmallRange<T>
-where
-    usize: AsPrimitive<T>,
+    /// Returns the packed representation.
+    ///
+    /// The layout is a stable guarantee: the high half of the storage holds
+    /// `start + 1` and the low half holds `len + 1`, so neither half is ever
+    /// zero. Values written with `to_bits` can be persisted and reloaded with
+    /// [`from_bits`](Self::from_bits) by any version of this crate using the
+    /// same storage type. `usize` storage differs between 32- and 64-bit
+    /// targets, so use a fixed-width type for data that crosses platforms.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let span = SmallRange::<u32>::new(10, 20);
+    /// assert_eq!(span.to_bits().get(), (11 << 16) | 11);
+    /// ```
+    #[inline]
+    pub fn to_bits(&self) -> T::NonZeroStorage {
+        self.bits
+    }
+
+    /// Rebuilds a range from its packed representation.
+    ///
+    /// Returns `None` if either half of `bits` is zero, which no valid range
+    /// produces. See [`to_bits`](Self::to_bits) for the layout.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let span = SmallRange::<u32>::new(10, 20);
+    /// assert_eq!(SmallRange::from_bits(span.to_bits().get()), Some(span));
+    ///
+    /// assert_eq!(SmallRange::<u32>::from_bits(0), None);
+    /// assert_eq!(SmallRange::<u32>::from_bits(11 << 16), None);
+    /// ```
+    #[inline]
+    pub fn from_bits(bits: T) -> Option<Self> {
+        let hi = bits >> T::HALF_BITS as usize;
+        let lo = bits & T::LOW_MASK;
+        // hi > LOW_MASK only happens with storage wider than 2 * HALF_BITS
+        if hi.is_zero() || hi > T::LOW_MASK || lo.is_zero() {
+            return None;
+        }
+        // SAFETY: both halves are non-zero
+        Some(unsafe { Self::from_bits_unchecked(bits) })
+    }
+
+    /// Rebuilds a range from its packed representation without validation.
+    ///
+    /// # Safety
+    /// Both halves of `bits` must be non-zero, as they are for any value
+    /// returned by [`to_bits`](Self::to_bits).
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let span = SmallRange::<u32>::new(10, 20);
+    /// // SAFETY: bits come straight from a valid range
+    /// let copy = unsafe { SmallRange::from_bits_unchecked(span.to_bits().get()) };
+    /// assert_eq!(copy, span);
+    /// ```
+    #[inline]
+    pub unsafe fn from_bits_unchecked(bits: T) -> Self {
+        debug_assert!(
+            !(bits >> T::HALF_BITS as usize).is_zero() && !(bits & T::LOW_MASK).is_zero(),
+            "both halves of bits must be non-zero"
+        );
+        Self {
+            // SAFETY: the caller guarantees both halves, and thus bits, are non-zero
+            bits: unsafe { T::new_nonzero_unchecked(bits) },
+        }
+    }
+
+    /// Converts the range to the equivalent `RangeInclusive<T>`.
+    ///
+    /// Returns `None` for an empty range, which has no inclusive equivalent
+    /// with the same start.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// assert_eq!(SmallRange::<u32>::new(10, 20).to_inclusive(), Some(10..=19));
+    /// assert_eq!(SmallRange::<u32>::new(10, 10).to_inclusive(), None);
+    /// ```
+    #[inline]
+    pub fn to_inclusive(&self) -> Option<RangeInclusive<T>> {
+        let (start, length) = Self::decode_start_length(self.bits);
+        if length.is_zero() {
+            return None;
+        }
+        Some(start..=(start + length - T::one()))
+    }
+
+    /// Returns an adapter whose `Debug` output is `start..end` instead of the
+    /// struct form.
+    ///
+    /// Useful when dumping many ranges, where `SmallRange { start: .., end: .. }`
+    /// per element is too noisy.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let spans = [SmallRange::<u32>::new(0, 4), SmallRange::new(10, 20)];
+    /// let compact: Vec<_> = spans.iter().map(|r| r.compact_debug()).collect();
+    /// assert_eq!(format!("{:?}", compact), "[0..4, 10..20]");
+    /// ```
+    #[inline]
+    pub fn compact_debug(&self) -> CompactDebug<T> {
+        CompactDebug(*self)
+    }
+
+    /// Converts the range to a wider storage type.
+    ///
+    /// Available wherever a lossless `From` conversion exists: `u16` to `u32`,
+    /// `u64` or `usize`, `u32` to `u64` or `usize`, and `usize` to `u64`. The
+    /// conversions into `usize` need a 32- or 64-bit target.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let narrow = SmallRange::<u16>::new(10, 20);
+    /// let wide: SmallRange<u64> = narrow.widen();
+    /// assert_eq!(wide, SmallRange::new(10, 20));
+    /// ```
+    #[inline]
+    pub fn widen<U: SmallRangeStorage>(self) -> SmallRange<U>
+    where
+        usize: AsPrimitive<U>,
+        SmallRange<U>: From<Self>,
+    {
+        self.into()
+    }
+
+    /// Converts the range to a narrower storage type, if it fits.
+    ///
+    /// Returns `None` if the start or length exceeds the target's half-width
+    /// capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let wide = SmallRange::<u64>::new(10, 20);
+    /// assert_eq!(wide.try_narrow::<u16>(), Some(SmallRange::new(10, 20)));
+    ///
+    /// let big = SmallRange::<u64>::new(1000, 2000);
+    /// assert_eq!(big.try_narrow::<u16>(), None);
+    /// ```
+    #[inline]
+    pub fn try_narrow<U: SmallRangeStorage>(self) -> Option<SmallRange<U>>
+    where
+        usize: AsPrimitive<U>,
+        SmallRange<U>: TryFrom<Self>,
+    {
+        self.try_into().ok()
+    }
+
+    /// Creates a new `SmallRange` if the values are valid, returns `None` otherwise.
+    ///
+    /// Returns `None` if:
+    /// - `start > end`
+    /// - `start` or `length` exceed half-width capacity
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// // Valid range
+    /// assert!(SmallRange::<u32>::try_new(10, 20).is_some());
+    ///
+    /// // Invalid: start > end
+    /// assert!(SmallRange::<u32>::try_new(20, 10).is_none());
+    ///
+    /// // Invalid: values exceed capacity
+    /// assert!(SmallRange::<u16>::try_new(255, 300).is_none());
+    /// ```
+    #[inline]
+    pub fn try_new(start: T, end: T) -> Option<Self> {
+        Self::try_new_checked(start, end).ok()
+    }
+
+    /// Creates a new `SmallRange`, reporting why the values are invalid.
+    ///
+    /// Like [`try_new`](Self::try_new), but the error says which constraint
+    /// was violated.
+    ///
+    /// # Errors
+    /// - [`SmallRangeError::StartAfterEnd`] if `start > end`
+    /// - [`SmallRangeError::StartTooLarge`] if `start` exceeds half-width capacity
+    /// - [`SmallRangeError::LengthTooLarge`] if `end - start` exceeds half-width capacity
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{SmallRange, SmallRangeError};
+    ///
+    /// assert_eq!(SmallRange::<u16>::try_new_checked(10, 20), Ok(SmallRange::new(10, 20)));
+    /// assert_eq!(SmallRange::<u16>::try_new_checked(20, 10), Err(SmallRangeError::StartAfterEnd));
+    /// assert_eq!(SmallRange::<u16>::try_new_checked(255, 256), Err(SmallRangeError::StartTooLarge));
+    /// assert_eq!(SmallRange::<u16>::try_new_checked(0, 255), Err(SmallRangeError::LengthTooLarge));
+    /// ```
+    #[inline]
+    pub fn try_new_checked(start: T, end: T) -> Result<Self, SmallRangeError> {
+        Self::try_encode(start, end).map(|bits| Self { bits })
+    }
+
+    #[inline]
+    fn try_encode(start: T, end: T) -> Result<T::NonZeroStorage, SmallRangeError> {
+        if start > end {
+            return Err(SmallRangeError::StartAfterEnd);
+        }
+        Self::try_encode_start_length(start, end - start)
+    }
+
+    #[inline]
+    fn try_encode_start_length(start: T, length: T) -> Result<T::NonZeroStorage, SmallRangeError> {
+        // Compare before adding 1 so that values near T::MAX can't overflow
+        if start >= T::LOW_MASK {
+            return Err(SmallRangeError::StartTooLarge);
+        }
+        if length >= T::LOW_MASK {
+            return Err(SmallRangeError::LengthTooLarge);
+        }
+        let hi = start + T::one();
+        let lo = length + T::one();
+        let packed = (hi << T::HALF_BITS as usize) | lo;
+        // SAFETY: packed is never zero because both hi >= 1 and lo >= 1
+        Ok(unsafe { T::new_nonzero_unchecked(packed) })
+    }
+
+    /// Returns `true` if the range contains the given value.
+    ///
+    /// A value is contained if `start <= value < end`.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let range = SmallRange::<u32>::new(5, 10);
+    /// assert!(range.contains(5));   // start is included
+    /// assert!(range.contains(7));
+    /// assert!(!range.contains(10)); // end is excluded
+    /// assert!(!range.contains(4));
+    /// ```
+    #[inline]
+    pub fn contains(&self, value: T) -> bool {
+        value >= self.start() && value < self.end()
+    }
+
+    /// Returns the zero-based offset of `value` within the range.
+    ///
+    /// This is the inverse of [`get`](Self::get): `position_of(v)` is
+    /// `Some(v - start)` if the range contains `v`, and `None` otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let range = SmallRange::<u32>::new(10, 15);
+    /// assert_eq!(range.position_of(10), Some(0));
+    /// assert_eq!(range.position_of(14), Some(4));
+    /// assert_eq!(range.position_of(15), None);
+    /// assert_eq!(range.position_of(9), None);
+    /// ```
+    #[inline]
+    pub fn position_of(&self, value: T) -> Option<usize> {
+        if self.contains(value) {
+            Some((value - self.start()).as_())
+        } else {
+            None
+        }
+    }
+
+    /// Compares this range against a single value.
+    ///
+    /// Returns how the range is positioned relative to `value`:
+    /// - `Less` if the range lies entirely below it (`end <= value`)
+    /// - `Equal` if the range contains it
+    /// - `Greater` if the range lies entirely above it (`value < start`)
+    ///
+    /// The result plugs directly into `binary_search_by` to find the range
+    /// containing a point in a sorted list of disjoint ranges. An empty range
+    /// never returns `Equal`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use small_range::SmallRange;
+    ///
+    /// let range = SmallRange::<u32>::new(10, 20);
+    /// assert_eq!(range.cmp_value(5), Ordering::Greater);
+    /// assert_eq!(range.cmp_value(15), Ordering::Equal);
+    /// assert_eq!(range.cmp_value(20), Ordering::Less);
+    ///
+    /// let spans = [
+    ///     SmallRange::<u32>::new(0, 10),
+    ///     SmallRange::new(10, 25),
+    ///     SmallRange::new(40, 50),
+    /// ];
+    /// assert_eq!(spans.binary_search_by(|r| r.cmp_value(12)), Ok(1));
+    /// assert_eq!(spans.binary_search_by(|r| r.cmp_value(30)), Err(2));
+    /// ```
+    #[inline]
+    pub fn cmp_value(&self, value: T) -> Ordering {
+        let (start, end) = (self.start(), self.end());
+        if value < start {
+            Ordering::Greater
+        } else if value >= end {
+            Ordering::Less
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    /// Clamps `value` to the values contained in this range.
+    ///
+    /// Returns `value` if the range contains it, `start` if it lies below the
+    /// range, and `end - 1` (the last contained value) if it lies above. An empty
+    /// range has no values to clamp to, so it always returns `start`.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let range = SmallRange::<u32>::new(10, 20);
+    /// assert_eq!(range.clamp_value(5), 10);
+    /// assert_eq!(range.clamp_value(15), 15);
+    /// assert_eq!(range.clamp_value(25), 19);
+    ///
+    /// // Empty ranges clamp everything to their start
+    /// assert_eq!(SmallRange::<u32>::new(7, 7).clamp_value(100), 7);
+    /// ```
+    #[inline]
+    pub fn clamp_value(&self, value: T) -> T {
+        let (start, end) = (self.start(), self.end());
+        if value <= start || start == end {
+            start
+        } else if value >= end {
+            end - T::one()
+        } else {
+            value
+        }
+    }
+
+    /// Returns the distance from `value` to the nearest value in this range.
+    ///
+    /// Returns `0` if the range contains `value`, `start - value` if it lies
+    /// below the range, and `value - (end - 1)` if it lies above. This is the
+    /// distance between `value` and [`clamp_value`](Self::clamp_value), so for
+    /// an empty range it is the distance to `start`.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let range = SmallRange::<u32>::new(10, 20);
+    /// assert_eq!(range.distance_to(15), 0);
+    /// assert_eq!(range.distance_to(4), 6);
+    /// assert_eq!(range.distance_to(20), 1); // end is excluded
+    /// assert_eq!(range.distance_to(25), 6);
+    /// ```
+    #[inline]
+    pub fn distance_to(&self, value: T) -> T {
+        let nearest = self.clamp_value(value);
+        if value < nearest {
+            nearest - value
+        } else {
+            value - nearest
+        }
+    }
+
+    /// Returns this range grown just enough to contain `value`.
+    ///
+    /// The result is the smallest range covering both this range and `value`.
+    /// An empty range contributes no values, so expanding it yields the
+    /// single-value range `value..value + 1`. This makes an empty range a
+    /// convenient starting point when accumulating a bounding span.
+    ///
+    /// Returns `None` if the grown range would exceed the half-width capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let range = SmallRange::<u32>::new(10, 20);
+    /// assert_eq!(range.expand_to_include(25), Some(SmallRange::new(10, 26)));
+    /// assert_eq!(range.expand_to_include(5), Some(SmallRange::new(5, 20)));
+    /// assert_eq!(range.expand_to_include(15), Some(range));
+    ///
+    /// // Accumulate a bounding span from an empty start
+    /// let span = [7u32, 3, 12]
+    ///     .into_iter()
+    ///     .try_fold(SmallRange::default(), |span, v| span.expand_to_include(v));
+    /// assert_eq!(span, Some(SmallRange::new(3, 13)));
+    ///
+    /// // Exceeds u16 capacity (max length 254)
+    /// assert_eq!(SmallRange::<u16>::new(0, 10).expand_to_include(300), None);
+    /// ```
+    #[inline]
+    pub fn expand_to_include(&self, value: T) -> Option<Self> {
+        let value_end = value.checked_add(&T::one())?;
+        if self.is_empty() {
+            return Self::try_new(value, value_end);
+        }
+        Self::try_new(self.start().min(value), self.end().max(value_end))
+    }
+
+    /// Returns `true` if every value of `other` is also in this range.
+    ///
+    /// An empty range contains no values, so it is contained in every range
+    /// (including other empty ranges), regardless of where it is positioned.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let outer = SmallRange::<u32>::new(0, 100);
+    /// let inner = SmallRange::<u32>::new(25, 75);
+    /// let partial = SmallRange::<u32>::new(50, 150);
+    ///
+    /// assert!(outer.contains_range(&inner));
+    /// assert!(!outer.contains_range(&partial));
+    ///
+    /// // Empty ranges are contained everywhere
+    /// assert!(inner.contains_range(&SmallRange::new(500, 500)));
+    /// ```
+    #[inline]
+    pub fn contains_range(&self, other: &Self) -> bool {
+        other.is_empty() || (self.start() <= other.start() && other.end() <= self.end())
+    }
+
+    /// Returns `true` if every value of this range is also in `other`.
+    ///
+    /// This is the mirror of [`contains_range`](Self::contains_range):
+    /// `a.is_subset_of(&b)` is equivalent to `b.contains_range(&a)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let outer = SmallRange::<u32>::new(0, 100);
+    /// let inner = SmallRange::<u32>::new(25, 75);
+    ///
+    /// assert!(inner.is_subset_of(&outer));
+    /// assert!(!outer.is_subset_of(&inner));
+    /// ```
+    #[inline]
+    pub fn is_subset_of(&self, other: &Self) -> bool {
+        other.contains_range(self)
+    }
+
+    /// Returns `true` if this range overlaps with `other`.
+    ///
+    /// Two ranges overlap if they share at least one common value.
+    /// Empty ranges never overlap with anything (including themselves).
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let a = SmallRange::<u32>::new(0, 10);
+    /// let b = SmallRange::<u32>::new(5, 15);
+    /// let c = SmallRange::<u32>::new(10, 20);
+    ///
+    /// assert!(a.overlaps(&b));   // overlap at 5..10
+    /// assert!(!a.overlaps(&c));  // a ends where c starts (no overlap)
+    /// assert!(b.overlaps(&c));   // overlap at 10..15
+    ///
+    /// // Empty ranges never overlap
+    /// let empty = SmallRange::<u32>::new(5, 5);
+    /// assert!(!empty.overlaps(&a));
+    /// ```
+    #[inline]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        // Empty ranges never overlap with anything
+        !self.is_empty()
+            && !other.is_empty()
+            && self.start() < other.end()
+            && other.start() < self.end()
+    }
+
+    /// Returns the number of values shared by this range and `other`.
+    ///
+    /// This is the length of the intersection, computed directly from the
+    /// endpoints without building an intermediate range. Returns `0` for
+    /// disjoint, adjacent, or empty ranges.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let a = SmallRange::<u32>::new(0, 10);
+    ///
+    /// assert_eq!(a.overlap_len(&SmallRange::new(5, 15)), 5);
+    /// assert_eq!(a.overlap_len(&SmallRange::new(2, 4)), 2);
+    /// assert_eq!(a.overlap_len(&SmallRange::new(10, 20)), 0);
+    /// ```
+    #[inline]
+    pub fn overlap_len(&self, other: &Self) -> usize {
+        let start = self.start().max(other.start());
+        let end = self.end().min(other.end());
+        if start < end {
+            (end - start).as_()
+        } else {
+            0
+        }
+    }
+
+    /// Returns `true` if this range and `other` touch without overlapping.
+    ///
+    /// Two ranges are adjacent if one ends exactly where the other starts.
+    /// Like [`overlaps`](Self::overlaps), empty ranges are never adjacent to
+    /// anything, since they have no values to border on.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let a = SmallRange::<u32>::new(0, 10);
+    /// let b = SmallRange::<u32>::new(10, 20);
+    /// let c = SmallRange::<u32>::new(5, 15);
+    ///
+    /// assert!(a.is_adjacent(&b));   // a ends where b starts
+    /// assert!(b.is_adjacent(&a));
+    /// assert!(!a.is_adjacent(&c));  // overlapping, not adjacent
+    /// ```
+    #[inline]
+    pub fn is_adjacent(&self, other: &Self) -> bool {
+        !self.is_empty()
+            && !other.is_empty()
+            && (self.end() == other.start() || other.end() == self.start())
+    }
+
+    /// Returns the union of this range and `other` if they overlap or touch.
+    ///
+    /// Two ranges can be merged when they share at least one value or when one
+    /// ends exactly where the other starts. An empty range contributes no values,
+    /// so merging with it yields the other range unchanged.
+    ///
+    /// Returns `None` if the ranges are disjoint with a gap between them, or if
+    /// the merged range would exceed the half-width capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let a = SmallRange::<u32>::new(0, 10);
+    /// let b = SmallRange::<u32>::new(5, 15);
+    /// let c = SmallRange::<u32>::new(15, 20);
+    /// let d = SmallRange::<u32>::new(30, 40);
+    ///
+    /// assert_eq!(a.union(&b), Some(SmallRange::new(0, 15)));  // overlapping
+    /// assert_eq!(b.union(&c), Some(SmallRange::new(5, 20))); // touching
+    /// assert_eq!(a.union(&d), None);                         // gap between
+    /// ```
+    #[inline]
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        if other.is_empty() {
+            return Some(*self);
+        }
+        if self.is_empty() {
+            return Some(*other);
+        }
+        let (self_start, self_end) = (self.start(), self.end());
+        let (other_start, other_end) = (other.start(), other.end());
+        if self_start > other_end || other_start > self_end {
+            return None;
+        }
+        Self::try_new(self_start.min(other_start), self_end.max(other_end))
+    }
+
+    /// Returns the smallest range covering both this range and `other`.
+    ///
+    /// Unlike [`union`](Self::union), the ranges don't need to overlap or
+    /// touch: any gap between them is included in the result. An empty range
+    /// contributes no values, so the hull with it is the other range.
+    ///
+    /// # Panics (debug only)
+    /// - If the covering range exceeds the half-width capacity
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let a = SmallRange::<u32>::new(0, 10);
+    /// let b = SmallRange::<u32>::new(30, 40);
+    ///
+    /// assert_eq!(a.hull(&b), SmallRange::new(0, 40));
+    /// ```
+    #[inline]
+    pub fn hull(&self, other: &Self) -> Self {
+        if other.is_empty() {
+            return *self;
+        }
+        if self.is_empty() {
+            return *other;
+        }
+        Self::new(self.start().min(other.start()), self.end().max(other.end()))
+    }
+
+    /// Returns the parts of this range not covered by `other`.
+    ///
+    /// The result is `(left, right)`, where `left` is the piece of `self` before
+    /// `other` and `right` is the piece after it. Each piece is `None` when it
+    /// would be empty. An empty `other` removes nothing, so a non-empty `self`
+    /// is returned whole as the left piece.
+    ///
+    /// # Errors
+    /// - [`SmallRangeError::StartTooLarge`] if the right piece would start
+    ///   beyond the half-width capacity, which can happen when `self` ends
+    ///   past [`MAX_START`](Self::MAX_START)
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{SmallRange, SmallRangeError};
+    ///
+    /// let a = SmallRange::<u32>::new(0, 20);
+    ///
+    /// // Hole in the middle: both pieces remain
+    /// let hole = SmallRange::<u32>::new(5, 10);
+    /// assert_eq!(
+    ///     a.difference(&hole),
+    ///     Ok((Some(SmallRange::new(0, 5)), Some(SmallRange::new(10, 20))))
+    /// );
+    ///
+    /// // Overlapping the end: only the left piece remains
+    /// let tail = SmallRange::<u32>::new(15, 30);
+    /// assert_eq!(a.difference(&tail), Ok((Some(SmallRange::new(0, 15)), None)));
+    ///
+    /// // Fully covered: nothing remains
+    /// let cover = SmallRange::<u32>::new(0, 50);
+    /// assert_eq!(a.difference(&cover), Ok((None, None)));
+    ///
+    /// // 300..400 can't be stored, since u16 starts max out at 254
+    /// let wide = SmallRange::<u16>::new(200, 400);
+    /// assert_eq!(
+    ///     wide.difference(&SmallRange::new(200, 300)),
+    ///     Err(SmallRangeError::StartTooLarge)
+    /// );
+    /// ```
+    #[inline]
+    pub fn difference(
+        &self,
+        other: &Self,
+    ) -> Result<(Option<Self>, Option<Self>), SmallRangeError> {
+        if self.is_empty() {
+            return Ok((None, None));
+        }
+        if other.is_empty() {
+            return Ok((Some(*self), None));
+        }
+        let (self_start, self_end) = (self.start(), self.end());
+        let (other_start, other_end) = (other.start(), other.end());
+
+        let left_end = self_end.min(other_start);
+        let left = Self::piece(self_start, left_end)?;
+
+        let right_start = self_start.max(other_end);
+        let right = Self::piece(right_start, self_end)?;
+
+        Ok((left, right))
+    }
+
+    /// Returns the parts covered by exactly one of this range and `other`.
+    ///
+    /// The result is `(left, right)` in ascending order: for overlapping ranges,
+    /// `left` is the piece before the intersection and `right` the piece after
+    /// it. For disjoint ranges the two (non-empty) inputs are returned as-is,
+    /// lower one first. Each piece is `None` when it would be empty.
+    ///
+    /// # Errors
+    /// - [`SmallRangeError::StartTooLarge`] if the right piece of overlapping
+    ///   ranges would start beyond the half-width capacity, which can happen
+    ///   when both ranges end past [`MAX_START`](Self::MAX_START)
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{SmallRange, SmallRangeError};
+    ///
+    /// let a = SmallRange::<u32>::new(0, 10);
+    /// let b = SmallRange::<u32>::new(5, 15);
+    /// assert_eq!(
+    ///     a.symmetric_difference(&b),
+    ///     Ok((Some(SmallRange::new(0, 5)), Some(SmallRange::new(10, 15))))
+    /// );
+    ///
+    /// // Shared start: only the right piece remains
+    /// let c = SmallRange::<u32>::new(0, 4);
+    /// assert_eq!(a.symmetric_difference(&c), Ok((None, Some(SmallRange::new(4, 10)))));
+    ///
+    /// // 255..400 can't be stored, since u16 starts max out at 254
+    /// let d = SmallRange::<u16>::new(1, 255);
+    /// assert_eq!(
+    ///     d.symmetric_difference(&SmallRange::new(200, 400)),
+    ///     Err(SmallRangeError::StartTooLarge)
+    /// );
+    /// ```
+    #[inline]
+    pub fn symmetric_difference(
+        &self,
+        other: &Self,
+    ) -> Result<(Option<Self>, Option<Self>), SmallRangeError> {
+        let (self_start, self_end) = (self.start(), self.end());
+        let (other_start, other_end) = (other.start(), other.end());
+
+        if !self.overlaps(other) {
+            let this = (!self.is_empty()).then_some(*self);
+            let that = (!other.is_empty()).then_some(*other);
+            return Ok(match (this, that) {
+                (Some(_), Some(_)) if other_start < self_start => (that, this),
+                (None, Some(_)) => (that, None),
+                _ => (this, that),
+            });
+        }
+
+        let left = Self::piece(self_start.min(other_start), self_start.max(other_start))?;
+        let right = Self::piece(self_end.min(other_end), self_end.max(other_end))?;
+
+        Ok((left, right))
+    }
+
+    /// Builds the piece `start..end` of a difference, or `None` if it's empty.
+    #[inline]
+    fn piece(start: T, end: T) -> Result<Option<Self>, SmallRangeError> {
+        if start < end {
+            Self::try_new_checked(start, end).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Returns the range strictly between this range and `other`.
+    ///
+    /// The gap runs from the end of the lower range to the start of the higher
+    /// one. Returns `None` if the ranges overlap or touch (there is no gap), or
+    /// if either range is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let a = SmallRange::<u32>::new(0, 10);
+    /// let b = SmallRange::<u32>::new(25, 30);
+    /// let c = SmallRange::<u32>::new(10, 20);
+    ///
+    /// assert_eq!(a.gap_between(&b), Some(SmallRange::new(10, 25)));
+    /// assert_eq!(b.gap_between(&a), Some(SmallRange::new(10, 25)));
+    /// assert_eq!(a.gap_between(&c), None); // touching
+    /// ```
+    #[inline]
+    pub fn gap_between(&self, other: &Self) -> Option<Self> {
+        if self.is_empty() || other.is_empty() {
+            return None;
+        }
+        let (self_start, self_end) = (self.start(), self.end());
+        let (other_start, other_end) = (other.start(), other.end());
+        if self_end < other_start {
+            Some(Self::new(self_end, other_start))
+        } else if other_end < self_start {
+            Some(Self::new(other_end, self_start))
+        } else {
+            None
+        }
+    }
+
+    /// Classifies how this range relates to `other` using Allen's interval algebra.
+    ///
+    /// Exactly one [`IntervalRelation`] holds for any pair of ranges, and
+    /// `b.relation(&a)` is always `a.relation(&b).inverse()`. Empty ranges are
+    /// treated as points: the first matching rule wins, checking `Equals`, then
+    /// `Before`/`After`, then `Meets`/`MetBy`, then shared endpoints, and
+    /// finally containment and overlap.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{IntervalRelation, SmallRange};
+    ///
+    /// let a = SmallRange::<u32>::new(0, 10);
+    ///
+    /// assert_eq!(a.relation(&SmallRange::new(20, 30)), IntervalRelation::Before);
+    /// assert_eq!(a.relation(&SmallRange::new(10, 30)), IntervalRelation::Meets);
+    /// assert_eq!(a.relation(&SmallRange::new(5, 30)), IntervalRelation::Overlaps);
+    /// assert_eq!(a.relation(&SmallRange::new(0, 30)), IntervalRelation::Starts);
+    /// assert_eq!(a.relation(&SmallRange::new(2, 8)), IntervalRelation::Contains);
+    /// assert_eq!(a.relation(&a), IntervalRelation::Equals);
+    /// ```
+    #[inline]
+    pub fn relation(&self, other: &Self) -> IntervalRelation {
+        let (a_start, a_end) = (self.start(), self.end());
+        let (b_start, b_end) = (other.start(), other.end());
+
+        if a_start == b_start && a_end == b_end {
+            IntervalRelation::Equals
+        } else if a_end < b_start {
+            IntervalRelation::Before
+        } else if b_end < a_start {
+            IntervalRelation::After
+        } else if a_end == b_start {
+            IntervalRelation::Meets
+        } else if b_end == a_start {
+            IntervalRelation::MetBy
+        } else if a_start == b_start {
+            if a_end < b_end {
+                IntervalRelation::Starts
+            } else {
+                IntervalRelation::StartedBy
+            }
+        } else if a_end == b_end {
+            if a_start > b_start {
+                IntervalRelation::Finishes
+            } else {
+                IntervalRelation::FinishedBy
+            }
+        } else if a_start > b_start && a_end < b_end {
+            IntervalRelation::During
+        } else if a_start < b_start && a_end > b_end {
+            IntervalRelation::Contains
+        } else if a_start < b_start {
+            IntervalRelation::Overlaps
+        } else {
+            IntervalRelation::OverlappedBy
+        }
+    }
+
+    /// Splits the range into `start..mid` and `mid..end`.
+    ///
+    /// `mid` may equal `start` or `end`, in which case one of the pieces is
+    /// empty. See [`checked_split_at`](Self::checked_split_at) for a
+    /// non-panicking version.
+    ///
+    /// # Panics
+    /// - If `mid` is outside `start..=end`
+    ///
+    /// # Panics (debug only)
+    /// - If `mid` exceeds the half-width capacity for a start value
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let range = SmallRange::<u32>::new(10, 20);
+    /// let (left, right) = range.split_at(15);
+    ///
+    /// assert_eq!(left, SmallRange::new(10, 15));
+    /// assert_eq!(right, SmallRange::new(15, 20));
+    /// ```
+    #[inline]
+    pub fn split_at(&self, mid: T) -> (Self, Self) {
+        let (start, end) = (self.start(), self.end());
+        assert!(start <= mid && mid <= end, "mid out of range");
+        (Self::new(start, mid), Self::new(mid, end))
+    }
+
+    /// Splits the range into `start..mid` and `mid..end`, or returns `None`.
+    ///
+    /// Returns `None` if `mid` is outside `start..=end`, or if the right piece
+    /// can't be represented because `mid` exceeds the half-width capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let range = SmallRange::<u32>::new(10, 20);
+    ///
+    /// assert_eq!(
+    ///     range.checked_split_at(12),
+    ///     Some((SmallRange::new(10, 12), SmallRange::new(12, 20)))
+    /// );
+    /// assert_eq!(range.checked_split_at(25), None);
+    /// ```
+    #[inline]
+    pub fn checked_split_at(&self, mid: T) -> Option<(Self, Self)> {
+        let (start, end) = (self.start(), self.end());
+        if mid < start || mid > end {
+            return None;
+        }
+        Some((Self::new(start, mid), Self::try_new(mid, end)?))
+    }
+
+    /// Splits off the first `n` values, returning `(taken, remainder)`.
+    ///
+    /// Returns `None` if the range has fewer than `n` values, or if the
+    /// remainder's start exceeds the half-width capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let free = SmallRange::<u32>::new(100, 164);
+    /// let (block, rest) = free.take_front(16).unwrap();
+    ///
+    /// assert_eq!(block, SmallRange::new(100, 116));
+    /// assert_eq!(rest, SmallRange::new(116, 164));
+    /// assert_eq!(free.take_front(65), None);
+    /// ```
+    #[inline]
+    pub fn take_front(&self, n: usize) -> Option<(Self, Self)> {
+        if n > self.len() {
+            return None;
+        }
+        self.checked_split_at(self.start() + n.as_())
+    }
+
+    /// Splits off the last `n` values, returning `(taken, remainder)`.
+    ///
+    /// Returns `None` if the range has fewer than `n` values, or if the taken
+    /// piece's start exceeds the half-width capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let free = SmallRange::<u32>::new(100, 164);
+    /// let (block, rest) = free.take_back(16).unwrap();
+    ///
+    /// assert_eq!(block, SmallRange::new(148, 164));
+    /// assert_eq!(rest, SmallRange::new(100, 148));
+    /// assert_eq!(free.take_back(65), None);
+    /// ```
+    #[inline]
+    pub fn take_back(&self, n: usize) -> Option<(Self, Self)> {
+        if n > self.len() {
+            return None;
+        }
+        let (rest, taken) = self.checked_split_at(self.end() - n.as_())?;
+        Some((taken, rest))
+    }
+
+    /// Splits the range into two halves of nearly equal length.
+    ///
+    /// The split point is `start + len / 2`, so for odd lengths the right half
+    /// gets the extra element. An empty range splits into two copies of itself.
+    ///
+    /// # Panics (debug only)
+    /// - If the right half's start exceeds the half-width capacity
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let (left, right) = SmallRange::<u32>::new(0, 10).bisect();
+    /// assert_eq!((left, right), (SmallRange::new(0, 5), SmallRange::new(5, 10)));
+    ///
+    /// // Odd length: the right half is longer
+    /// let (left, right) = SmallRange::<u32>::new(0, 5).bisect();
+    /// assert_eq!((left, right), (SmallRange::new(0, 2), SmallRange::new(2, 5)));
+    /// ```
+    #[inline]
+    pub fn bisect(&self) -> (Self, Self) {
+        let (start, length) = Self::decode_start_length(self.bits);
+        let mid = start + (length >> 1);
+        (Self::new(start, mid), Self::new(mid, start + length))
+    }
+
+    /// Returns an iterator over `parts` contiguous sub-ranges of nearly equal length.
+    ///
+    /// The sub-ranges cover the original range in order, and their lengths
+    /// differ by at most one: the first `len % parts` are one element longer.
+    /// When `parts` exceeds the length, the trailing sub-ranges are empty.
+    ///
+    /// # Panics
+    /// - If `parts` is zero
+    ///
+    /// # Panics (debug only)
+    /// - If a sub-range's start exceeds the half-width capacity
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let work = SmallRange::<u32>::new(0, 10);
+    /// let parts: Vec<_> = work.partition(3).collect();
+    ///
+    /// assert_eq!(
+    ///     parts,
+    ///     [SmallRange::new(0, 4), SmallRange::new(4, 7), SmallRange::new(7, 10)]
+    /// );
+    /// ```
+    #[inline]
+    pub fn partition(&self, parts: usize) -> Partition<T> {
+        Partition::new(*self, parts)
+    }
+
+    /// Returns an iterator over consecutive sub-ranges of at most `chunk_len` values.
+    ///
+    /// This is the range analogue of `slice::chunks`: every chunk has exactly
+    /// `chunk_len` values except possibly the last, which holds the remainder.
+    /// An empty range yields no chunks.
+    ///
+    /// # Panics
+    /// - If `chunk_len` is zero
+    ///
+    /// # Panics (debug only)
+    /// - If a chunk's start exceeds the half-width capacity
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let bytes = SmallRange::<u32>::new(0, 10);
+    /// let chunks: Vec<_> = bytes.chunks(4).collect();
+    ///
+    /// assert_eq!(
+    ///     chunks,
+    ///     [SmallRange::new(0, 4), SmallRange::new(4, 8), SmallRange::new(8, 10)]
+    /// );
+    /// ```
+    #[inline]
+    pub fn chunks(&self, chunk_len: T) -> Chunks<T> {
+        Chunks::new(*self, chunk_len)
+    }
+
+    /// Returns an iterator over all contiguous sub-ranges of exactly `size` values.
+    ///
+    /// This is the range analogue of `slice::windows`: the windows overlap and
+    /// start at consecutive values. If the range is shorter than `size`, the
+    /// iterator yields nothing.
+    ///
+    /// # Panics
+    /// - If `size` is zero
+    ///
+    /// # Panics (debug only)
+    /// - If a window's start exceeds the half-width capacity
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let range = SmallRange::<u32>::new(0, 4);
+    /// let windows: Vec<_> = range.windows(2).collect();
+    ///
+    /// assert_eq!(
+    ///     windows,
+    ///     [SmallRange::new(0, 2), SmallRange::new(1, 3), SmallRange::new(2, 4)]
+    /// );
+    /// ```
+    #[inline]
+    pub fn windows(&self, size: T) -> Windows<T> {
+        Windows::new(*self, size)
+    }
+
+    /// Returns an iterator over the values of the range, without consuming
+    /// it.
+    ///
+    /// Equivalent to `(&range).into_iter()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let range = SmallRange::<u64>::new(10, 14);
+    /// assert_eq!(range.iter().collect::<Vec<_>>(), [10, 11, 12, 13]);
+    /// assert_eq!(range.iter().len(), 4);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> SmallRangeIter<T> {
+        SmallRangeIter::new(*self)
+    }
+
+    /// Returns an iterator over the values of the range from the end,
+    /// without consuming it.
+    ///
+    /// Equivalent to `range.iter().rev()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let range = SmallRange::<u64>::new(10, 14);
+    /// assert_eq!(range.rev_iter().collect::<Vec<_>>(), [13, 12, 11, 10]);
+    /// ```
+    #[inline]
+    pub fn rev_iter(&self) -> Rev<SmallRangeIter<T>> {
+        self.iter().rev()
+    }
+
+    /// Returns an iterator over every `step`-th value, starting at `start`.
+    ///
+    /// Yields `start`, `start + step`, `start + 2 * step`, ... for as long as
+    /// the values stay below `end`. The iterator is double-ended and reports
+    /// its exact length for every storage type.
+    ///
+    /// # Panics
+    /// - If `step` is zero
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let range = SmallRange::<u64>::new(10, 20);
+    /// let samples: Vec<_> = range.iter_step(3).collect();
+    ///
+    /// assert_eq!(samples, [10, 13, 16, 19]);
+    /// assert_eq!(range.iter_step(3).len(), 4);
+    /// ```
+    #[inline]
+    pub fn iter_step(&self, step: T) -> StepBy<T> {
+        StepBy::new(*self, step)
+    }
+
+    /// Returns the range with its start rounded up to a multiple of `align`.
+    ///
+    /// The end is unchanged. Returns `None` if the rounded start would pass the
+    /// end, or if it exceeds the half-width capacity.
+    ///
+    /// # Panics
+    /// - If `align` is not a power of two
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let range = SmallRange::<u32>::new(100, 300);
+    /// assert_eq!(range.align_start_up(64), Some(SmallRange::new(128, 300)));
+    /// assert_eq!(range.align_start_up(512), None); // 512 is past the end
+    /// ```
+    #[inline]
+    pub fn align_start_up(&self, align: T) -> Option<Self> {
+        assert!(align.count_ones() == 1, "align must be a power of two");
+        let mask = align - T::one();
+        let start = self.start().checked_add(&mask)? & !mask;
+        let end = self.end();
+        if start > end {
+            return None;
+        }
+        Self::try_new(start, end)
+    }
+
+    /// Returns the range with its end rounded down to a multiple of `align`.
+    ///
+    /// The start is unchanged. Returns `None` if the rounded end would fall
+    /// before the start.
+    ///
+    /// # Panics
+    /// - If `align` is not a power of two
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let range = SmallRange::<u32>::new(100, 300);
+    /// assert_eq!(range.align_end_down(64), Some(SmallRange::new(100, 256)));
+    /// assert_eq!(range.align_end_down(512), None); // 0 is before the start
+    /// ```
+    #[inline]
+    pub fn align_end_down(&self, align: T) -> Option<Self> {
+        assert!(align.count_ones() == 1, "align must be a power of two");
+        let start = self.start();
+        let end = self.end() & !(align - T::one());
+        if end < start {
+            return None;
+        }
+        Some(Self::new(start, end))
+    }
+
+    /// Returns the largest sub-range whose start and end are multiples of `align`.
+    ///
+    /// The start is rounded up and the end rounded down to the nearest multiple
+    /// of `align`, which need not be a power of two. Returns `None` if the range
+    /// doesn't contain at least one complete aligned block, or if the aligned
+    /// start exceeds the half-width capacity.
+    ///
+    /// # Panics
+    /// - If `align` is zero
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let buffer = SmallRange::<u32>::new(100, 300);
+    /// assert_eq!(buffer.aligned_subrange(64), Some(SmallRange::new(128, 256)));
+    /// assert_eq!(buffer.aligned_subrange(48), Some(SmallRange::new(144, 288)));
+    ///
+    /// // No complete 256-byte block fits
+    /// assert_eq!(buffer.aligned_subrange(256), None);
+    /// ```
+    #[inline]
+    pub fn aligned_subrange(&self, align: T) -> Option<Self> {
+        assert!(!align.is_zero(), "align must be non-zero");
+        let (start, end) = (self.start(), self.end());
+        let start_rem = start % align;
+        let start = if start_rem.is_zero() {
+            start
+        } else {
+            start.checked_add(&(align - start_rem))?
+        };
+        let end = end - end % align;
+        if start >= end {
+            return None;
+        }
+        Self::try_new(start, end)
+    }
+
+    /// Returns an iterator over the pieces of this range split at page boundaries.
+    ///
+    /// Boundaries fall at every multiple of `page_size`. The first piece runs
+    /// from `start` to the first boundary (a partial head if `start` is
+    /// unaligned), followed by full pages, and finally a partial tail if `end`
+    /// is unaligned. Every piece lies within a single page.
+    ///
+    /// # Panics
+    /// - If `page_size` is zero
+    ///
+    /// # Panics (debug only)
+    /// - If a piece's start exceeds the half-width capacity
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let extent = SmallRange::<u32>::new(1000, 10000);
+    /// let pieces: Vec<_> = extent.pages(4096).collect();
+    ///
+    /// assert_eq!(
+    ///     pieces,
+    ///     [
+    ///         SmallRange::new(1000, 4096),  // partial head
+    ///         SmallRange::new(4096, 8192),  // full page
+    ///         SmallRange::new(8192, 10000), // partial tail
+    ///     ]
+    /// );
+    /// ```
+    #[inline]
+    pub fn pages(&self, page_size: T) -> Pages<T> {
+        Pages::new(*self, page_size)
+    }
+
+    /// Moves both endpoints up by `offset`, preserving the length.
+    ///
+    /// Returns `None` if the shifted range would overflow the storage type or
+    /// exceed the half-width capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let span = SmallRange::<u32>::new(10, 20);
+    /// assert_eq!(span.checked_translate(100), Some(SmallRange::new(110, 120)));
+    ///
+    /// // Start would exceed u16's max start of 254
+    /// assert_eq!(SmallRange::<u16>::new(200, 210).checked_translate(100), None);
+    /// ```
+    #[inline]
+    pub fn checked_translate(&self, offset: T) -> Option<Self> {
+        let (start, length) = Self::decode_start_length(self.bits);
+        let start = start.checked_add(&offset)?;
+        Self::try_new(start, start.checked_add(&length)?)
+    }
+
+    /// Moves both endpoints by a signed `delta`, preserving the length.
+    ///
+    /// A negative `delta` moves the range down. Returns `None` if the start
+    /// would become negative, or if the shifted range would overflow the
+    /// storage type or exceed the half-width capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let span = SmallRange::<u32>::new(10, 20);
+    /// assert_eq!(span.checked_translate_signed(5), Some(SmallRange::new(15, 25)));
+    /// assert_eq!(span.checked_translate_signed(-10), Some(SmallRange::new(0, 10)));
+    /// assert_eq!(span.checked_translate_signed(-11), None);
+    /// ```
+    #[inline]
+    pub fn checked_translate_signed(&self, delta: i64) -> Option<Self> {
+        let (start, length) = Self::decode_start_length(self.bits);
+        let start: T = NumCast::from(start.to_i64()?.checked_add(delta)?)?;
+        Self::try_new(start, start.checked_add(&length)?)
+    }
+
+    /// Moves the range so it begins at `new_start`, preserving its length.
+    ///
+    /// # Panics (debug only)
+    /// - If `new_start + len` overflows the storage type
+    /// - If `new_start` exceeds the half-width capacity
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let block = SmallRange::<u32>::new(100, 164);
+    /// assert_eq!(block.translate_to(512), SmallRange::new(512, 576));
+    /// ```
+    #[inline]
+    pub fn translate_to(&self, new_start: T) -> Self {
+        let (_, length) = Self::decode_start_length(self.bits);
+        Self::new(new_start, strict_add(new_start, length))
+    }
+
+    /// Moves the range so it begins at `new_start`, or returns `None`.
+    ///
+    /// Returns `None` if the moved range would overflow the storage type or
+    /// exceed the half-width capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let block = SmallRange::<u16>::new(10, 20);
+    /// assert_eq!(block.checked_translate_to(200), Some(SmallRange::new(200, 210)));
+    /// assert_eq!(block.checked_translate_to(255), None);
+    /// ```
+    #[inline]
+    pub fn checked_translate_to(&self, new_start: T) -> Option<Self> {
+        let (_, length) = Self::decode_start_length(self.bits);
+        Self::try_new(new_start, new_start.checked_add(&length)?)
+    }
+
+    /// Multiplies both the start and the length by `factor`.
+    ///
+    /// This converts between units, e.g. from an element range to the byte
+    /// range of those elements. Returns `None` if the scaled range would
+    /// overflow the storage type or exceed the half-width capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// // Elements 10..20 of a u32 array occupy bytes 40..80
+    /// let elements = SmallRange::<u32>::new(10, 20);
+    /// assert_eq!(elements.checked_scale(4), Some(SmallRange::new(40, 80)));
+    ///
+    /// assert_eq!(SmallRange::<u16>::new(100, 110).checked_scale(4), None);
+    /// ```
+    #[inline]
+    pub fn checked_scale(&self, factor: T) -> Option<Self> {
+        let (start, length) = Self::decode_start_length(self.bits);
+        let start = start.checked_mul(&factor)?;
+        let length = length.checked_mul(&factor)?;
+        Self::try_new(start, start.checked_add(&length)?)
+    }
+
+    /// Multiplies the length by `factor`, keeping the start in place.
+    ///
+    /// Returns `None` if the scaled range would overflow the storage type or
+    /// exceed the half-width capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let span = SmallRange::<u32>::new(10, 20);
+    /// assert_eq!(span.checked_mul_len(3), Some(SmallRange::new(10, 40)));
+    ///
+    /// assert_eq!(SmallRange::<u16>::new(0, 100).checked_mul_len(3), None);
+    /// ```
+    #[inline]
+    pub fn checked_mul_len(&self, factor: T) -> Option<Self> {
+        let (start, length) = Self::decode_start_length(self.bits);
+        let length = length.checked_mul(&factor)?;
+        Self::try_new(start, start.checked_add(&length)?)
+    }
+
+    /// Grows the range by `n` values on both sides.
+    ///
+    /// # Panics (debug only)
+    /// - If `start - n` underflows or `end + n` overflows
+    /// - If the grown range exceeds the half-width capacity
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let word = SmallRange::<u32>::new(40, 45);
+    /// assert_eq!(word.expand(10), SmallRange::new(30, 55));
+    /// ```
+    #[inline]
+    pub fn expand(&self, n: T) -> Self {
+        Self::new(strict_sub(self.start(), n), strict_add(self.end(), n))
+    }
+
+    /// Grows the range by `n` values on both sides, or returns `None`.
+    ///
+    /// Returns `None` if `start - n` would be negative, or if the grown range
+    /// would overflow the storage type or exceed the half-width capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let word = SmallRange::<u32>::new(40, 45);
+    /// assert_eq!(word.checked_expand(10), Some(SmallRange::new(30, 55)));
+    /// assert_eq!(word.checked_expand(41), None);
+    /// ```
+    #[inline]
+    pub fn checked_expand(&self, n: T) -> Option<Self> {
+        Self::try_new(self.start().checked_sub(&n)?, self.end().checked_add(&n)?)
+    }
+
+    /// Grows the range by up to `n` values on both sides.
+    ///
+    /// The start stops at zero and the length stops at the half-width
+    /// capacity, so this never fails. When the length limit is hit, the end
+    /// is clamped and the start keeps its full adjustment.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let word = SmallRange::<u32>::new(5, 10);
+    /// assert_eq!(word.saturating_expand(10), SmallRange::new(0, 20));
+    ///
+    /// // u16 lengths max out at 254
+    /// let wide = SmallRange::<u16>::new(10, 200);
+    /// assert_eq!(wide.saturating_expand(100), SmallRange::new(0, 254));
+    /// ```
+    #[inline]
+    pub fn saturating_expand(&self, n: T) -> Self {
+        let start = self.start().saturating_sub(n);
+        let max_end = start + T::MAX_HALF;
+        Self::new(start, self.end().saturating_add(n).min(max_end))
+    }
+
+    /// Shrinks the range by `n` values on both sides.
+    ///
+    /// # Panics (debug only)
+    /// - If the range has fewer than `2 * n` values
+    /// - If the shrunk range's start exceeds the half-width capacity
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let context = SmallRange::<u32>::new(30, 55);
+    /// assert_eq!(context.shrink(10), SmallRange::new(40, 45));
+    /// ```
+    #[inline]
+    pub fn shrink(&self, n: T) -> Self {
+        Self::new(strict_add(self.start(), n), strict_sub(self.end(), n))
+    }
+
+    /// Shrinks the range by `n` values on both sides, or returns `None`.
+    ///
+    /// Returns `None` if the range has fewer than `2 * n` values, or if the
+    /// shrunk range's start exceeds the half-width capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let context = SmallRange::<u32>::new(30, 55);
+    /// assert_eq!(context.checked_shrink(10), Some(SmallRange::new(40, 45)));
+    /// assert_eq!(context.checked_shrink(13), None);
+    /// ```
+    #[inline]
+    pub fn checked_shrink(&self, n: T) -> Option<Self> {
+        let (start, end) = (self.start(), self.end());
+        let start = start.checked_add(&n)?;
+        let end = end.checked_sub(&n)?;
+        if start > end {
+            return None;
+        }
+        Self::try_new(start, end)
+    }
+
+    /// Shrinks the range by up to `n` values on both sides.
+    ///
+    /// If the range has fewer than `2 * n` values, it collapses to an empty
+    /// range at its [`midpoint`](Self::midpoint) position (`start + len / 2`).
+    ///
+    /// # Panics (debug only)
+    /// - If the shrunk range's start exceeds the half-width capacity
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let context = SmallRange::<u32>::new(30, 55);
+    /// assert_eq!(context.saturating_shrink(10), SmallRange::new(40, 45));
+    /// assert_eq!(context.saturating_shrink(100), SmallRange::new(42, 42));
+    /// ```
+    #[inline]
+    pub fn saturating_shrink(&self, n: T) -> Self {
+        let (start, length) = Self::decode_start_length(self.bits);
+        let twice_n = n.saturating_add(n);
+        if twice_n >= length {
+            let mid = start + (length >> 1);
+            return Self::new(mid, mid);
+        }
+        Self::new(start + n, start + length - n)
+    }
+
+    /// Returns a copy of this range with a new start, keeping the end.
+    ///
+    /// Returns `None` if `start > end` or the result exceeds the half-width
+    /// capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let span = SmallRange::<u32>::new(10, 20);
+    /// assert_eq!(span.with_start(15), Some(SmallRange::new(15, 20)));
+    /// assert_eq!(span.with_start(25), None);
+    /// ```
+    #[inline]
+    pub fn with_start(&self, start: T) -> Option<Self> {
+        Self::try_new(start, self.end())
+    }
+
+    /// Returns a copy of this range with a new end, keeping the start.
+    ///
+    /// Returns `None` if `end < start` or the result exceeds the half-width
+    /// capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let span = SmallRange::<u32>::new(10, 20);
+    /// assert_eq!(span.with_end(30), Some(SmallRange::new(10, 30)));
+    /// assert_eq!(span.with_end(5), None);
+    /// ```
+    #[inline]
+    pub fn with_end(&self, end: T) -> Option<Self> {
+        Self::try_new(self.start(), end)
+    }
+
+    /// Returns a copy of this range with a new length, keeping the start.
+    ///
+    /// Returns `None` if the result would overflow the storage type or exceed
+    /// the half-width capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let span = SmallRange::<u32>::new(10, 20);
+    /// assert_eq!(span.with_len(5), Some(SmallRange::new(10, 15)));
+    /// assert_eq!(span.with_len(100_000), None);
+    /// ```
+    #[inline]
+    pub fn with_len(&self, len: usize) -> Option<Self> {
+        let start = self.start();
+        let len: T = NumCast::from(len)?;
+        Self::try_new(start, start.checked_add(&len)?)
+    }
+
+    /// Replaces the start in place, keeping the end.
+    ///
+    /// # Panics (debug only)
+    /// - If the new start exceeds the end
+    /// - If the new start or resulting length exceed the half-width capacity
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let mut span = SmallRange::<u32>::new(10, 20);
+    /// span.set_start(15);
+    /// assert_eq!(span, SmallRange::new(15, 20));
+    /// ```
+    #[inline]
+    pub fn set_start(&mut self, start: T) {
+        *self = Self::new(start, self.end());
+    }
+
+    /// Replaces the end in place, keeping the start.
+    ///
+    /// # Panics (debug only)
+    /// - If the new end is before the start
+    /// - If the resulting length exceeds the half-width capacity
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange;
+    ///
+    /// let mut span = SmallRange::<u32>::new(10, 20);
+    /// span.set_end(30);
+    /// assert_eq!(span, SmallRange::new(10, 30));
+    /// ```
+    #[inline]
+    pub fn set_end(&mut self, end: T) {
+        *self = Self::new(self.start(), end);
+    }
+
+    /// Replaces the start in place, keeping the end, or reports why it can't.
+    ///
+    /// On error the range is left unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{SmallRange, SmallRangeError};
+    ///
+    /// let mut span = SmallRange::<u32>::new(10, 20);
+    /// assert_eq!(span.try_set_start(25), Err(SmallRangeError::StartAfterEnd));
+    /// assert_eq!(span, SmallRange::new(10, 20));
+    ///
+    /// assert_eq!(span.try_set_start(15), Ok(()));
+    /// assert_eq!(span, SmallRange::new(15, 20));
+    /// ```
+    #[inline]
+    pub fn try_set_start(&mut self, start: T) -> Result<(), SmallRangeError> {
+        self.bits = Self::try_encode(start, self.end())?;
+        Ok(())
+    }
+
+    /// Replaces the end in place, keeping the start, or reports why it can't.
+    ///
+    /// On error the range is left unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{SmallRange, SmallRangeError};
+    ///
+    /// let mut span = SmallRange::<u16>::new(10, 20);
+    /// assert_eq!(span.try_set_end(300), Err(SmallRangeError::LengthTooLarge));
+    /// assert_eq!(span, SmallRange::new(10, 20));
+    ///
+    /// assert_eq!(span.try_set_end(30), Ok(()));
+    /// assert_eq!(span, SmallRange::new(10, 30));
+    /// ```
+    #[inline]
+    pub fn try_set_end(&mut self, end: T) -> Result<(), SmallRangeError> {
+        self.bits = Self::try_encode(self.start(), end)?;
+        Ok(())
+    }
+}
+
+impl<T: SmallRangeStorage> Default for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    fn default() -> Self {
+        Self::new(T::zero(), T::zero())
+    }
+}
+
+impl<T: SmallRangeStorage + fmt::Debug> fmt::Debug for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("SmallRange")
@@ -335,28 +2417,594 @@ where
     }
 }
 
+/// Formats a [`SmallRange`] with `Debug` as `start..end`, like `Range<T>`.
+///
+/// Created by [`SmallRange::compact_debug`].
+#[derive(Clone, Copy)]
+pub struct CompactDebug<T: SmallRangeStorage>(SmallRange<T>)
+where
+    usize: AsPrimitive<T>;
+
+impl<T: SmallRangeStorage + fmt::Debug> fmt::Debug for CompactDebug<T>
+where
+    usize: AsPrimitive<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.to_range().fmt(f)
+    }
+}
+
+/// Formats the range as `start..end`, like the std range syntax.
+///
+/// Formatting options apply to each bound, as with `Range`'s `Debug`.
+///
+/// # Examples
+/// ```
+/// use small_range::SmallRange;
+///
+/// let span = SmallRange::<u32>::new(10, 20);
+/// assert_eq!(span.to_string(), "10..20");
+/// assert_eq!(format!("{:03}", span), "010..020");
+/// ```
+impl<T: SmallRangeStorage + fmt::Display> fmt::Display for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.start().fmt(f)?;
+        f.write_str("..")?;
+        self.end().fmt(f)
+    }
+}
+
+/// Parses `start..end` or `start..=end`; see [`SmallRange::parse`].
+///
+/// # Examples
+/// ```
+/// use small_range::SmallRange;
+///
+/// let span: SmallRange<u32> = "10..20".parse().unwrap();
+/// assert_eq!(span, SmallRange::new(10, 20));
+/// ```
+impl<T: SmallRangeStorage> FromStr for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    type Err = ParseSmallRangeError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Orders ranges by start, then by end.
+///
+/// This is the same order as `(start, end)` tuples and as `Range<T>` sorted
+/// by its fields, so sorted vectors work with `binary_search`. Comparison is
+/// a single integer compare of [`sort_key`](SmallRange::sort_key) with no
+/// decoding.
+///
+/// # Examples
+/// ```
+/// use small_range::SmallRange;
+///
+/// let mut spans = vec![
+///     SmallRange::<u32>::new(10, 20),
+///     SmallRange::new(5, 30),
+///     SmallRange::new(10, 15),
+/// ];
+/// spans.sort();
+/// assert_eq!(spans, [SmallRange::new(5, 30), SmallRange::new(10, 15), SmallRange::new(10, 20)]);
+/// assert_eq!(spans.binary_search(&SmallRange::new(10, 15)), Ok(1));
+/// ```
+impl<T: SmallRangeStorage> Ord for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sort_key().cmp(&other.sort_key())
+    }
+}
+
+impl<T: SmallRangeStorage> PartialOrd for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compares against a standard `Range<T>` by start and end.
+///
+/// # Examples
+/// ```
+/// use small_range::SmallRange;
+///
+/// assert_eq!(SmallRange::<u32>::new(10, 20), 10..20);
+/// assert_eq!(10..20, SmallRange::<u32>::new(10, 20));
+/// assert_ne!(SmallRange::<u32>::new(5, 5), 6..6);
+/// ```
+impl<T: SmallRangeStorage> PartialEq<Range<T>> for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    fn eq(&self, other: &Range<T>) -> bool {
+        self.to_range() == *other
+    }
+}
+
+impl<T: SmallRangeStorage> PartialEq<SmallRange<T>> for Range<T>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    fn eq(&self, other: &SmallRange<T>) -> bool {
+        other == self
+    }
+}
+
+/// Iterates over the values of the range.
+///
+/// The iterator knows its exact length for every storage type, including
+/// `u64`, where `Range<u64>` doesn't implement `ExactSizeIterator`.
+///
+/// # Examples
+/// ```
+/// use small_range::SmallRange;
+///
+/// let range = SmallRange::<u64>::new(10, 15);
+/// let mut iter = range.into_iter();
+/// assert_eq!(iter.len(), 5);
+/// assert_eq!(iter.nth(2), Some(12));
+/// assert_eq!(iter.next_back(), Some(14));
+/// assert_eq!(iter.collect::<Vec<_>>(), [13]);
+/// ```
 impl<T: SmallRangeStorage> IntoIterator for SmallRange<T>
 where
     usize: AsPrimitive<T>,
-    Range<T>: Iterator<Item = T>,
 {
     type Item = T;
-    type IntoIter = Range<T>;
+    type IntoIter = SmallRangeIter<T>;
 
+    #[inline]
     fn into_iter(self) -> Self::IntoIter {
-        self.to_range()
+        SmallRangeIter::new(self)
     }
 }
 
 impl<T: SmallRangeStorage> IntoIterator for &SmallRange<T>
 where
     usize: AsPrimitive<T>,
-    Range<T>: Iterator<Item = T>,
 {
     type Item = T;
-    type IntoIter = Range<T>;
+    type IntoIter = SmallRangeIter<T>;
 
+    #[inline]
     fn into_iter(self) -> Self::IntoIter {
-        self.to_range()
+        SmallRangeIter::new(*self)
+    }
+}
+
+/// Collects values into the smallest range covering all of them.
+///
+/// The values don't need to be sorted. No values gives the empty range
+/// `0..0`. Use [`SmallRange::try_from_values`] when the values may not fit.
+///
+/// # Panics (debug only)
+/// - If the covering range exceeds the half-width capacity
+///
+/// # Examples
+/// ```
+/// use small_range::SmallRange;
+///
+/// let span: SmallRange<u32> = [7, 3, 12].into_iter().collect();
+/// assert_eq!(span, SmallRange::new(3, 13));
+/// ```
+impl<T: SmallRangeStorage> FromIterator<T> for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(values: I) -> Self {
+        match min_max(values) {
+            Some((min, max)) => Self::new(min, strict_add(max, T::one())),
+            None => Self::default(),
+        }
+    }
+}
+
+/// Collects ranges into their [`hull`](SmallRange::hull), the smallest
+/// range covering all of them.
+///
+/// Empty ranges are skipped, and no non-empty ranges gives the empty range
+/// `0..0`. Use [`SmallRange::try_hull_of`] when the result may not fit.
+///
+/// # Panics (debug only)
+/// - If the covering range exceeds the half-width capacity
+///
+/// # Examples
+/// ```
+/// use small_range::SmallRange;
+///
+/// let children = [SmallRange::<u32>::new(4, 7), SmallRange::new(8, 9), SmallRange::new(12, 20)];
+/// let parent: SmallRange<u32> = children.iter().collect();
+/// assert_eq!(parent, SmallRange::new(4, 20));
+/// ```
+impl<T: SmallRangeStorage> FromIterator<SmallRange<T>> for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    fn from_iter<I: IntoIterator<Item = SmallRange<T>>>(ranges: I) -> Self {
+        match hull_bounds(ranges) {
+            Some((start, end)) => Self::new(start, end),
+            None => Self::default(),
+        }
+    }
+}
+
+impl<'a, T: SmallRangeStorage> FromIterator<&'a SmallRange<T>> for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    fn from_iter<I: IntoIterator<Item = &'a SmallRange<T>>>(ranges: I) -> Self {
+        ranges.into_iter().copied().collect()
+    }
+}
+
+/// Returns the smallest and largest value, or `None` if there are none.
+fn min_max<T: Ord + Copy>(values: impl IntoIterator<Item = T>) -> Option<(T, T)> {
+    let mut values = values.into_iter();
+    let first = values.next()?;
+    Some(values.fold((first, first), |(min, max), value| {
+        (min.min(value), max.max(value))
+    }))
+}
+
+/// Returns the smallest start and largest end of the non-empty ranges, or
+/// `None` if there are none.
+fn hull_bounds<T: SmallRangeStorage>(
+    ranges: impl IntoIterator<Item = SmallRange<T>>,
+) -> Option<(T, T)>
+where
+    usize: AsPrimitive<T>,
+{
+    ranges
+        .into_iter()
+        .filter(|range| !range.is_empty())
+        .map(|range| range.to_parts())
+        .reduce(|(start, end), (other_start, other_end)| {
+            (start.min(other_start), end.max(other_end))
+        })
+}
+
+/// Destructures the range into `(start, end)`.
+///
+/// # Examples
+/// ```
+/// use small_range::SmallRange;
+///
+/// let (start, end) = SmallRange::<u32>::new(10, 20).into();
+/// assert_eq!((start, end), (10, 20));
+/// ```
+impl<T: SmallRangeStorage> From<SmallRange<T>> for (T, T)
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    fn from(range: SmallRange<T>) -> Self {
+        range.to_parts()
+    }
+}
+
+/// Converts `a..=b` into the equivalent half-open range `a..b + 1`.
+///
+/// An exhausted `RangeInclusive` converts to an empty range at `b + 1`,
+/// matching how slice indexing treats it.
+///
+/// # Errors
+/// Returns [`SmallRangeError`] if `a > b + 1`, or if `b + 1` overflows `T` or
+/// the result exceeds the half-width capacity.
+///
+/// # Examples
+/// ```
+/// use small_range::{SmallRange, SmallRangeError};
+///
+/// assert_eq!(SmallRange::<u32>::try_from(10..=19), Ok(SmallRange::new(10, 20)));
+///
+/// // 9 + 1 is empty but valid; 10..=u16::MAX can't be stored
+/// assert_eq!(SmallRange::<u32>::try_from(10..=9), Ok(SmallRange::new(10, 10)));
+/// assert_eq!(
+///     SmallRange::<u16>::try_from(10..=u16::MAX),
+///     Err(SmallRangeError::LengthTooLarge)
+/// );
+/// ```
+impl<T: SmallRangeStorage> TryFrom<RangeInclusive<T>> for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    type Error = SmallRangeError;
+
+    fn try_from(range: RangeInclusive<T>) -> Result<Self, Self::Error> {
+        let exhausted = range.is_empty() && range.start() <= range.end();
+        let (start, end) = range.into_inner();
+        let Some(end) = end.checked_add(&T::one()) else {
+            // b + 1 overflowed, so b is far beyond any representable end
+            return Err(if start >= T::LOW_MASK {
+                SmallRangeError::StartTooLarge
+            } else {
+                SmallRangeError::LengthTooLarge
+            });
+        };
+        let start = if exhausted { end } else { start };
+        Self::try_new_checked(start, end)
+    }
+}
+
+/// Shifts the range up by `offset`, preserving its length.
+///
+/// Follows the same policy as [`SmallRange::new`]: overflow and capacity
+/// violations panic in debug builds. Use
+/// [`checked_translate`](SmallRange::checked_translate) for a fallible version.
+///
+/// # Examples
+/// ```
+/// use small_range::SmallRange;
+///
+/// let span = SmallRange::<u32>::new(10, 20);
+/// assert_eq!(span + 16, SmallRange::new(26, 36));
+/// ```
+impl<T: SmallRangeStorage> Add<T> for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(self, offset: T) -> Self {
+        let (start, length) = Self::decode_start_length(self.bits);
+        Self::from_start_len(strict_add(start, offset), length)
+    }
+}
+
+impl<T: SmallRangeStorage> AddAssign<T> for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    fn add_assign(&mut self, offset: T) {
+        *self = *self + offset;
+    }
+}
+
+/// Shifts the range down by `offset`, preserving its length.
+///
+/// Follows the same policy as [`SmallRange::new`]: underflow panics in debug
+/// builds. Use [`checked_translate_signed`](SmallRange::checked_translate_signed)
+/// for a fallible version.
+///
+/// # Examples
+/// ```
+/// use small_range::SmallRange;
+///
+/// let span = SmallRange::<u32>::new(26, 36);
+/// assert_eq!(span - 16, SmallRange::new(10, 20));
+/// ```
+impl<T: SmallRangeStorage> Sub<T> for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, offset: T) -> Self {
+        let (start, length) = Self::decode_start_length(self.bits);
+        Self::from_start_len(strict_sub(start, offset), length)
+    }
+}
+
+impl<T: SmallRangeStorage> SubAssign<T> for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    fn sub_assign(&mut self, offset: T) {
+        *self = *self - offset;
     }
 }
+
+/// Implements lossless `From` conversions into wider storage types.
+///
+/// Every start and length that fits the source half-width also fits the
+/// target half-width, so re-encoding can't fail.
+macro_rules! impl_widen {
+    ($($from:ty => $to:ty),* $(,)?) => {
+        $(
+            impl From<SmallRange<$from>> for SmallRange<$to> {
+                #[inline]
+                fn from(range: SmallRange<$from>) -> Self {
+                    Self::new(range.start() as $to, range.end() as $to)
+                }
+            }
+        )*
+    };
+}
+
+// usize is at least 16 bits, so its half-width holds any u8 half. It is at
+// most 64 bits, so its half-width fits in a u64 half.
+impl_widen! {
+    u8 => u16,
+    u8 => u32,
+    u8 => u64,
+    u8 => usize,
+    u16 => u32,
+    u16 => u64,
+    u32 => u64,
+    usize => u64,
+}
+
+// Only a 32- or 64-bit usize has room for every u16 and u32 half. On 16-bit
+// targets such as AVR these conversions are fallible instead (see below).
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_widen! {
+    u16 => usize,
+    u32 => usize,
+}
+
+/// Implements fallible `TryFrom` conversions into narrower storage types.
+///
+/// The start and length are checked against the target half-width, reporting
+/// the first one that doesn't fit.
+macro_rules! impl_narrow {
+    ($($from:ty => $to:ty),* $(,)?) => {
+        $(
+            impl TryFrom<SmallRange<$from>> for SmallRange<$to> {
+                type Error = SmallRangeError;
+
+                #[inline]
+                fn try_from(range: SmallRange<$from>) -> Result<Self, Self::Error> {
+                    let start = <$to>::try_from(range.start())
+                        .map_err(|_| SmallRangeError::StartTooLarge)?;
+                    let length = <$to>::try_from(range.len())
+                        .map_err(|_| SmallRangeError::LengthTooLarge)?;
+                    Self::try_encode_start_length(start, length).map(|bits| Self { bits })
+                }
+            }
+        )*
+    };
+}
+
+// usize => u32 and u64 => usize are lossless on some targets but not others,
+// so they are fallible everywhere.
+impl_narrow! {
+    u16 => u8,
+    u32 => u8,
+    u64 => u8,
+    usize => u8,
+    u32 => u16,
+    u64 => u16,
+    u64 => u32,
+    u64 => usize,
+    usize => u16,
+    usize => u32,
+}
+
+#[cfg(not(any(target_pointer_width = "32", target_pointer_width = "64")))]
+impl_narrow! {
+    u16 => usize,
+    u32 => usize,
+}
+
+/// Implements fixed-endian byte conversions for a concrete storage type.
+macro_rules! impl_bytes {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl SmallRange<$t> {
+                /// Returns the packed representation as little-endian bytes.
+                ///
+                /// The bytes follow the stable layout described in
+                /// [`to_bits`](Self::to_bits).
+                #[inline]
+                pub fn to_le_bytes(&self) -> [u8; core::mem::size_of::<$t>()] {
+                    self.to_bits().get().to_le_bytes()
+                }
+
+                /// Returns the packed representation as big-endian bytes.
+                #[inline]
+                pub fn to_be_bytes(&self) -> [u8; core::mem::size_of::<$t>()] {
+                    self.to_bits().get().to_be_bytes()
+                }
+
+                /// Decodes little-endian bytes produced by
+                /// [`to_le_bytes`](Self::to_le_bytes).
+                ///
+                /// Returns `None` if the bytes don't encode a valid range.
+                #[inline]
+                pub fn from_le_bytes(bytes: [u8; core::mem::size_of::<$t>()]) -> Option<Self> {
+                    Self::from_bits(<$t>::from_le_bytes(bytes))
+                }
+
+                /// Decodes big-endian bytes produced by
+                /// [`to_be_bytes`](Self::to_be_bytes).
+                ///
+                /// Returns `None` if the bytes don't encode a valid range.
+                #[inline]
+                pub fn from_be_bytes(bytes: [u8; core::mem::size_of::<$t>()]) -> Option<Self> {
+                    Self::from_bits(<$t>::from_be_bytes(bytes))
+                }
+            }
+        )*
+    };
+}
+
+// Implemented per storage type so the arrays have a concrete length.
+impl_bytes!(u8, u16, u32, u64, usize);
+
+/// Implements `const fn` counterparts of the core constructors and accessors
+/// for a concrete storage type.
+///
+/// The generic methods go through `SmallRangeStorage`, whose trait methods
+/// can't be called in const context, so these work on the concrete integer
+/// directly.
+macro_rules! impl_const {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl SmallRange<$t> {
+                /// `const` version of [`new`](Self::new), for building
+                /// static tables of ranges.
+                ///
+                /// # Panics (debug only)
+                /// - If start exceeds end
+                /// - If start or length exceed the half-width capacity
+                ///
+                /// When evaluated in a `const` item these panics become
+                /// compile errors.
+                ///
+                /// # Examples
+                /// ```
+                /// use small_range::SmallRange32;
+                ///
+                /// static SPANS: [SmallRange32; 2] =
+                ///     [SmallRange32::new_const(0, 4), SmallRange32::new_const(4, 10)];
+                /// const LAST_END: u32 = SPANS[1].end_const();
+                /// assert_eq!(LAST_END, 10);
+                /// ```
+                #[inline]
+                pub const fn new_const(start: $t, end: $t) -> Self {
+                    strict_assert!(start <= end, "start must not exceed end");
+                    let length = end - start;
+                    strict_assert!(start < <$t>::LOW_MASK, "start+1 exceeds half-width capacity");
+                    strict_assert!(length < <$t>::LOW_MASK, "length+1 exceeds half-width capacity");
+                    let packed = ((start + 1) << <$t>::HALF_BITS) | (length + 1);
+                    match NonZero::new(packed) {
+                        Some(bits) => Self { bits },
+                        None => panic!("packed value must be non-zero"),
+                    }
+                }
+
+                /// `const` version of [`start`](Self::start).
+                #[inline]
+                pub const fn start_const(&self) -> $t {
+                    (self.bits.get() >> <$t>::HALF_BITS) - 1
+                }
+
+                /// `const` version of [`end`](Self::end).
+                #[inline]
+                pub const fn end_const(&self) -> $t {
+                    self.start_const() + self.len_const() as $t
+                }
+
+                /// `const` version of [`len`](Self::len).
+                #[inline]
+                pub const fn len_const(&self) -> usize {
+                    ((self.bits.get() & <$t>::LOW_MASK) - 1) as usize
+                }
+            }
+        )*
+    };
+}
+
+impl_const!(u8, u16, u32, u64, usize);
@@ -0,0 +1,213 @@
+use core::fmt;
+use core::num::NonZero;
+use core::ops::Range;
+
+use crate::SmallRange;
+
+/// A 3-byte range with 12 bits each for the start and the length.
+///
+/// For tables so dense that even `SmallRange<u32>`'s 4 bytes per entry is too
+/// much. The value is stored as three bytes with alignment 1, so arrays of
+/// `SmallRange24` have no padding.
+///
+/// # Encoding
+/// The 24-bit value is `(start + 16) << 12 | len`, stored little-endian. The
+/// bias of 16 keeps the high byte non-zero, which gives the type a niche:
+/// `Option<SmallRange24>` is also 3 bytes. The cost is 16 values of start
+/// capacity compared to a plain 12-bit field.
+///
+/// # Constraints
+/// - Start must not exceed end
+/// - Start must be at most [`MAX_START`](Self::MAX_START) (4,079)
+/// - Length must be at most [`MAX_LEN`](Self::MAX_LEN) (4,095)
+///
+/// # Examples
+/// ```
+/// use small_range::SmallRange24;
+/// use core::mem::size_of;
+///
+/// let r = SmallRange24::new(100, 200);
+/// assert_eq!(r.start(), 100);
+/// assert_eq!(r.len(), 100);
+/// assert_eq!(size_of::<SmallRange24>(), 3);
+/// assert_eq!(size_of::<Option<SmallRange24>>(), 3);
+/// ```
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SmallRange24 {
+    low: [u8; 2],
+    high: NonZero<u8>,
+}
+
+impl SmallRange24 {
+    /// Largest start value.
+    pub const MAX_START: u16 = 0xFFF - Self::START_BIAS;
+
+    /// Largest length.
+    pub const MAX_LEN: u16 = 0xFFF;
+
+    /// Added to the start so the high byte is never zero.
+    const START_BIAS: u16 = 16;
+
+    #[inline]
+    const fn encode(start: u16, length: u16) -> Self {
+        let packed = ((start + Self::START_BIAS) as u32) << 12 | length as u32;
+        let [b0, b1, b2, _] = packed.to_le_bytes();
+        match NonZero::new(b2) {
+            Some(high) => Self {
+                low: [b0, b1],
+                high,
+            },
+            None => panic!("start+16 keeps the high byte non-zero"),
+        }
+    }
+
+    #[inline]
+    const fn packed(&self) -> u32 {
+        u32::from_le_bytes([self.low[0], self.low[1], self.high.get(), 0])
+    }
+
+    /// Creates a new `SmallRange24` with the given start and end values.
+    ///
+    /// # Panics (debug only)
+    /// - If start exceeds end
+    /// - If start or length exceed their 12-bit capacity
+    #[inline]
+    pub const fn new(start: u16, end: u16) -> Self {
+        strict_assert!(start <= end, "start must not exceed end");
+        Self::from_start_len(start, end - start)
+    }
+
+    /// Creates a new `SmallRange24` from a start value and a length.
+    ///
+    /// # Panics (debug only)
+    /// - If start or length exceed their 12-bit capacity
+    #[inline]
+    pub const fn from_start_len(start: u16, len: u16) -> Self {
+        strict_assert!(start <= Self::MAX_START, "start exceeds 12-bit capacity");
+        strict_assert!(len <= Self::MAX_LEN, "length exceeds 12-bit capacity");
+        Self::encode(start, len)
+    }
+
+    /// Creates a new `SmallRange24` if the values are valid, returns `None`
+    /// otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange24;
+    ///
+    /// assert!(SmallRange24::try_new(4079, 8174).is_some());
+    /// assert!(SmallRange24::try_new(4080, 4080).is_none());
+    /// assert!(SmallRange24::try_new(0, 4096).is_none());
+    /// assert!(SmallRange24::try_new(20, 10).is_none());
+    /// ```
+    #[inline]
+    pub const fn try_new(start: u16, end: u16) -> Option<Self> {
+        if start > end {
+            return None;
+        }
+        Self::try_from_start_len(start, end - start)
+    }
+
+    /// Creates a new `SmallRange24` from a start and length, or returns
+    /// `None` if either exceeds its capacity.
+    #[inline]
+    pub const fn try_from_start_len(start: u16, len: u16) -> Option<Self> {
+        if start > Self::MAX_START || len > Self::MAX_LEN {
+            return None;
+        }
+        Some(Self::encode(start, len))
+    }
+
+    /// Returns the start of the range.
+    #[inline]
+    pub const fn start(&self) -> u16 {
+        (self.packed() >> 12) as u16 - Self::START_BIAS
+    }
+
+    /// Returns the end of the range (exclusive).
+    #[inline]
+    pub const fn end(&self) -> u16 {
+        self.start() + self.len() as u16
+    }
+
+    /// Returns the length of the range.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        (self.packed() & 0xFFF) as usize
+    }
+
+    /// Returns `true` if the range is empty.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the range contains the given value.
+    #[inline]
+    pub const fn contains(&self, value: u16) -> bool {
+        value >= self.start() && value < self.end()
+    }
+
+    /// Converts the `SmallRange24` to a standard `Range<u16>`.
+    #[inline]
+    pub const fn to_range(&self) -> Range<u16> {
+        self.start()..self.end()
+    }
+
+    /// Returns the three stored bytes.
+    ///
+    /// The layout is stable: the little-endian bytes of
+    /// `(start + 16) << 12 | len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRange24;
+    ///
+    /// let r = SmallRange24::new(0, 1);
+    /// assert_eq!(r.to_bytes(), [0x01, 0x00, 0x01]);
+    /// assert_eq!(SmallRange24::from_bytes(r.to_bytes()), Some(r));
+    /// ```
+    #[inline]
+    pub const fn to_bytes(&self) -> [u8; 3] {
+        [self.low[0], self.low[1], self.high.get()]
+    }
+
+    /// Decodes bytes produced by [`to_bytes`](Self::to_bytes).
+    ///
+    /// Returns `None` if the bytes don't encode a valid range.
+    #[inline]
+    pub const fn from_bytes(bytes: [u8; 3]) -> Option<Self> {
+        // A high byte of zero would mean a start field below the bias
+        match NonZero::new(bytes[2]) {
+            Some(high) => Some(Self {
+                low: [bytes[0], bytes[1]],
+                high,
+            }),
+            None => None,
+        }
+    }
+}
+
+impl Default for SmallRange24 {
+    fn default() -> Self {
+        Self::new(0, 0)
+    }
+}
+
+impl fmt::Debug for SmallRange24 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SmallRange24")
+            .field("start", &self.start())
+            .field("end", &self.end())
+            .finish()
+    }
+}
+
+/// Widens to `SmallRange<u32>`, which holds any `SmallRange24`.
+impl From<SmallRange24> for SmallRange<u32> {
+    #[inline]
+    fn from(range: SmallRange24) -> Self {
+        Self::new(range.start().into(), range.end().into())
+    }
+}
@@ -0,0 +1,221 @@
+use core::fmt;
+use core::ops::RangeInclusive;
+
+use num_traits::AsPrimitive;
+
+use crate::{SmallRange, SmallRangeError, SmallRangeStorage};
+
+/// A compact inclusive range (`start..=end`) with the same niche optimization
+/// as [`SmallRange`].
+///
+/// The start and `end - start` are packed like a `SmallRange`'s start and
+/// length, so the inclusive end can reach `MAX_START + MAX_LEN` and the range
+/// can hold one more value than a `SmallRange` of the same storage. An
+/// inclusive range is never empty, and conversions to and from
+/// `RangeInclusive<T>` are lossless.
+///
+/// # Constraints
+/// - Start must not exceed end
+/// - Start and `end - start` must each fit in half the storage width minus 1
+///
+/// # Examples
+/// ```
+/// use small_range::{SmallRange, SmallRangeInclusive};
+///
+/// // The last representable u16 value as an endpoint
+/// let r = SmallRangeInclusive::<u16>::new(254, 508);
+/// assert_eq!(r.end(), 508);
+/// assert_eq!(r.len(), 255);
+///
+/// // The half-open equivalent would need an end of 509
+/// assert!(SmallRange::<u16>::try_new(254, 509).is_none());
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SmallRangeInclusive<T: SmallRangeStorage = u64>
+where
+    usize: AsPrimitive<T>,
+{
+    // start..end packed as a SmallRange, with `end` read as inclusive
+    inner: SmallRange<T>,
+}
+
+impl<T: SmallRangeStorage> SmallRangeInclusive<T>
+where
+    usize: AsPrimitive<T>,
+{
+    /// Creates a new `SmallRangeInclusive` covering `start..=end`.
+    ///
+    /// # Panics (debug only)
+    /// - If start exceeds end
+    /// - If start or `end - start` exceed the half-width capacity
+    #[inline]
+    pub fn new(start: T, end: T) -> Self {
+        Self {
+            inner: SmallRange::new(start, end),
+        }
+    }
+
+    /// Creates a new `SmallRangeInclusive` if the values are valid, returns
+    /// `None` otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRangeInclusive;
+    ///
+    /// assert!(SmallRangeInclusive::<u16>::try_new(0, 254).is_some());
+    /// assert!(SmallRangeInclusive::<u16>::try_new(0, 255).is_none());
+    /// assert!(SmallRangeInclusive::<u16>::try_new(10, 9).is_none());
+    /// ```
+    #[inline]
+    pub fn try_new(start: T, end: T) -> Option<Self> {
+        SmallRange::try_new(start, end).map(|inner| Self { inner })
+    }
+
+    /// Returns the start of the range.
+    #[inline]
+    pub fn start(&self) -> T {
+        self.inner.start()
+    }
+
+    /// Returns the end of the range (inclusive).
+    #[inline]
+    pub fn end(&self) -> T {
+        self.inner.end()
+    }
+
+    /// Returns the number of values in the range, which is always at least 1.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len() + 1
+    }
+
+    /// Always returns `false`: an inclusive range holds at least its start.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns `true` if `start <= value <= end`.
+    #[inline]
+    pub fn contains(&self, value: T) -> bool {
+        let (start, end) = self.inner.to_parts();
+        value >= start && value <= end
+    }
+
+    /// Converts to a standard `RangeInclusive<T>`.
+    #[inline]
+    pub fn to_range_inclusive(&self) -> RangeInclusive<T> {
+        let (start, end) = self.inner.to_parts();
+        start..=end
+    }
+
+    /// Converts to the equivalent half-open `SmallRange`, if `end + 1` is
+    /// representable.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{SmallRange, SmallRangeInclusive};
+    ///
+    /// let r = SmallRangeInclusive::<u16>::new(10, 19);
+    /// assert_eq!(r.to_small_range(), Some(SmallRange::new(10, 20)));
+    ///
+    /// let full = SmallRangeInclusive::<u16>::new(0, 254);
+    /// assert_eq!(full.to_small_range(), None);
+    /// ```
+    #[inline]
+    pub fn to_small_range(&self) -> Option<SmallRange<T>> {
+        let (start, length) = self.inner.to_start_len();
+        SmallRange::try_from_start_len(start, length + T::one())
+    }
+}
+
+impl<T: SmallRangeStorage + fmt::Debug> fmt::Debug for SmallRangeInclusive<T>
+where
+    usize: AsPrimitive<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SmallRangeInclusive")
+            .field("start", &self.start())
+            .field("end", &self.end())
+            .finish()
+    }
+}
+
+/// Formats the range as `start..=end`, like the std range syntax.
+impl<T: SmallRangeStorage + fmt::Display> fmt::Display for SmallRangeInclusive<T>
+where
+    usize: AsPrimitive<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.start().fmt(f)?;
+        f.write_str("..=")?;
+        self.end().fmt(f)
+    }
+}
+
+/// Converts `a..=b` losslessly.
+///
+/// # Errors
+/// Returns [`SmallRangeError::StartAfterEnd`] for an empty range, including
+/// an exhausted one, and the capacity errors if `a` or `b - a` don't fit.
+///
+/// # Examples
+/// ```
+/// use small_range::{SmallRangeError, SmallRangeInclusive};
+///
+/// let r = SmallRangeInclusive::<u16>::try_from(0..=254).unwrap();
+/// assert_eq!(r.to_range_inclusive(), 0..=254);
+///
+/// assert_eq!(
+///     SmallRangeInclusive::<u16>::try_from(0..=255),
+///     Err(SmallRangeError::LengthTooLarge)
+/// );
+/// ```
+impl<T: SmallRangeStorage> TryFrom<RangeInclusive<T>> for SmallRangeInclusive<T>
+where
+    usize: AsPrimitive<T>,
+{
+    type Error = SmallRangeError;
+
+    fn try_from(range: RangeInclusive<T>) -> Result<Self, Self::Error> {
+        if range.is_empty() {
+            return Err(SmallRangeError::StartAfterEnd);
+        }
+        let (start, end) = range.into_inner();
+        SmallRange::try_new_checked(start, end).map(|inner| Self { inner })
+    }
+}
+
+impl<T: SmallRangeStorage> From<SmallRangeInclusive<T>> for RangeInclusive<T>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    fn from(range: SmallRangeInclusive<T>) -> Self {
+        range.to_range_inclusive()
+    }
+}
+
+/// Converts a non-empty `SmallRange` to `start..=end - 1`.
+///
+/// # Errors
+/// Returns [`SmallRangeError::StartAfterEnd`] if the range is empty, since
+/// `start..=start - 1` has its start after its end.
+impl<T: SmallRangeStorage> TryFrom<SmallRange<T>> for SmallRangeInclusive<T>
+where
+    usize: AsPrimitive<T>,
+{
+    type Error = SmallRangeError;
+
+    #[inline]
+    fn try_from(range: SmallRange<T>) -> Result<Self, Self::Error> {
+        let (start, length) = range.to_start_len();
+        if length.is_zero() {
+            return Err(SmallRangeError::StartAfterEnd);
+        }
+        Ok(Self {
+            inner: SmallRange::from_start_len(start, length - T::one()),
+        })
+    }
+}
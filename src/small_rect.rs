@@ -0,0 +1,179 @@
+use core::fmt;
+
+use crate::SmallRange;
+
+/// A compact 2D rectangle made of two `SmallRange<u32>`s.
+///
+/// The x-range and y-range are half-open, so a rectangle covers the points
+/// `(x, y)` with `x` in `x_range()` and `y` in `y_range()`. Each axis has the
+/// limits of `SmallRange<u32>`: origins and sizes up to 65,534. The whole
+/// rectangle is 8 bytes and keeps the niche, so `Option<SmallRect>` is also
+/// 8 bytes. Suited to tile and texture-atlas managers.
+///
+/// # Examples
+/// ```
+/// use small_range::SmallRect;
+///
+/// let tile = SmallRect::from_origin_size(32, 64, 16, 16);
+/// assert!(tile.contains(40, 70));
+/// assert_eq!(tile.area(), 256);
+/// assert_eq!(core::mem::size_of::<Option<SmallRect>>(), 8);
+/// ```
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SmallRect {
+    x: SmallRange<u32>,
+    y: SmallRange<u32>,
+}
+
+impl SmallRect {
+    /// Creates a new `SmallRect` from its x-range and y-range.
+    #[inline]
+    pub fn new(x: SmallRange<u32>, y: SmallRange<u32>) -> Self {
+        Self { x, y }
+    }
+
+    /// Creates a new `SmallRect` with the given top-left corner and size.
+    ///
+    /// # Panics (debug only)
+    /// - If an origin or size exceeds the half-width capacity
+    #[inline]
+    pub fn from_origin_size(x: u32, y: u32, width: u32, height: u32) -> Self {
+        Self {
+            x: SmallRange::from_start_len(x, width),
+            y: SmallRange::from_start_len(y, height),
+        }
+    }
+
+    /// Creates a new `SmallRect` with the given top-left corner and size, or
+    /// returns `None` if any value exceeds the half-width capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRect;
+    ///
+    /// assert!(SmallRect::try_from_origin_size(0, 0, 65_534, 65_534).is_some());
+    /// assert!(SmallRect::try_from_origin_size(0, 65_535, 1, 1).is_none());
+    /// ```
+    #[inline]
+    pub fn try_from_origin_size(x: u32, y: u32, width: u32, height: u32) -> Option<Self> {
+        Some(Self {
+            x: SmallRange::try_from_start_len(x, width)?,
+            y: SmallRange::try_from_start_len(y, height)?,
+        })
+    }
+
+    /// Returns the horizontal extent.
+    #[inline]
+    pub fn x_range(&self) -> SmallRange<u32> {
+        self.x
+    }
+
+    /// Returns the vertical extent.
+    #[inline]
+    pub fn y_range(&self) -> SmallRange<u32> {
+        self.y
+    }
+
+    /// Returns the left edge.
+    #[inline]
+    pub fn x(&self) -> u32 {
+        self.x.start()
+    }
+
+    /// Returns the top edge.
+    #[inline]
+    pub fn y(&self) -> u32 {
+        self.y.start()
+    }
+
+    /// Returns the width.
+    #[inline]
+    pub fn width(&self) -> u32 {
+        self.x.len() as u32
+    }
+
+    /// Returns the height.
+    #[inline]
+    pub fn height(&self) -> u32 {
+        self.y.len() as u32
+    }
+
+    /// Returns the number of points covered, `width * height`.
+    #[inline]
+    pub fn area(&self) -> u64 {
+        u64::from(self.width()) * u64::from(self.height())
+    }
+
+    /// Returns `true` if the rectangle covers no points.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.x.is_empty() || self.y.is_empty()
+    }
+
+    /// Returns `true` if the rectangle contains the point `(x, y)`.
+    #[inline]
+    pub fn contains(&self, x: u32, y: u32) -> bool {
+        self.x.contains(x) && self.y.contains(y)
+    }
+
+    /// Returns `true` if every point of `other` is inside this rectangle.
+    ///
+    /// Empty rectangles are contained everywhere.
+    #[inline]
+    pub fn contains_rect(&self, other: &Self) -> bool {
+        other.is_empty() || (self.x.contains_range(&other.x) && self.y.contains_range(&other.y))
+    }
+
+    /// Returns `true` if the two rectangles share at least one point.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRect;
+    ///
+    /// let a = SmallRect::from_origin_size(0, 0, 10, 10);
+    /// assert!(a.intersects(&SmallRect::from_origin_size(9, 9, 10, 10)));
+    ///
+    /// // Sharing an edge is not an intersection
+    /// assert!(!a.intersects(&SmallRect::from_origin_size(10, 0, 10, 10)));
+    /// ```
+    #[inline]
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.x.overlaps(&other.x) && self.y.overlaps(&other.y)
+    }
+
+    /// Returns the rectangle covered by both, or `None` if they don't
+    /// intersect.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::SmallRect;
+    ///
+    /// let a = SmallRect::from_origin_size(0, 0, 10, 10);
+    /// let b = SmallRect::from_origin_size(5, 8, 10, 10);
+    /// assert_eq!(a.intersection(&b), Some(SmallRect::from_origin_size(5, 8, 5, 2)));
+    /// ```
+    #[inline]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        if !self.intersects(other) {
+            return None;
+        }
+        let axis = |a: SmallRange<u32>, b: SmallRange<u32>| {
+            // Both bounds lie within `a`, so the result always fits
+            SmallRange::new(a.start().max(b.start()), a.end().min(b.end()))
+        };
+        Some(Self {
+            x: axis(self.x, other.x),
+            y: axis(self.y, other.y),
+        })
+    }
+}
+
+impl fmt::Debug for SmallRect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SmallRect")
+            .field("x", &self.x.to_range())
+            .field("y", &self.y.to_range())
+            .finish()
+    }
+}
@@ -0,0 +1,125 @@
+use num_traits::AsPrimitive;
+
+use crate::{SmallRange, SmallRangeStorage};
+
+/// A value paired with the [`SmallRange`] it came from.
+///
+/// Compiler frontends wrap tokens and AST nodes this way. The span's niche
+/// carries over, so `Option<Spanned<V, S>>` is the same size as
+/// `Spanned<V, S>`.
+///
+/// # Examples
+/// ```
+/// use small_range::{SmallRange, Spanned};
+///
+/// let ident = Spanned::new("foo", SmallRange::<u32>::new(4, 7));
+/// let len = ident.map(str::len);
+/// assert_eq!(*len.value(), 3);
+/// assert_eq!(len.span(), SmallRange::new(4, 7));
+///
+/// assert_eq!(core::mem::size_of::<Option<Spanned<u32>>>(), 8);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Spanned<V, S: SmallRangeStorage = u32>
+where
+    usize: AsPrimitive<S>,
+{
+    value: V,
+    span: SmallRange<S>,
+}
+
+impl<V, S: SmallRangeStorage> Spanned<V, S>
+where
+    usize: AsPrimitive<S>,
+{
+    /// Pairs a value with its span.
+    #[inline]
+    pub fn new(value: V, span: SmallRange<S>) -> Self {
+        Self { value, span }
+    }
+
+    /// Returns the span.
+    #[inline]
+    pub fn span(&self) -> SmallRange<S> {
+        self.span
+    }
+
+    /// Returns a reference to the value.
+    #[inline]
+    pub fn value(&self) -> &V {
+        &self.value
+    }
+
+    /// Returns a mutable reference to the value.
+    #[inline]
+    pub fn value_mut(&mut self) -> &mut V {
+        &mut self.value
+    }
+
+    /// Consumes the wrapper, returning the value.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.value
+    }
+
+    /// Consumes the wrapper, returning the value and the span.
+    #[inline]
+    pub fn into_parts(self) -> (V, SmallRange<S>) {
+        (self.value, self.span)
+    }
+
+    /// Borrows the value, keeping the span.
+    ///
+    /// # Examples
+    /// ```
+    /// use small_range::{SmallRange, Spanned};
+    ///
+    /// let name = Spanned::new(String::from("main"), SmallRange::<u32>::new(3, 7));
+    /// let borrowed: Spanned<&String> = name.as_ref();
+    /// assert_eq!(borrowed.value().as_str(), "main");
+    /// ```
+    #[inline]
+    pub fn as_ref(&self) -> Spanned<&V, S> {
+        Spanned {
+            value: &self.value,
+            span: self.span,
+        }
+    }
+
+    /// Mutably borrows the value, keeping the span.
+    #[inline]
+    pub fn as_mut(&mut self) -> Spanned<&mut V, S> {
+        Spanned {
+            value: &mut self.value,
+            span: self.span,
+        }
+    }
+
+    /// Transforms the value, keeping the span.
+    #[inline]
+    pub fn map<U, F: FnOnce(V) -> U>(self, f: F) -> Spanned<U, S> {
+        Spanned {
+            value: f(self.value),
+            span: self.span,
+        }
+    }
+
+    /// Replaces the span, keeping the value.
+    #[inline]
+    pub fn with_span(self, span: SmallRange<S>) -> Self {
+        Self {
+            value: self.value,
+            span,
+        }
+    }
+}
+
+impl<V, S: SmallRangeStorage> From<(V, SmallRange<S>)> for Spanned<V, S>
+where
+    usize: AsPrimitive<S>,
+{
+    #[inline]
+    fn from((value, span): (V, SmallRange<S>)) -> Self {
+        Self { value, span }
+    }
+}
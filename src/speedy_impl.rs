@@ -0,0 +1,44 @@
+//! speedy `Readable` and `Writable` for `SmallRange`.
+//!
+//! A range is written as the packed representation from
+//! [`SmallRange::to_bits`], a single `T` in the context's byte order, so files
+//! written big-endian read back correctly on little-endian machines and vice
+//! versa. Reading re-validates it, so bytes that no range produces are an
+//! error rather than a corrupt value.
+
+use num_traits::AsPrimitive;
+use speedy::{Context, Readable, Reader, Writable, Writer};
+
+use crate::{SmallRange, SmallRangeStorage};
+
+impl<'a, C: Context, T: SmallRangeStorage + Readable<'a, C>> Readable<'a, C> for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    fn read_from<R: Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+        let bits = T::read_from(reader)?;
+        SmallRange::from_bits(bits)
+            .ok_or_else(|| speedy::Error::custom("invalid SmallRange encoding").into())
+    }
+
+    #[inline]
+    fn minimum_bytes_needed() -> usize {
+        T::minimum_bytes_needed()
+    }
+}
+
+impl<C: Context, T: SmallRangeStorage + Writable<C>> Writable<C> for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    #[inline]
+    fn write_to<W: ?Sized + Writer<C>>(&self, writer: &mut W) -> Result<(), C::Error> {
+        T::get_nonzero(self.to_bits()).write_to(writer)
+    }
+
+    #[inline]
+    fn bytes_needed(&self) -> Result<usize, C::Error> {
+        T::get_nonzero(self.to_bits()).bytes_needed()
+    }
+}
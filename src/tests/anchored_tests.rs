@@ -0,0 +1,128 @@
+extern crate std;
+
+use crate::{AnchoredRange, SmallRange};
+use core::mem::size_of;
+use core::ops::Range;
+
+// =============================================================================
+// Layout Tests
+// =============================================================================
+
+#[test]
+fn test_anchored_size() {
+    assert_eq!(size_of::<AnchoredRange>(), 16);
+    assert_eq!(size_of::<Option<AnchoredRange>>(), 16);
+}
+
+// =============================================================================
+// Construction and Accessor Tests
+// =============================================================================
+
+#[test]
+fn test_anchored_accessors() {
+    let r = AnchoredRange::new(1 << 40, SmallRange::new(100, 150));
+    assert_eq!(r.base(), 1 << 40);
+    assert_eq!(r.offsets(), SmallRange::new(100, 150));
+    assert_eq!(r.absolute_start(), (1 << 40) + 100);
+    assert_eq!(r.absolute_end(), (1 << 40) + 150);
+    assert_eq!(r.len(), 50);
+    assert!(!r.is_empty());
+    assert_eq!(r.to_range(), (1 << 40) + 100..(1 << 40) + 150);
+    assert_eq!(Range::from(r), r.to_range());
+
+    let empty = AnchoredRange::default();
+    assert!(empty.is_empty());
+    assert_eq!(empty.to_range(), 0..0);
+}
+
+#[test]
+fn test_anchored_from_absolute() {
+    let r = AnchoredRange::from_absolute(1_000, 1_010, 1_020).unwrap();
+    assert_eq!(r.offsets(), SmallRange::new(10, 20));
+
+    assert!(AnchoredRange::from_absolute(1_000, 1_000 + 65_534, 1_000 + 65_534 * 2).is_some());
+    // Start before the base
+    assert!(AnchoredRange::from_absolute(1_000, 999, 1_010).is_none());
+    // Relative start too large
+    assert!(AnchoredRange::from_absolute(0, 65_535, 65_535).is_none());
+    // Length too large
+    assert!(AnchoredRange::from_absolute(0, 0, 65_535).is_none());
+    assert!(AnchoredRange::from_absolute(0, 0, u64::MAX).is_none());
+    // Reversed
+    assert!(AnchoredRange::from_absolute(0, 20, 10).is_none());
+}
+
+#[test]
+fn test_anchored_contains() {
+    let r = AnchoredRange::new(u32::MAX as u64, SmallRange::new(1, 3));
+    assert!(!r.contains(u32::MAX as u64));
+    assert!(r.contains(u32::MAX as u64 + 1));
+    assert!(r.contains(u32::MAX as u64 + 2));
+    assert!(!r.contains(u32::MAX as u64 + 3));
+}
+
+#[test]
+fn test_anchored_rebasing() {
+    let r = AnchoredRange::new(1_000, SmallRange::new(500, 600));
+
+    let rebased = r.rebase(1_400).unwrap();
+    assert_eq!(rebased.base(), 1_400);
+    assert_eq!(rebased.offsets(), SmallRange::new(100, 200));
+    assert_eq!(rebased.to_range(), r.to_range());
+    assert_ne!(rebased, r);
+
+    assert_eq!(r.rebase(1_500).unwrap().offsets(), SmallRange::new(0, 100));
+    assert!(r.rebase(1_501).is_none());
+    assert!(r.rebase(0).is_some());
+    assert!(AnchoredRange::new(100_000, SmallRange::new(0, 10))
+        .rebase(0)
+        .is_none());
+
+    let anchored = r.anchor_at_start();
+    assert_eq!(anchored.base(), 1_500);
+    assert_eq!(anchored.offsets(), SmallRange::new(0, 100));
+    assert_eq!(anchored.to_range(), r.to_range());
+
+    let moved = r.with_base(5_000);
+    assert_eq!(moved.offsets(), r.offsets());
+    assert_eq!(moved.to_range(), 5_500..5_600);
+}
+
+#[test]
+fn test_anchored_debug() {
+    assert_eq!(
+        std::format!("{:?}", AnchoredRange::new(7, SmallRange::new(1, 2))),
+        "AnchoredRange { base: 7, offsets: 1..2 }"
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "absolute end overflows u64")]
+fn test_anchored_overflow_panics() {
+    let _ = AnchoredRange::new(u64::MAX, SmallRange::new(0, 1));
+}
+
+// =============================================================================
+// Property-Based Tests
+// =============================================================================
+
+mod proptest_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn rebase_preserves_absolute_range(
+            base in 0u64..1 << 50, start in 0u32..30_000, len in 0u32..30_000, shift in 0u32..30_000,
+        ) {
+            let r = AnchoredRange::new(base, SmallRange::from_start_len(start, len));
+            let new_base = base + u64::from(shift);
+            match r.rebase(new_base) {
+                Some(rebased) => prop_assert_eq!(rebased.to_range(), r.to_range()),
+                None => prop_assert!(shift > start),
+            }
+            prop_assert_eq!(r.anchor_at_start().to_range(), r.to_range());
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use crate::SmallRange;
+use arbitrary::{Arbitrary, Unstructured};
+
+#[test]
+fn test_arbitrary_empty_input_is_origin() {
+    let mut u = Unstructured::new(&[]);
+    assert_eq!(
+        SmallRange::<u32>::arbitrary(&mut u).unwrap(),
+        SmallRange::new(0, 0)
+    );
+}
+
+#[test]
+fn test_arbitrary_always_valid() {
+    let data: [u8; 64] = core::array::from_fn(|i| (i as u8).wrapping_mul(37) ^ 0xA5);
+    let mut u = Unstructured::new(&data);
+    while !u.is_empty() {
+        let range = SmallRange::<u16>::arbitrary(&mut u).unwrap();
+        assert!(range.start() <= range.end());
+        assert!(range.start() <= SmallRange::<u16>::MAX_START);
+        assert!(range.len() <= SmallRange::<u16>::MAX_LEN as usize);
+    }
+}
+
+#[test]
+fn test_arbitrary_reaches_capacity() {
+    let data = [0xFF, 0xFF, 0xFF, 0xFE, 0xFF, 0xFF, 0xFF, 0xFE];
+    let mut u = Unstructured::new(&data);
+    let range = SmallRange::<u64>::arbitrary(&mut u).unwrap();
+    assert_eq!(range.start(), SmallRange::<u64>::MAX_START);
+    assert_eq!(range.end(), SmallRange::<u64>::MAX_END);
+}
+
+#[test]
+fn test_arbitrary_size_hint() {
+    assert_eq!(<SmallRange<u32> as Arbitrary>::size_hint(0), (0, Some(8)));
+    assert_eq!(<SmallRange<u8> as Arbitrary>::size_hint(0), (0, Some(2)));
+}
+
+#[test]
+fn test_arbitrary_in_tuple() {
+    let data = [7u8; 16];
+    let (span, replacement) =
+        <(SmallRange<u32>, SmallRange<u32>)>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+    assert!(span.start() <= span.end());
+    assert!(replacement.start() <= replacement.end());
+}
+
+mod proptest_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn arbitrary_is_valid(data in proptest::collection::vec(any::<u8>(), 0..32)) {
+            let mut u = Unstructured::new(&data);
+            let range = SmallRange::<u32>::arbitrary(&mut u).unwrap();
+            prop_assert!(SmallRange::<u32>::fits(range.start(), range.end()));
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::SmallRange;
+use bincode::config;
+use bincode::error::DecodeError;
+
+fn roundtrip<T>(range: SmallRange<T>) -> SmallRange<T>
+where
+    T: crate::SmallRangeStorage + bincode::Encode + bincode::Decode<()>,
+    usize: num_traits::AsPrimitive<T>,
+{
+    let mut buf = [0u8; 16];
+    let written = bincode::encode_into_slice(range, &mut buf, config::standard()).unwrap();
+    let (decoded, read) = bincode::decode_from_slice(&buf[..written], config::standard()).unwrap();
+    assert_eq!(read, written);
+    decoded
+}
+
+// =============================================================================
+// Round-Trip Tests
+// =============================================================================
+
+#[test]
+fn test_bincode_roundtrip() {
+    let range = SmallRange::<u32>::new(10, 20);
+    assert_eq!(roundtrip(range), range);
+    assert_eq!(
+        roundtrip(SmallRange::<u8>::new(3, 7)),
+        SmallRange::new(3, 7)
+    );
+    assert_eq!(
+        roundtrip(SmallRange::<u16>::new(0, 0)),
+        SmallRange::new(0, 0)
+    );
+    let range = SmallRange::<u64>::new(1 << 30, (1 << 30) + 5);
+    assert_eq!(roundtrip(range), range);
+    let range = SmallRange::<usize>::new(100, 200);
+    assert_eq!(roundtrip(range), range);
+}
+
+#[test]
+fn test_bincode_writes_packed_bits() {
+    let range = SmallRange::<u32>::new(10, 20);
+    let config = config::standard().with_fixed_int_encoding();
+    let mut buf = [0u8; 4];
+    bincode::encode_into_slice(range, &mut buf, config).unwrap();
+    assert_eq!(buf, range.to_bits().get().to_le_bytes());
+}
+
+#[test]
+fn test_bincode_borrow_decode() {
+    let range = SmallRange::<u16>::new(4, 9);
+    let mut buf = [0u8; 8];
+    let written = bincode::encode_into_slice(range, &mut buf, config::standard()).unwrap();
+    let (decoded, _): (SmallRange<u16>, usize) =
+        bincode::borrow_decode_from_slice(&buf[..written], config::standard()).unwrap();
+    assert_eq!(decoded, range);
+}
+
+// =============================================================================
+// Validation Tests
+// =============================================================================
+
+#[test]
+fn test_bincode_rejects_invalid_encoding() {
+    let config = config::standard().with_fixed_int_encoding();
+    for bits in [0u32, 11 << 16, 11] {
+        let result = bincode::decode_from_slice::<SmallRange<u32>, _>(&bits.to_le_bytes(), config);
+        assert!(matches!(
+            result,
+            Err(DecodeError::Other("invalid SmallRange encoding"))
+        ));
+    }
+    let result = bincode::decode_from_slice::<SmallRange<u16>, _>(&[0xFF, 0x00], config);
+    assert!(result.is_err());
+}
@@ -0,0 +1,77 @@
+use crate::SmallRange;
+use bytemuck::TransparentWrapper;
+use core::num::NonZero;
+
+// =============================================================================
+// Slice Cast Tests
+// =============================================================================
+
+#[test]
+fn test_bytemuck_slice_roundtrip() {
+    let ranges = [
+        SmallRange::<u64>::new(0, 0),
+        SmallRange::new(10, 20),
+        SmallRange::new(1 << 30, (1 << 30) + 5),
+    ];
+    let bits = SmallRange::slice_to_bits(&ranges);
+    assert_eq!(bits.len(), 3);
+    for (range, bits) in ranges.iter().zip(bits) {
+        assert_eq!(range.to_bits().get(), *bits);
+    }
+    let back = SmallRange::try_slice_from_bits(bits).unwrap();
+    assert_eq!(back, &ranges[..]);
+    assert_eq!(back.as_ptr().cast::<u64>(), bits.as_ptr());
+}
+
+#[test]
+fn test_bytemuck_slice_rejects_invalid_encoding() {
+    let valid = SmallRange::<u32>::new(10, 20).to_bits().get();
+    assert!(SmallRange::<u32>::try_slice_from_bits(&[valid, 0]).is_none());
+    assert!(SmallRange::<u32>::try_slice_from_bits(&[valid, 11 << 16]).is_none());
+    assert!(SmallRange::<u32>::try_slice_from_bits(&[11, valid]).is_none());
+    assert_eq!(SmallRange::<u32>::try_slice_from_bits(&[]), Some(&[][..]));
+}
+
+#[test]
+fn test_bytemuck_byte_cast() {
+    let ranges = [SmallRange::<u16>::new(1, 4), SmallRange::new(7, 9)];
+    let bytes: &[u8] = bytemuck::cast_slice(&ranges);
+    assert_eq!(bytes.len(), 4);
+    let back: &[SmallRange<u16>] = bytemuck::checked::cast_slice(bytes);
+    assert_eq!(back, &ranges[..]);
+    assert!(bytemuck::checked::try_cast_slice::<u8, SmallRange<u16>>(&[0, 0]).is_err());
+}
+
+// =============================================================================
+// Checked Bit Pattern Tests
+// =============================================================================
+
+#[test]
+fn test_bytemuck_checked_cast() {
+    let range = SmallRange::<u8>::new(3, 7);
+    let bits: u8 = bytemuck::cast(range);
+    assert_eq!(bits, range.to_bits().get());
+    assert_eq!(
+        bytemuck::checked::try_cast::<u8, SmallRange<u8>>(bits),
+        Ok(range)
+    );
+    assert!(bytemuck::checked::try_cast::<u8, SmallRange<u8>>(0).is_err());
+    assert!(bytemuck::checked::try_cast::<u8, SmallRange<u8>>(0xF0).is_err());
+    assert!(bytemuck::checked::try_cast::<u8, SmallRange<u8>>(0x0F).is_err());
+}
+
+// =============================================================================
+// Transparent Wrapper Tests
+// =============================================================================
+
+#[test]
+fn test_bytemuck_transparent_wrapper() {
+    let range = SmallRange::<u32>::new(10, 20);
+    let inner: NonZero<u32> = SmallRange::peel(range);
+    assert_eq!(inner, range.to_bits());
+    assert_eq!(SmallRange::<u32>::wrap(inner), range);
+
+    let inners = [inner, SmallRange::<u32>::new(0, 1).to_bits()];
+    let wrapped = SmallRange::<u32>::wrap_slice(&inners);
+    assert_eq!(wrapped, &[range, SmallRange::new(0, 1)]);
+}
@@ -0,0 +1,145 @@
+extern crate std;
+
+use crate::{CharRange, SmallRange, SmallRangeError};
+use core::mem::size_of;
+use std::vec::Vec;
+
+// =============================================================================
+// Layout Tests
+// =============================================================================
+
+#[test]
+fn test_char_range_size() {
+    assert_eq!(size_of::<CharRange>(), 8);
+    assert_eq!(size_of::<Option<CharRange>>(), 8);
+}
+
+// =============================================================================
+// Construction and Accessor Tests
+// =============================================================================
+
+#[test]
+fn test_char_range_accessors() {
+    let r = CharRange::new('a', 'e');
+    assert_eq!(r.start(), 'a' as u32);
+    assert_eq!(r.end(), 'e' as u32);
+    assert_eq!(r.len(), 4);
+    assert!(!r.is_empty());
+    assert_eq!(r.first(), Some('a'));
+    assert_eq!(r.last(), Some('d'));
+    assert_eq!(r.as_small_range(), SmallRange::<u64>::new(97, 101));
+
+    let empty = CharRange::default();
+    assert!(empty.is_empty());
+    assert_eq!(empty.first(), None);
+    assert_eq!(empty.last(), None);
+}
+
+#[test]
+fn test_char_range_contains() {
+    let r = CharRange::try_from('a'..='z').unwrap();
+    assert!(r.contains('a'));
+    assert!(r.contains('z'));
+    assert!(!r.contains('{'));
+    assert!(!r.contains('`'));
+}
+
+#[test]
+fn test_char_range_from_range_inclusive() {
+    let all = CharRange::try_from('\0'..=char::MAX).unwrap();
+    assert_eq!(all.end(), 0x11_0000);
+    assert_eq!(all.len(), 0x11_0000);
+    assert_eq!(all.chars().len(), 0x11_0000 - 0x800);
+    assert_eq!(all.to_range_inclusive(), Some('\0'..=char::MAX));
+
+    let single = CharRange::try_from('x'..='x').unwrap();
+    assert_eq!(single.len(), 1);
+    assert_eq!(single.to_range_inclusive(), Some('x'..='x'));
+
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = 'z'..='a';
+    assert_eq!(
+        CharRange::try_from(reversed),
+        Err(SmallRangeError::StartAfterEnd)
+    );
+
+    let mut exhausted = 'a'..='a';
+    exhausted.next();
+    assert_eq!(
+        CharRange::try_from(exhausted),
+        Err(SmallRangeError::StartAfterEnd)
+    );
+}
+
+// =============================================================================
+// Iteration Tests
+// =============================================================================
+
+#[test]
+fn test_char_range_iteration() {
+    let r = CharRange::try_from('a'..='e').unwrap();
+    assert_eq!(r.chars().collect::<Vec<_>>(), ['a', 'b', 'c', 'd', 'e']);
+    assert_eq!(
+        r.chars().rev().collect::<Vec<_>>(),
+        ['e', 'd', 'c', 'b', 'a']
+    );
+    assert_eq!(r.into_iter().count(), 5);
+    assert_eq!((&r).into_iter().len(), 5);
+}
+
+#[test]
+fn test_char_range_skips_surrogates() {
+    let r = CharRange::new('\u{D7FE}', '\u{E001}');
+    assert_eq!(r.len(), 0x803);
+    assert_eq!(r.chars().len(), 3);
+    assert_eq!(
+        r.chars().collect::<Vec<_>>(),
+        ['\u{D7FE}', '\u{D7FF}', '\u{E000}']
+    );
+    assert_eq!(
+        r.chars().rev().collect::<Vec<_>>(),
+        ['\u{E000}', '\u{D7FF}', '\u{D7FE}']
+    );
+
+    // Ends just before the surrogates: the exclusive end is U+D800
+    let below = CharRange::try_from('\u{D700}'..='\u{D7FF}').unwrap();
+    assert_eq!(below.end(), 0xD800);
+    assert_eq!(below.last(), Some('\u{D7FF}'));
+
+    let mut chars = r.chars();
+    assert_eq!(chars.next(), Some('\u{D7FE}'));
+    assert_eq!(chars.next_back(), Some('\u{E000}'));
+    assert_eq!(chars.len(), 1);
+    assert_eq!(chars.next_back(), Some('\u{D7FF}'));
+    assert_eq!(chars.next(), None);
+    assert_eq!(chars.next_back(), None);
+}
+
+#[test]
+fn test_char_range_debug() {
+    assert_eq!(
+        std::format!("{:?}", CharRange::new('a', 'e')),
+        "CharRange { start: 'a', end: U+0065 }"
+    );
+}
+
+// =============================================================================
+// Property-Based Tests
+// =============================================================================
+
+mod proptest_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn chars_match_std(a in any::<char>(), b in any::<char>()) {
+            let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+            let r = CharRange::try_from(lo..=hi).unwrap();
+            let chars = r.chars();
+            prop_assert_eq!(chars.len(), (lo..=hi).count());
+            prop_assert!(r.chars().eq(lo..=hi));
+            prop_assert!(r.chars().rev().eq((lo..=hi).rev()));
+        }
+    }
+}
@@ -0,0 +1,126 @@
+extern crate std;
+
+use crate::{FullSmallRange, SmallRange};
+use core::mem::size_of;
+
+// =============================================================================
+// Layout Tests
+// =============================================================================
+
+#[test]
+fn test_full_range_size() {
+    assert_eq!(size_of::<FullSmallRange<u8>>(), 1);
+    assert_eq!(size_of::<FullSmallRange<u32>>(), 4);
+    assert_eq!(size_of::<Option<FullSmallRange<u32>>>(), 8);
+}
+
+#[test]
+fn test_full_range_capacity() {
+    assert_eq!(FullSmallRange::<u8>::MAX_START, 15);
+    assert_eq!(FullSmallRange::<u16>::MAX_LEN, 255);
+    assert_eq!(FullSmallRange::<u32>::MAX_START, 65_535);
+    assert_eq!(FullSmallRange::<u64>::MAX_LEN, u32::MAX as u64);
+}
+
+// =============================================================================
+// Construction and Accessor Tests
+// =============================================================================
+
+#[test]
+fn test_full_range_roundtrip() {
+    let r = FullSmallRange::<u32>::new(100, 150);
+    assert_eq!(r.start(), 100);
+    assert_eq!(r.end(), 150);
+    assert_eq!(r.len(), 50);
+    assert!(!r.is_empty());
+    assert_eq!(r.to_range(), 100..150);
+
+    let max = FullSmallRange::<u8>::from_start_len(15, 15);
+    assert_eq!(max.to_range(), 15..30);
+
+    let empty = FullSmallRange::<u64>::default();
+    assert!(empty.is_empty());
+    assert_eq!(empty.to_range(), 0..0);
+}
+
+#[test]
+fn test_full_range_try_new() {
+    assert!(FullSmallRange::<u16>::try_new(255, 510).is_some());
+    assert!(FullSmallRange::<u16>::try_new(256, 256).is_none());
+    assert!(FullSmallRange::<u16>::try_new(0, 256).is_none());
+    assert!(FullSmallRange::<u16>::try_new(10, 5).is_none());
+    assert!(FullSmallRange::<u16>::try_from_start_len(u16::MAX, 0).is_none());
+}
+
+#[test]
+fn test_full_range_contains() {
+    let r = FullSmallRange::<u32>::new(100, 110);
+    assert!(r.contains(100));
+    assert!(r.contains(109));
+    assert!(!r.contains(110));
+    assert!(!r.contains(99));
+}
+
+#[test]
+fn test_full_range_ord() {
+    let a = FullSmallRange::<u32>::new(5, 30);
+    let b = FullSmallRange::<u32>::new(10, 15);
+    let c = FullSmallRange::<u32>::new(10, 20);
+    assert!(a < b);
+    assert!(b < c);
+}
+
+#[test]
+fn test_full_range_small_range_conversions() {
+    let small = SmallRange::<u16>::new(254, 508);
+    let full = FullSmallRange::from(small);
+    assert_eq!(full.to_range(), 254..508);
+    assert_eq!(full.to_small_range(), Some(small));
+
+    assert_eq!(FullSmallRange::<u16>::new(255, 255).to_small_range(), None);
+    assert_eq!(FullSmallRange::<u16>::new(0, 255).to_small_range(), None);
+}
+
+#[test]
+fn test_full_range_debug() {
+    assert_eq!(
+        std::format!("{:?}", FullSmallRange::<u32>::new(1, 2)),
+        "FullSmallRange { start: 1, end: 2 }"
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "start exceeds half-width capacity")]
+fn test_full_range_start_overflow_panics() {
+    let _ = FullSmallRange::<u16>::from_start_len(256, 0);
+}
+
+// =============================================================================
+// Property-Based Tests
+// =============================================================================
+
+mod proptest_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn roundtrip(start in 0u32..=65_535, len in 0u32..=65_535) {
+            let r = FullSmallRange::<u32>::from_start_len(start, len);
+            prop_assert_eq!(r.start(), start);
+            prop_assert_eq!(r.len(), len as usize);
+            prop_assert_eq!(r.to_range(), start..start + len);
+        }
+
+        #[test]
+        fn ord_matches_tuple(
+            s1 in 0u16..=255, l1 in 0u16..=255,
+            s2 in 0u16..=255, l2 in 0u16..=255,
+        ) {
+            let a = FullSmallRange::<u16>::from_start_len(s1, l1);
+            let b = FullSmallRange::<u16>::from_start_len(s2, l2);
+            prop_assert_eq!(a.cmp(&b), (a.start(), a.end()).cmp(&(b.start(), b.end())));
+        }
+    }
+}
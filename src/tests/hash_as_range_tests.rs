@@ -0,0 +1,49 @@
+extern crate std;
+
+use crate::{HashAsRange, SmallRange};
+use core::hash::BuildHasher;
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+
+#[test]
+fn test_hash_matches_range() {
+    let state = RandomState::new();
+    for (start, end) in [(0u64, 0u64), (10, 20), (1 << 30, (1 << 30) + 7)] {
+        let key = HashAsRange::new(SmallRange::new(start, end));
+        assert_eq!(state.hash_one(key), state.hash_one(start..end));
+    }
+    let key = HashAsRange::new(SmallRange::<u8>::new(3, 7));
+    assert_eq!(state.hash_one(key), state.hash_one(3u8..7));
+}
+
+#[test]
+fn test_map_lookup_by_range() {
+    // Entries inserted under either representation land in the same bucket
+    // and compare equal once converted
+    let mut map: HashMap<HashAsRange<u32>, &str> = HashMap::new();
+    map.insert(SmallRange::new(4, 8).into(), "main");
+    let by_range = 4u32..8;
+    let key = HashAsRange::new(SmallRange::new(by_range.start, by_range.end));
+    assert_eq!(map.get(&key), Some(&"main"));
+    assert_eq!(key, by_range);
+    assert_eq!(by_range, key);
+}
+
+#[test]
+fn test_conversions() {
+    let range = SmallRange::<u16>::new(1, 4);
+    let key = HashAsRange::from(range);
+    assert_eq!(key.get(), range);
+    assert_eq!(key.to_range(), 1..4);
+    assert_eq!(SmallRange::from(key), range);
+    assert_eq!(HashAsRange::<u16>::default().get(), SmallRange::default());
+    assert_eq!(core::mem::size_of::<Option<HashAsRange<u32>>>(), 4);
+}
+
+#[test]
+fn test_ordering_and_debug() {
+    let a = HashAsRange::new(SmallRange::<u32>::new(1, 4));
+    let b = HashAsRange::new(SmallRange::<u32>::new(1, 5));
+    assert!(a < b);
+    assert_eq!(std::format!("{:?}", a), std::format!("{:?}", a.get()));
+}
@@ -0,0 +1,140 @@
+extern crate alloc;
+extern crate std;
+
+use crate::{IntervalTree, SmallRange};
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+
+fn tree(entries: &[(u32, u32)]) -> IntervalTree<u32, usize> {
+    entries
+        .iter()
+        .enumerate()
+        .map(|(index, &(start, end))| (SmallRange::new(start, end), index))
+        .collect()
+}
+
+fn values<'a>(hits: impl Iterator<Item = (SmallRange<u32>, &'a usize)>) -> Vec<usize> {
+    hits.map(|(_, &value)| value).collect()
+}
+
+// =============================================================================
+// Construction Tests
+// =============================================================================
+
+#[test]
+fn test_entries_are_sorted_by_range() {
+    let t = tree(&[(10, 20), (0, 5), (10, 15), (0, 5)]);
+    let ranges: Vec<_> = t.iter().map(|(range, _)| range.to_parts()).collect();
+    assert_eq!(ranges, vec![(0, 5), (0, 5), (10, 15), (10, 20)]);
+    // Equal ranges keep their insertion order
+    assert_eq!(values(t.iter()), vec![1, 3, 2, 0]);
+    assert_eq!(t.len(), 4);
+}
+
+#[test]
+fn test_insert_matches_collect() {
+    let entries = [(10, 20), (0, 5), (3, 30), (10, 15), (0, 5)];
+    let mut inserted = IntervalTree::new();
+    for (index, &(start, end)) in entries.iter().enumerate() {
+        inserted.insert(SmallRange::new(start, end), index);
+    }
+    assert_eq!(inserted, tree(&entries));
+}
+
+#[test]
+fn test_empty_tree() {
+    let t = IntervalTree::<u32, ()>::default();
+    assert!(t.is_empty());
+    assert_eq!(t.stabbing(0).next(), None);
+    assert_eq!(t.overlapping(SmallRange::new(0, 100)).next(), None);
+}
+
+// =============================================================================
+// Query Tests
+// =============================================================================
+
+#[test]
+fn test_stabbing() {
+    let t = tree(&[(0, 10), (5, 15), (5, 15), (20, 30), (0, 100)]);
+    assert_eq!(values(t.stabbing(0)), vec![0, 4]);
+    // Sorted by range, so 0..100 comes before 5..15
+    assert_eq!(values(t.stabbing(7)), vec![0, 4, 1, 2]);
+    assert_eq!(values(t.stabbing(10)), vec![4, 1, 2]);
+    assert_eq!(values(t.stabbing(17)), vec![4]);
+    assert_eq!(values(t.stabbing(100)), Vec::<usize>::new());
+    assert_eq!(values(t.stabbing(u32::MAX)), Vec::<usize>::new());
+}
+
+#[test]
+fn test_overlapping() {
+    let t = tree(&[(0, 10), (5, 15), (15, 20), (30, 40), (12, 12)]);
+    assert_eq!(values(t.overlapping(SmallRange::new(8, 15))), vec![0, 1]);
+    assert_eq!(
+        values(t.overlapping(SmallRange::new(0, 50))),
+        vec![0, 1, 2, 3]
+    );
+    // Touching isn't overlapping, and empty ranges overlap nothing
+    assert_eq!(
+        values(t.overlapping(SmallRange::new(20, 30))),
+        Vec::<usize>::new()
+    );
+    assert_eq!(
+        values(t.overlapping(SmallRange::new(7, 7))),
+        Vec::<usize>::new()
+    );
+}
+
+// =============================================================================
+// Trait Tests
+// =============================================================================
+
+#[test]
+fn test_iteration_and_debug() {
+    let mut t = IntervalTree::<u32, &str>::new();
+    t.extend([(SmallRange::new(5, 8), "b"), (SmallRange::new(1, 3), "a")]);
+    let by_ref: Vec<_> = (&t).into_iter().map(|(r, &v)| (r, v)).collect();
+    let owned: Vec<_> = t.clone().into_iter().collect();
+    assert_eq!(by_ref, owned);
+    assert_eq!(t.iter().len(), 2);
+    assert_eq!(t.iter().next_back(), Some((SmallRange::new(5, 8), &"b")));
+    assert_eq!(
+        format!("{t:?}"),
+        format!(
+            "{{{:?}: \"a\", {:?}: \"b\"}}",
+            SmallRange::<u32>::new(1, 3),
+            SmallRange::<u32>::new(5, 8)
+        )
+    );
+    t.clear();
+    assert!(t.is_empty());
+}
+
+// =============================================================================
+// Property-Based Tests
+// =============================================================================
+
+mod proptest_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn range() -> impl Strategy<Value = SmallRange<u16>> {
+        (0u16..=254, 0u16..=40).prop_map(|(start, len)| SmallRange::from_start_len(start, len))
+    }
+
+    proptest! {
+        #[test]
+        fn queries_match_scan(
+            ranges in proptest::collection::vec(range(), 0..60),
+            query in range(),
+            point in 0u16..300,
+        ) {
+            let t: IntervalTree<u16, usize> =
+                ranges.iter().copied().zip(0..).collect();
+            let scan: Vec<_> = t.iter().filter(|(range, _)| range.overlaps(&query)).collect();
+            prop_assert_eq!(t.overlapping(query).collect::<Vec<_>>(), scan);
+            let scan: Vec<_> = t.iter().filter(|(range, _)| range.contains(point)).collect();
+            prop_assert_eq!(t.stabbing(point).collect::<Vec<_>>(), scan);
+        }
+    }
+}
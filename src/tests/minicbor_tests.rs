@@ -0,0 +1,86 @@
+use crate::SmallRange;
+use minicbor::encode::write::Cursor;
+
+fn encode<T>(range: SmallRange<T>) -> ([u8; 32], usize)
+where
+    T: crate::SmallRangeStorage + minicbor::Encode<()>,
+    usize: num_traits::AsPrimitive<T>,
+{
+    let mut cursor = Cursor::new([0u8; 32]);
+    minicbor::encode(range, &mut cursor).unwrap();
+    let len = cursor.position();
+    (cursor.into_inner(), len)
+}
+
+// =============================================================================
+// Round-Trip Tests
+// =============================================================================
+
+#[test]
+fn test_minicbor_roundtrip() {
+    let range = SmallRange::<u32>::new(10, 20);
+    let (buf, len) = encode(range);
+    assert_eq!(
+        minicbor::decode::<SmallRange<u32>>(&buf[..len]).unwrap(),
+        range
+    );
+
+    let range = SmallRange::<u64>::new(1 << 30, (1 << 30) + 5);
+    let (buf, len) = encode(range);
+    assert_eq!(
+        minicbor::decode::<SmallRange<u64>>(&buf[..len]).unwrap(),
+        range
+    );
+
+    let range = SmallRange::<u8>::new(0, 0);
+    let (buf, len) = encode(range);
+    assert_eq!(
+        minicbor::decode::<SmallRange<u8>>(&buf[..len]).unwrap(),
+        range
+    );
+}
+
+#[test]
+fn test_minicbor_writes_array() {
+    // [10, 20]: array(2), then two small unsigned integers
+    let (buf, len) = encode(SmallRange::<u32>::new(10, 20));
+    assert_eq!(&buf[..len], &[0x82, 0x0A, 0x14]);
+
+    // [0, 300]: the end needs a two-byte integer
+    let (buf, len) = encode(SmallRange::<u32>::new(0, 300));
+    assert_eq!(&buf[..len], &[0x82, 0x00, 0x19, 0x01, 0x2C]);
+}
+
+#[test]
+fn test_minicbor_cbor_len() {
+    for range in [SmallRange::<u32>::new(10, 20), SmallRange::new(0, 300)] {
+        let (_, len) = encode(range);
+        assert_eq!(minicbor::len(range), len);
+    }
+}
+
+// =============================================================================
+// Validation Tests
+// =============================================================================
+
+#[test]
+fn test_minicbor_rejects_invalid_ranges() {
+    // [20, 10]
+    assert!(minicbor::decode::<SmallRange<u32>>(&[0x82, 0x14, 0x0A]).is_err());
+    // [0, 255] doesn't fit u16's half-width
+    assert!(minicbor::decode::<SmallRange<u16>>(&[0x82, 0x00, 0x18, 0xFF]).is_err());
+    // [255, 255]
+    assert!(minicbor::decode::<SmallRange<u16>>(&[0x82, 0x18, 0xFF, 0x18, 0xFF]).is_err());
+}
+
+#[test]
+fn test_minicbor_rejects_malformed_input() {
+    // [10]
+    assert!(minicbor::decode::<SmallRange<u32>>(&[0x81, 0x0A]).is_err());
+    // [10, 20, 30]
+    assert!(minicbor::decode::<SmallRange<u32>>(&[0x83, 0x0A, 0x14, 0x1E]).is_err());
+    // Indefinite-length array
+    assert!(minicbor::decode::<SmallRange<u32>>(&[0x9F, 0x0A, 0x14, 0xFF]).is_err());
+    // A bare integer
+    assert!(minicbor::decode::<SmallRange<u32>>(&[0x0A]).is_err());
+}
@@ -0,0 +1,44 @@
+extern crate std;
+
+use crate::SmallRange;
+use core::hash::{BuildHasher, Hash, Hasher};
+use nohash_hasher::{BuildNoHashHasher, IntMap, IntSet, NoHashHasher};
+
+#[test]
+fn test_nohash_hash_is_packed_bits() {
+    let range = SmallRange::<u32>::new(10, 20);
+    let mut hasher = NoHashHasher::<SmallRange<u32>>::default();
+    range.hash(&mut hasher);
+    assert_eq!(hasher.finish(), range.to_u64_key());
+
+    let range = SmallRange::<u8>::new(3, 7);
+    assert_eq!(
+        BuildNoHashHasher::<SmallRange<u8>>::default().hash_one(range),
+        range.to_u64_key()
+    );
+}
+
+#[test]
+fn test_nohash_int_map() {
+    let mut spans: IntMap<SmallRange<u64>, &str> = IntMap::default();
+    spans.insert(SmallRange::new(0, 3), "fn");
+    spans.insert(SmallRange::new(4, 8), "main");
+    assert_eq!(spans[&SmallRange::new(4, 8)], "main");
+    assert_eq!(spans.get(&SmallRange::new(4, 9)), None);
+
+    let set: IntSet<SmallRange<u16>> = [SmallRange::new(1, 2), SmallRange::new(1, 2)]
+        .into_iter()
+        .collect();
+    assert_eq!(set.len(), 1);
+}
+
+#[test]
+fn test_nohash_u64_key_map() {
+    let mut spans: IntMap<u64, &str> = IntMap::default();
+    let narrow = SmallRange::<u16>::new(1, 4);
+    let wide = SmallRange::<u32>::new(1, 4);
+    spans.insert(narrow.to_u64_key(), "narrow");
+    spans.insert(wide.to_u64_key(), "wide");
+    assert_eq!(spans.len(), 2);
+    assert_eq!(spans[&wide.to_u64_key()], "wide");
+}
@@ -0,0 +1,106 @@
+extern crate std;
+
+use crate::{NonEmptySmallRange, SmallRange};
+use core::mem::size_of;
+
+// =============================================================================
+// Layout Tests
+// =============================================================================
+
+#[test]
+fn test_non_empty_size() {
+    assert_eq!(size_of::<NonEmptySmallRange<u32>>(), 4);
+    assert_eq!(size_of::<Option<NonEmptySmallRange<u32>>>(), 4);
+}
+
+// =============================================================================
+// Construction Tests
+// =============================================================================
+
+#[test]
+fn test_non_empty_construction() {
+    assert!(NonEmptySmallRange::<u16>::try_new(10, 11).is_some());
+    assert!(NonEmptySmallRange::<u16>::try_new(10, 10).is_none());
+    assert!(NonEmptySmallRange::<u16>::try_new(10, 9).is_none());
+    assert!(NonEmptySmallRange::<u16>::try_new(0, 255).is_none());
+
+    let small = SmallRange::<u16>::new(3, 8);
+    let non_empty = NonEmptySmallRange::from_small_range(small).unwrap();
+    assert_eq!(non_empty.as_small_range(), small);
+    assert_eq!(SmallRange::from(non_empty), small);
+    assert!(NonEmptySmallRange::from_small_range(SmallRange::<u16>::new(3, 3)).is_none());
+}
+
+// =============================================================================
+// Accessor Tests
+// =============================================================================
+
+#[test]
+fn test_non_empty_accessors() {
+    let r = NonEmptySmallRange::<u32>::new(10, 15);
+    assert_eq!(r.start(), 10);
+    assert_eq!(r.end(), 15);
+    assert_eq!(r.len(), 5);
+    assert!(!r.is_empty());
+    assert_eq!(r.first(), 10);
+    assert_eq!(r.last(), 14);
+    assert_eq!(r.midpoint(), 12);
+    assert_eq!(r.to_range(), 10..15);
+    assert!(r.contains(14));
+    assert!(!r.contains(15));
+
+    let single = NonEmptySmallRange::<u32>::new(7, 8);
+    assert_eq!(single.first(), 7);
+    assert_eq!(single.last(), 7);
+    assert_eq!(single.midpoint(), 7);
+}
+
+#[test]
+fn test_non_empty_overlaps() {
+    let a = NonEmptySmallRange::<u32>::new(10, 20);
+    assert!(a.overlaps(&NonEmptySmallRange::new(19, 30)));
+    assert!(a.overlaps(&NonEmptySmallRange::new(0, 11)));
+    assert!(a.overlaps(&NonEmptySmallRange::new(12, 13)));
+    assert!(!a.overlaps(&NonEmptySmallRange::new(20, 30)));
+    assert!(!a.overlaps(&NonEmptySmallRange::new(0, 10)));
+}
+
+#[test]
+fn test_non_empty_debug() {
+    assert_eq!(
+        std::format!("{:?}", NonEmptySmallRange::<u32>::new(1, 2)),
+        "NonEmptySmallRange { start: 1, end: 2 }"
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "range must not be empty")]
+fn test_non_empty_new_panics_on_empty() {
+    let _ = NonEmptySmallRange::<u32>::new(5, 5);
+}
+
+// =============================================================================
+// Property-Based Tests
+// =============================================================================
+
+mod proptest_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn matches_small_range(
+            s1 in 0u32..1000, l1 in 1u32..1000,
+            s2 in 0u32..1000, l2 in 1u32..1000,
+        ) {
+            let a = SmallRange::<u32>::from_start_len(s1, l1);
+            let b = SmallRange::<u32>::from_start_len(s2, l2);
+            let na = NonEmptySmallRange::from_small_range(a).unwrap();
+            let nb = NonEmptySmallRange::from_small_range(b).unwrap();
+            prop_assert_eq!(na.overlaps(&nb), a.overlaps(&b));
+            prop_assert_eq!(Some(na.midpoint()), a.midpoint());
+            prop_assert_eq!(na.cmp(&nb), a.cmp(&b));
+        }
+    }
+}
@@ -0,0 +1,165 @@
+extern crate std;
+
+use crate::{PackedRange, SmallRange};
+use core::mem::size_of;
+
+// =============================================================================
+// Layout Tests
+// =============================================================================
+
+#[test]
+fn test_packed_range_size() {
+    assert_eq!(size_of::<PackedRange<u16, 12>>(), 2);
+    assert_eq!(size_of::<Option<PackedRange<u16, 12>>>(), 2);
+    assert_eq!(size_of::<PackedRange<u64, 48>>(), 8);
+    assert_eq!(size_of::<Option<PackedRange<u64, 48>>>(), 8);
+}
+
+#[test]
+fn test_packed_range_capacity() {
+    assert_eq!(PackedRange::<u16, 12>::LEN_BITS, 4);
+    assert_eq!(PackedRange::<u16, 12>::max_start(), 4094);
+    assert_eq!(PackedRange::<u16, 12>::max_len(), 14);
+    assert_eq!(PackedRange::<u64, 40>::max_start(), (1 << 40) - 2);
+    assert_eq!(PackedRange::<u64, 40>::max_len(), (1 << 24) - 2);
+
+    // A 50/50 split matches SmallRange
+    assert_eq!(
+        PackedRange::<u32, 16>::max_start(),
+        SmallRange::<u32>::MAX_START
+    );
+    assert_eq!(
+        PackedRange::<u32, 16>::max_len(),
+        SmallRange::<u32>::MAX_LEN
+    );
+}
+
+// =============================================================================
+// Construction and Accessor Tests
+// =============================================================================
+
+#[test]
+fn test_packed_range_roundtrip() {
+    let r = PackedRange::<u16, 12>::new(4000, 4014);
+    assert_eq!(r.start(), 4000);
+    assert_eq!(r.end(), 4014);
+    assert_eq!(r.len(), 14);
+    assert!(!r.is_empty());
+    assert_eq!(r.to_range(), 4000..4014);
+
+    let max = PackedRange::<u16, 12>::from_start_len(4094, 14);
+    assert_eq!(max.to_range(), 4094..4108);
+
+    let empty = PackedRange::<u64, 48>::default();
+    assert!(empty.is_empty());
+    assert_eq!(empty.to_range(), 0..0);
+}
+
+#[test]
+fn test_packed_range_try_new() {
+    assert!(PackedRange::<u16, 12>::try_new(4094, 4108).is_some());
+    assert!(PackedRange::<u16, 12>::try_new(4095, 4095).is_none());
+    assert!(PackedRange::<u16, 12>::try_new(0, 15).is_none());
+    assert!(PackedRange::<u16, 12>::try_new(10, 5).is_none());
+    assert!(PackedRange::<u16, 12>::try_from_start_len(u16::MAX, u16::MAX).is_none());
+}
+
+#[test]
+fn test_packed_range_contains() {
+    let r = PackedRange::<u32, 24>::new(100, 110);
+    assert!(r.contains(100));
+    assert!(r.contains(109));
+    assert!(!r.contains(110));
+    assert!(!r.contains(99));
+}
+
+#[test]
+fn test_packed_range_ord() {
+    let a = PackedRange::<u32, 24>::new(5, 30);
+    let b = PackedRange::<u32, 24>::new(10, 15);
+    let c = PackedRange::<u32, 24>::new(10, 20);
+    assert!(a < b);
+    assert!(b < c);
+}
+
+#[test]
+fn test_packed_range_small_range_conversion() {
+    let small = SmallRange::<u32>::new(100, 110);
+    let packed = PackedRange::<u32, 24>::from_small_range(small).unwrap();
+    assert_eq!(packed.to_small_range(), Some(small));
+
+    // Start too large for a 50/50 split
+    let far = PackedRange::<u32, 24>::new(1_000_000, 1_000_010);
+    assert_eq!(far.to_small_range(), None);
+
+    // Length too large for 4 length bits
+    let long = SmallRange::<u32>::new(100, 120);
+    assert_eq!(PackedRange::<u32, 28>::from_small_range(long), None);
+}
+
+#[test]
+fn test_packed_range_debug() {
+    let r = PackedRange::<u32, 24>::new(10, 20);
+    assert_eq!(
+        std::format!("{:?}", r),
+        "PackedRange { start: 10, end: 20 }"
+    );
+}
+
+// =============================================================================
+// SmallSpan Tests
+// =============================================================================
+
+#[test]
+fn test_small_span() {
+    use crate::SmallSpan;
+
+    assert_eq!(size_of::<SmallSpan>(), 8);
+    assert_eq!(size_of::<Option<SmallSpan>>(), 8);
+    assert_eq!(SmallSpan::max_start(), (1 << 48) - 2);
+    assert_eq!(SmallSpan::max_len(), 65534);
+
+    // Past SmallRange<u64>'s start limit
+    let span = SmallSpan::new(6_000_000_000, 6_000_000_512);
+    assert_eq!(span.start(), 6_000_000_000);
+    assert_eq!(span.len(), 512);
+    assert!(SmallRange::<u64>::try_new(6_000_000_000, 6_000_000_512).is_none());
+
+    assert!(SmallSpan::try_new(0, 65535).is_none());
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "length exceeds LEN_BITS capacity")]
+fn test_packed_range_new_panics_on_length_overflow() {
+    let _ = PackedRange::<u16, 12>::new(0, 15);
+}
+
+// =============================================================================
+// Property-Based Tests
+// =============================================================================
+
+mod proptest_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn roundtrip_u64_40(start in 0u64..(1 << 40) - 1, len in 0u64..(1 << 24) - 1) {
+            let r = PackedRange::<u64, 40>::from_start_len(start, len);
+            prop_assert_eq!(r.start(), start);
+            prop_assert_eq!(r.len() as u64, len);
+            prop_assert_eq!(r.end(), start + len);
+        }
+
+        #[test]
+        fn ord_matches_start_end_tuple(
+            s1 in 0u32..1000, l1 in 0u32..200,
+            s2 in 0u32..1000, l2 in 0u32..200,
+        ) {
+            let a = PackedRange::<u32, 24>::from_start_len(s1, l1);
+            let b = PackedRange::<u32, 24>::from_start_len(s2, l2);
+            prop_assert_eq!(a.cmp(&b), (a.start(), a.end()).cmp(&(b.start(), b.end())));
+        }
+    }
+}
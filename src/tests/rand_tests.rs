@@ -0,0 +1,51 @@
+use crate::SmallRange;
+use rand::distr::uniform::SampleRange;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+#[test]
+fn test_rand_sample_in_range() {
+    let mut rng = SmallRng::seed_from_u64(42);
+    let range = SmallRange::<u64>::new(1_000, 1_010);
+    for _ in 0..1_000 {
+        let value = range.sample(&mut rng).unwrap();
+        assert!(range.contains(value));
+    }
+}
+
+#[test]
+fn test_rand_sample_covers_range() {
+    let mut rng = SmallRng::seed_from_u64(7);
+    let range = SmallRange::<u8>::new(3, 7);
+    let mut seen = [false; 4];
+    for _ in 0..200 {
+        seen[usize::from(range.sample(&mut rng).unwrap() - 3)] = true;
+    }
+    assert_eq!(seen, [true; 4]);
+}
+
+#[test]
+fn test_rand_sample_empty() {
+    let mut rng = SmallRng::seed_from_u64(1);
+    let range = SmallRange::<u32>::new(5, 5);
+    assert_eq!(range.sample(&mut rng), None);
+    assert!(SampleRange::is_empty(&range));
+    assert!(range.sample_single(&mut rng).is_err());
+}
+
+#[test]
+fn test_rand_random_range_matches_range() {
+    let range = SmallRange::<u32>::new(10, 1_000);
+    let mut a = SmallRng::seed_from_u64(99);
+    let mut b = SmallRng::seed_from_u64(99);
+    for _ in 0..100 {
+        assert_eq!(a.random_range(range), b.random_range(range.to_range()));
+    }
+}
+
+#[test]
+#[should_panic(expected = "cannot sample empty range")]
+fn test_rand_random_range_empty_panics() {
+    let mut rng = SmallRng::seed_from_u64(1);
+    let _ = rng.random_range(SmallRange::<u32>::new(5, 5));
+}
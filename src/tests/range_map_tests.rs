@@ -0,0 +1,355 @@
+extern crate alloc;
+extern crate std;
+
+use crate::{RangeMap, SmallRange, SmallRangeError};
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+
+fn entries<V: Copy>(map: &RangeMap<u32, V>) -> Vec<(u32, u32, V)> {
+    map.iter()
+        .map(|(range, &value)| (range.start(), range.end(), value))
+        .collect()
+}
+
+// =============================================================================
+// Lookup Tests
+// =============================================================================
+
+#[test]
+fn test_get() {
+    let mut map = RangeMap::<u32, char>::new();
+    assert!(map.is_empty());
+    assert_eq!(map.get(0), None);
+
+    map.insert(SmallRange::new(10, 20), 'a').unwrap();
+    map.insert(SmallRange::new(30, 40), 'b').unwrap();
+    for (point, expected) in [
+        (9, None),
+        (10, Some('a')),
+        (19, Some('a')),
+        (20, None),
+        (30, Some('b')),
+        (40, None),
+    ] {
+        assert_eq!(map.get(point).copied(), expected, "point {point}");
+        assert_eq!(map.contains(point), expected.is_some(), "point {point}");
+    }
+    assert_eq!(map.get_key_value(35), Some((SmallRange::new(30, 40), &'b')));
+}
+
+#[test]
+fn test_get_mut() {
+    let mut map = RangeMap::<u32, u32>::new();
+    map.insert(SmallRange::new(0, 10), 1).unwrap();
+    *map.get_mut(5).unwrap() += 1;
+    assert_eq!(map.get(0), Some(&2));
+    assert_eq!(map.get_mut(10), None);
+}
+
+#[test]
+fn test_overlapping() {
+    let map: RangeMap<u32, char> = [
+        (SmallRange::new(0, 10), 'a'),
+        (SmallRange::new(10, 20), 'b'),
+        (SmallRange::new(30, 40), 'c'),
+        (SmallRange::new(50, 60), 'd'),
+    ]
+    .into_iter()
+    .collect();
+    let hits = |start, end| -> Vec<char> {
+        map.overlapping(SmallRange::new(start, end))
+            .map(|(_, &value)| value)
+            .collect()
+    };
+
+    assert_eq!(hits(0, 100), vec!['a', 'b', 'c', 'd']);
+    assert_eq!(hits(5, 35), vec!['a', 'b', 'c']);
+    assert_eq!(hits(19, 31), vec!['b', 'c']);
+    // Touching isn't overlapping
+    assert_eq!(hits(20, 30), Vec::<char>::new());
+    assert_eq!(hits(40, 50), Vec::<char>::new());
+    // Empty queries overlap nothing, even inside an entry
+    assert_eq!(hits(5, 5), Vec::<char>::new());
+    assert_eq!(hits(60, 70), Vec::<char>::new());
+
+    let query = SmallRange::new(15, 55);
+    assert_eq!(map.overlapping(query).len(), 3);
+    assert_eq!(
+        map.overlapping(query).next_back(),
+        Some((SmallRange::new(50, 60), &'d'))
+    );
+    assert_eq!(
+        RangeMap::<u32, char>::new()
+            .overlapping(SmallRange::new(0, 10))
+            .next(),
+        None
+    );
+}
+
+// =============================================================================
+// Insertion Tests
+// =============================================================================
+
+#[test]
+fn test_insert_disjoint_keeps_order() {
+    let mut map = RangeMap::<u32, char>::new();
+    map.insert(SmallRange::new(30, 40), 'c').unwrap();
+    map.insert(SmallRange::new(0, 10), 'a').unwrap();
+    map.insert(SmallRange::new(10, 20), 'b').unwrap();
+    assert_eq!(
+        entries(&map),
+        vec![(0, 10, 'a'), (10, 20, 'b'), (30, 40, 'c')]
+    );
+}
+
+#[test]
+fn test_insert_trims_and_splits() {
+    let mut map = RangeMap::<u32, char>::new();
+    map.insert(SmallRange::new(0, 100), 'a').unwrap();
+
+    // Splits the entry it lands inside
+    map.insert(SmallRange::new(40, 60), 'b').unwrap();
+    assert_eq!(
+        entries(&map),
+        vec![(0, 40, 'a'), (40, 60, 'b'), (60, 100, 'a')]
+    );
+
+    // Trims the entries on both sides and replaces the one it covers
+    map.insert(SmallRange::new(30, 70), 'c').unwrap();
+    assert_eq!(
+        entries(&map),
+        vec![(0, 30, 'a'), (30, 70, 'c'), (70, 100, 'a')]
+    );
+
+    // Overwrites an exact match
+    map.insert(SmallRange::new(30, 70), 'd').unwrap();
+    assert_eq!(
+        entries(&map),
+        vec![(0, 30, 'a'), (30, 70, 'd'), (70, 100, 'a')]
+    );
+
+    // Covers everything
+    map.insert(SmallRange::new(0, 100), 'e').unwrap();
+    assert_eq!(entries(&map), vec![(0, 100, 'e')]);
+}
+
+#[test]
+fn test_insert_keeps_touching_entries_separate() {
+    let mut map = RangeMap::<u32, char>::new();
+    map.insert(SmallRange::new(0, 10), 'a').unwrap();
+    map.insert(SmallRange::new(10, 20), 'a').unwrap();
+    assert_eq!(map.len(), 2);
+}
+
+#[test]
+fn test_eq_compares_entries() {
+    // Same mapping, different entries
+    let mut whole = RangeMap::<u32, char>::new();
+    whole.insert(SmallRange::new(0, 10), 'a').unwrap();
+    let mut halves = RangeMap::<u32, char>::new();
+    halves.insert(SmallRange::new(0, 5), 'a').unwrap();
+    halves.insert(SmallRange::new(5, 10), 'a').unwrap();
+    assert!((0..10).all(|value| whole.get(value) == halves.get(value)));
+    assert_ne!(whole, halves);
+
+    // Same entries, whatever order they were inserted in
+    let mut reversed = RangeMap::<u32, char>::new();
+    reversed.insert(SmallRange::new(5, 10), 'a').unwrap();
+    reversed.insert(SmallRange::new(0, 5), 'a').unwrap();
+    assert_eq!(halves, reversed);
+}
+
+#[test]
+fn test_insert_empty_range_does_nothing() {
+    let mut map = RangeMap::<u32, char>::new();
+    map.insert(SmallRange::new(0, 10), 'a').unwrap();
+    map.insert(SmallRange::new(5, 5), 'b').unwrap();
+    assert_eq!(entries(&map), vec![(0, 10, 'a')]);
+}
+
+// =============================================================================
+// Removal Tests
+// =============================================================================
+
+#[test]
+fn test_remove() {
+    let mut map: RangeMap<u32, char> = [
+        (SmallRange::new(0, 10), 'a'),
+        (SmallRange::new(10, 20), 'b'),
+        (SmallRange::new(30, 40), 'c'),
+    ]
+    .into_iter()
+    .collect();
+
+    assert_eq!(map.remove(SmallRange::new(20, 30)), Ok(false));
+    assert_eq!(map.remove(SmallRange::new(5, 5)), Ok(false));
+    assert_eq!(map.remove(SmallRange::new(5, 35)), Ok(true));
+    assert_eq!(entries(&map), vec![(0, 5, 'a'), (35, 40, 'c')]);
+    assert_eq!(map.remove(SmallRange::new(37, 38)), Ok(true));
+    assert_eq!(
+        entries(&map),
+        vec![(0, 5, 'a'), (35, 37, 'c'), (38, 40, 'c')]
+    );
+    assert_eq!(map.remove(SmallRange::new(0, 100)), Ok(true));
+    assert!(map.is_empty());
+}
+
+#[test]
+fn test_rejects_unrepresentable_piece() {
+    // 20..28 would remain of the old entry, but u8 starts max out at 14
+    let mut map = RangeMap::<u8, char>::new();
+    map.insert(SmallRange::new(14, 28), 'a').unwrap();
+    let before = map.clone();
+    assert_eq!(
+        map.insert(SmallRange::new(14, 20), 'b'),
+        Err(SmallRangeError::StartTooLarge)
+    );
+    assert_eq!(
+        map.remove(SmallRange::new(10, 20)),
+        Err(SmallRangeError::StartTooLarge)
+    );
+    assert_eq!(map, before);
+
+    // Cutting at 14 is fine
+    let mut map = RangeMap::<u8, char>::new();
+    map.insert(SmallRange::new(10, 24), 'a').unwrap();
+    map.insert(SmallRange::new(10, 14), 'b').unwrap();
+    assert_eq!(map.remove(SmallRange::new(0, 14)), Ok(true));
+    assert_eq!(map.get_key_value(14), Some((SmallRange::new(14, 24), &'a')));
+    assert_eq!(map.len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "entry piece starts beyond the half-width capacity")]
+fn test_extend_panics_on_unrepresentable_piece() {
+    let mut map = RangeMap::<u16, char>::new();
+    map.extend([
+        (SmallRange::new(200, 400), 'a'),
+        (SmallRange::new(200, 300), 'b'),
+    ]);
+}
+
+// =============================================================================
+// Trait Tests
+// =============================================================================
+
+#[test]
+fn test_iteration_and_debug() {
+    let mut map = RangeMap::<u32, &str>::default();
+    map.extend([(SmallRange::new(5, 8), "b"), (SmallRange::new(1, 3), "a")]);
+    let by_ref: Vec<_> = (&map).into_iter().map(|(r, &v)| (r, v)).collect();
+    let owned: Vec<_> = map.clone().into_iter().collect();
+    assert_eq!(by_ref, owned);
+    assert_eq!(map.iter().len(), 2);
+    assert_eq!(map.iter().next_back(), Some((SmallRange::new(5, 8), &"b")));
+    assert_eq!(
+        format!("{map:?}"),
+        format!(
+            "{{{:?}: \"a\", {:?}: \"b\"}}",
+            SmallRange::<u32>::new(1, 3),
+            SmallRange::<u32>::new(5, 8)
+        )
+    );
+    map.clear();
+    assert!(map.is_empty());
+}
+
+// =============================================================================
+// Property-Based Tests
+// =============================================================================
+
+mod proptest_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // None removes, Some inserts. Ends stay at or below 255, so every
+    // trimmed piece starts within the u16 capacity of 254
+    fn op() -> impl Strategy<Value = (Option<u8>, u16, u16)> {
+        (proptest::option::of(0u8..4), 0u16..=254).prop_flat_map(|(value, start)| {
+            (Just(value), Just(start), start..=(start + 254).min(255))
+        })
+    }
+
+    // Like `op`, but ends reach the u16 `MAX_END` of 508
+    fn wide_op() -> impl Strategy<Value = (Option<u8>, u16, u16)> {
+        (proptest::option::of(0u8..4), 0u16..=254)
+            .prop_flat_map(|(value, start)| (Just(value), Just(start), start..=start + 254))
+    }
+
+    proptest! {
+        #[test]
+        fn matches_model(ops in proptest::collection::vec(op(), 0..40)) {
+            let mut map = RangeMap::<u16, u8>::new();
+            let mut model = [None; 256];
+            for (value, start, end) in ops {
+                let range = SmallRange::new(start, end);
+                let slots = &mut model[usize::from(start)..usize::from(end)];
+                match value {
+                    Some(value) => {
+                        slots.fill(Some(value));
+                        map.insert(range, value).unwrap();
+                    }
+                    None => {
+                        let changed = slots.iter().any(Option::is_some);
+                        slots.fill(None);
+                        prop_assert_eq!(map.remove(range), Ok(changed));
+                    }
+                }
+            }
+
+            for point in 0..256u16 {
+                prop_assert_eq!(map.get(point).copied(), model[usize::from(point)]);
+            }
+            let ranges: Vec<_> = map.iter().map(|(range, _)| range).collect();
+            for pair in ranges.windows(2) {
+                prop_assert!(pair[0].end() <= pair[1].start());
+            }
+            prop_assert!(ranges.iter().all(|range| !range.is_empty()));
+        }
+
+        #[test]
+        fn rejects_pieces_past_max_start(ops in proptest::collection::vec(wide_op(), 0..40)) {
+            let mut map = RangeMap::<u16, u8>::new();
+            let mut model = [None; 509];
+            for (value, start, end) in ops {
+                let range = SmallRange::new(start, end);
+                // Only a piece left after `range` can start past 254
+                let cut = !range.is_empty()
+                    && end > 254
+                    && map.get_key_value(end - 1).is_some_and(|(entry, _)| entry.end() > end);
+                let before = map.clone();
+                let result = match value {
+                    Some(value) => map.insert(range, value).map(|()| true),
+                    None => map.remove(range),
+                };
+                if cut {
+                    prop_assert_eq!(result, Err(SmallRangeError::StartTooLarge));
+                    prop_assert_eq!(&map, &before);
+                } else {
+                    prop_assert!(result.is_ok());
+                    model[usize::from(start)..usize::from(end)].fill(value);
+                }
+            }
+
+            for point in 0..509u16 {
+                prop_assert_eq!(map.get(point).copied(), model[usize::from(point)]);
+            }
+        }
+
+        #[test]
+        fn overlapping_matches_scan(
+            ops in proptest::collection::vec(op(), 0..20),
+            (_, start, end) in op(),
+        ) {
+            let mut map = RangeMap::<u16, u8>::new();
+            for (value, start, end) in ops {
+                map.insert(SmallRange::new(start, end), value.unwrap_or(0)).unwrap();
+            }
+            let query = SmallRange::new(start, end);
+            let expected: Vec<_> = map.iter().filter(|(range, _)| range.overlaps(&query)).collect();
+            let found: Vec<_> = map.overlapping(query).collect();
+            prop_assert_eq!(found, expected);
+        }
+    }
+}
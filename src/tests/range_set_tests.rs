@@ -0,0 +1,559 @@
+extern crate alloc;
+extern crate std;
+
+use crate::{collapse_consecutive, RangeSet, SmallRange, SmallRangeError};
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+
+fn ranges<T: crate::SmallRangeStorage>(set: &RangeSet<T>) -> Vec<SmallRange<T>>
+where
+    usize: num_traits::AsPrimitive<T>,
+{
+    set.iter().collect()
+}
+
+// =============================================================================
+// Insertion Tests
+// =============================================================================
+
+#[test]
+fn test_insert_keeps_ranges_sorted() {
+    let mut set = RangeSet::<u32>::new();
+    assert!(set.is_empty());
+    assert!(set.insert(SmallRange::new(50, 60)));
+    assert!(set.insert(SmallRange::new(10, 20)));
+    assert!(set.insert(SmallRange::new(30, 40)));
+    assert_eq!(
+        ranges(&set),
+        vec![
+            SmallRange::new(10, 20),
+            SmallRange::new(30, 40),
+            SmallRange::new(50, 60)
+        ]
+    );
+    assert_eq!(set.len(), 30);
+    assert_eq!(set.range_count(), 3);
+}
+
+#[test]
+fn test_insert_coalesces_overlapping_and_adjacent() {
+    let mut set: RangeSet<u32> = [SmallRange::new(10, 20), SmallRange::new(30, 40)]
+        .into_iter()
+        .collect();
+    // Touching on the left, overlapping on the right
+    assert!(set.insert(SmallRange::new(20, 32)));
+    assert_eq!(ranges(&set), vec![SmallRange::new(10, 40)]);
+
+    // Spanning several ranges at once
+    set.insert(SmallRange::new(50, 60));
+    set.insert(SmallRange::new(70, 80));
+    assert!(set.insert(SmallRange::new(5, 75)));
+    assert_eq!(ranges(&set), vec![SmallRange::new(5, 80)]);
+}
+
+#[test]
+fn test_insert_reports_whether_values_were_added() {
+    let mut set = RangeSet::<u32>::from(SmallRange::new(10, 20));
+    assert!(!set.insert(SmallRange::new(12, 18)));
+    assert!(!set.insert(SmallRange::new(10, 20)));
+    assert!(!set.insert(SmallRange::new(100, 100)));
+    assert!(set.insert(SmallRange::new(15, 21)));
+    assert_eq!(ranges(&set), vec![SmallRange::new(10, 21)]);
+}
+
+#[test]
+fn test_insert_splits_runs_longer_than_capacity() {
+    // u8 ranges hold at most 14 values, so a run of 18 takes two pieces,
+    // split from the end
+    let mut set = RangeSet::<u8>::new();
+    set.insert(SmallRange::new(14, 28));
+    set.insert(SmallRange::new(10, 14));
+    assert_eq!(
+        ranges(&set),
+        vec![SmallRange::new(10, 14), SmallRange::new(14, 28)]
+    );
+    assert_eq!(set.len(), 18);
+    assert!(set.contains_range(&SmallRange::new(12, 20)));
+
+    // Growing the run re-splits it
+    set.insert(SmallRange::new(6, 10));
+    assert_eq!(
+        ranges(&set),
+        vec![SmallRange::new(6, 14), SmallRange::new(14, 28)]
+    );
+    set.insert(SmallRange::new(0, 14));
+    assert_eq!(
+        ranges(&set),
+        vec![SmallRange::new(0, 14), SmallRange::new(14, 28)]
+    );
+}
+
+// =============================================================================
+// Removal Tests
+// =============================================================================
+
+#[test]
+fn test_remove_splits_ranges() {
+    let mut set = RangeSet::<u32>::from(SmallRange::new(0, 100));
+    assert_eq!(set.remove(SmallRange::new(40, 60)), Ok(true));
+    assert_eq!(
+        ranges(&set),
+        vec![SmallRange::new(0, 40), SmallRange::new(60, 100)]
+    );
+    assert_eq!(set.remove(SmallRange::new(30, 70)), Ok(true));
+    assert_eq!(
+        ranges(&set),
+        vec![SmallRange::new(0, 30), SmallRange::new(70, 100)]
+    );
+    assert_eq!(set.remove(SmallRange::new(0, 100)), Ok(true));
+    assert!(set.is_empty());
+}
+
+#[test]
+fn test_remove_reports_whether_values_were_removed() {
+    let mut set = RangeSet::<u32>::from(SmallRange::new(10, 20));
+    assert_eq!(set.remove(SmallRange::new(0, 10)), Ok(false));
+    assert_eq!(set.remove(SmallRange::new(20, 30)), Ok(false));
+    assert_eq!(set.remove(SmallRange::new(15, 15)), Ok(false));
+    assert_eq!(set.remove(SmallRange::new(19, 30)), Ok(true));
+    assert_eq!(ranges(&set), vec![SmallRange::new(10, 19)]);
+}
+
+#[test]
+fn test_remove_re_splits_long_runs() {
+    let mut set: RangeSet<u8> = [SmallRange::new(0, 14), SmallRange::new(14, 28)]
+        .into_iter()
+        .collect();
+    set.remove(SmallRange::new(0, 6)).unwrap();
+    assert_eq!(
+        ranges(&set),
+        vec![SmallRange::new(6, 14), SmallRange::new(14, 28)]
+    );
+    set.remove(SmallRange::new(8, 10)).unwrap();
+    assert_eq!(
+        ranges(&set),
+        vec![
+            SmallRange::new(6, 8),
+            SmallRange::new(10, 14),
+            SmallRange::new(14, 28)
+        ]
+    );
+}
+
+#[test]
+fn test_remove_rejects_unrepresentable_piece() {
+    // 22..28 would remain, but u8 starts max out at 14
+    let mut set = RangeSet::<u8>::from(SmallRange::new(14, 28));
+    assert_eq!(
+        set.remove(SmallRange::new(14, 22)),
+        Err(SmallRangeError::StartTooLarge)
+    );
+    assert_eq!(ranges(&set), vec![SmallRange::new(14, 28)]);
+
+    // Leaving 14..28 is fine
+    let mut set: RangeSet<u8> = [SmallRange::new(0, 14), SmallRange::new(14, 28)]
+        .into_iter()
+        .collect();
+    assert_eq!(set.remove(SmallRange::new(10, 14)), Ok(true));
+    assert_eq!(
+        ranges(&set),
+        vec![SmallRange::new(0, 10), SmallRange::new(14, 28)]
+    );
+
+    // u16 starts max out at 254
+    let mut set = RangeSet::<u16>::from(SmallRange::new(100, 354));
+    assert_eq!(
+        set.remove(SmallRange::new(250, 300)),
+        Err(SmallRangeError::StartTooLarge)
+    );
+    assert_eq!(set.remove(SmallRange::new(250, 254)), Ok(true));
+    assert_eq!(
+        ranges(&set),
+        vec![SmallRange::new(100, 250), SmallRange::new(254, 354)]
+    );
+}
+
+// =============================================================================
+// Query Tests
+// =============================================================================
+
+#[test]
+fn test_contains() {
+    let set: RangeSet<u32> = [SmallRange::new(10, 20), SmallRange::new(30, 40)]
+        .into_iter()
+        .collect();
+    for (value, expected) in [(9, false), (10, true), (19, true), (20, false), (35, true)] {
+        assert_eq!(set.contains(value), expected, "value {value}");
+    }
+    assert!(!RangeSet::<u32>::new().contains(0));
+}
+
+#[test]
+fn test_contains_range() {
+    let set: RangeSet<u32> = [SmallRange::new(10, 20), SmallRange::new(30, 40)]
+        .into_iter()
+        .collect();
+    assert!(set.contains_range(&SmallRange::new(10, 20)));
+    assert!(set.contains_range(&SmallRange::new(32, 35)));
+    assert!(set.contains_range(&SmallRange::new(100, 100)));
+    assert!(!set.contains_range(&SmallRange::new(15, 35)));
+    assert!(!set.contains_range(&SmallRange::new(5, 15)));
+    assert!(!set.contains_range(&SmallRange::new(38, 41)));
+    assert!(!RangeSet::<u32>::new().contains_range(&SmallRange::new(0, 1)));
+}
+
+// =============================================================================
+// Trait Tests
+// =============================================================================
+
+#[test]
+fn test_equality_ignores_insertion_order() {
+    let a: RangeSet<u8> = [SmallRange::new(0, 14), SmallRange::new(14, 28)]
+        .into_iter()
+        .collect();
+    let b: RangeSet<u8> = [
+        SmallRange::new(14, 28),
+        SmallRange::new(0, 5),
+        SmallRange::new(5, 14),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(a, b);
+}
+
+#[test]
+fn test_iteration_and_debug() {
+    let mut set = RangeSet::<u32>::new();
+    set.extend([SmallRange::new(5, 8), SmallRange::new(1, 3)]);
+    let by_ref: Vec<_> = (&set).into_iter().collect();
+    let owned: Vec<_> = set.clone().into_iter().collect();
+    assert_eq!(by_ref, owned);
+    assert_eq!(
+        format!("{set:?}"),
+        format!(
+            "{{{:?}, {:?}}}",
+            SmallRange::<u32>::new(1, 3),
+            SmallRange::<u32>::new(5, 8)
+        )
+    );
+    set.clear();
+    assert!(set.is_empty());
+}
+
+// =============================================================================
+// Set Algebra Tests
+// =============================================================================
+
+fn set(ranges: &[(u32, u32)]) -> RangeSet<u32> {
+    ranges
+        .iter()
+        .map(|&(start, end)| SmallRange::new(start, end))
+        .collect()
+}
+
+#[test]
+fn test_union() {
+    let a = set(&[(0, 10), (20, 30), (50, 60)]);
+    let b = set(&[(5, 20), (40, 45)]);
+    let expected = set(&[(0, 30), (40, 45), (50, 60)]);
+    assert_eq!(a.union(&b), expected);
+    assert_eq!(&a | &b, expected);
+    assert_eq!(b.union(&a), expected);
+    assert_eq!(a.union(&RangeSet::new()), a);
+}
+
+#[test]
+fn test_intersection() {
+    let a = set(&[(0, 10), (20, 30), (50, 60)]);
+    let b = set(&[(5, 25), (28, 55)]);
+    let expected = set(&[(5, 10), (20, 25), (28, 30), (50, 55)]);
+    assert_eq!(a.intersection(&b), expected);
+    assert_eq!(&a & &b, expected);
+    assert_eq!(b.intersection(&a), expected);
+    assert!(a.intersection(&set(&[(10, 20)])).is_empty());
+}
+
+#[test]
+fn test_difference() {
+    let a = set(&[(0, 10), (20, 30)]);
+    let b = set(&[(5, 22), (25, 26)]);
+    let expected = set(&[(0, 5), (22, 25), (26, 30)]);
+    assert_eq!(a.difference(&b).unwrap(), expected);
+    assert_eq!(b.difference(&a).unwrap(), set(&[(10, 20)]));
+    assert_eq!(a.difference(&RangeSet::new()).unwrap(), a);
+    assert!(a.difference(&a).unwrap().is_empty());
+}
+
+#[test]
+fn test_symmetric_difference() {
+    let a = set(&[(0, 10), (20, 30)]);
+    let b = set(&[(10, 25)]);
+    // 0..10 and 10..20 touch, so they merge
+    let expected = set(&[(0, 20), (25, 30)]);
+    assert_eq!(a.symmetric_difference(&b).unwrap(), expected);
+    assert!(a.symmetric_difference(&a).unwrap().is_empty());
+}
+
+#[test]
+fn test_assigning_operators() {
+    let a = set(&[(0, 10), (20, 30)]);
+    let b = set(&[(5, 25)]);
+
+    let mut c = a.clone();
+    c |= &b;
+    assert_eq!(c, a.union(&b));
+    c = a.clone();
+    c &= &b;
+    assert_eq!(c, a.intersection(&b));
+}
+
+#[test]
+fn test_algebra_rejoins_long_runs() {
+    // u8 ranges hold at most 14 values, so the union is re-split from its end
+    let a = RangeSet::<u8>::from(SmallRange::new(0, 10));
+    let b = RangeSet::<u8>::from(SmallRange::new(10, 20));
+    let union = &a | &b;
+    assert_eq!(
+        ranges(&union),
+        vec![SmallRange::new(0, 6), SmallRange::new(6, 20)]
+    );
+    let trimmed = union
+        .difference(&RangeSet::from(SmallRange::new(0, 4)))
+        .unwrap();
+    assert_eq!(
+        ranges(&trimmed),
+        vec![SmallRange::new(4, 6), SmallRange::new(6, 20)]
+    );
+}
+
+#[test]
+fn test_algebra_at_capacity() {
+    // u8 starts max out at 14, so 20..28 can't stand on its own
+    let whole: RangeSet<u8> = [SmallRange::new(0, 14), SmallRange::new(14, 28)]
+        .into_iter()
+        .collect();
+    let head: RangeSet<u8> = [SmallRange::new(0, 6), SmallRange::new(6, 20)]
+        .into_iter()
+        .collect();
+    assert_eq!(whole.difference(&head), Err(SmallRangeError::StartTooLarge));
+    assert_eq!(
+        whole.symmetric_difference(&head),
+        Err(SmallRangeError::StartTooLarge)
+    );
+    // Union and intersection runs start where an operand's run starts
+    assert_eq!(&whole | &head, whole);
+    assert_eq!(&whole & &head, head);
+
+    // Cutting at 14 leaves a storable run
+    let front = RangeSet::<u8>::from(SmallRange::new(0, 14));
+    let back = RangeSet::<u8>::from(SmallRange::new(14, 28));
+    assert_eq!(whole.difference(&front), Ok(back.clone()));
+    assert_eq!(whole.symmetric_difference(&front), Ok(back));
+}
+
+// =============================================================================
+// Gap Tests
+// =============================================================================
+
+#[test]
+fn test_gaps() {
+    let a = set(&[(10, 20), (30, 40), (50, 60)]);
+    let gaps: Vec<_> = a
+        .gaps(SmallRange::new(0, 100))
+        .map(Result::unwrap)
+        .collect();
+    assert_eq!(
+        gaps,
+        vec![
+            SmallRange::new(0, 10),
+            SmallRange::new(20, 30),
+            SmallRange::new(40, 50),
+            SmallRange::new(60, 100)
+        ]
+    );
+
+    // The window clips the gaps at both ends
+    let gaps: Vec<_> = a
+        .gaps(SmallRange::new(15, 55))
+        .map(Result::unwrap)
+        .collect();
+    assert_eq!(gaps, vec![SmallRange::new(20, 30), SmallRange::new(40, 50)]);
+    let gaps: Vec<_> = a
+        .gaps(SmallRange::new(22, 28))
+        .map(Result::unwrap)
+        .collect();
+    assert_eq!(gaps, vec![SmallRange::new(22, 28)]);
+}
+
+#[test]
+fn test_gaps_none() {
+    let a = set(&[(10, 20)]);
+    assert_eq!(a.gaps(SmallRange::new(12, 18)).next(), None);
+    assert_eq!(a.gaps(SmallRange::new(10, 20)).next(), None);
+    assert_eq!(a.gaps(SmallRange::new(5, 5)).next(), None);
+
+    let everything: Vec<_> = RangeSet::<u32>::new()
+        .gaps(SmallRange::new(3, 7))
+        .map(Result::unwrap)
+        .collect();
+    assert_eq!(everything, vec![SmallRange::new(3, 7)]);
+}
+
+#[test]
+fn test_gaps_at_capacity() {
+    // u8 windows end at most at 28
+    let gaps: Vec<_> = RangeSet::<u8>::new()
+        .gaps(SmallRange::new(14, 28))
+        .map(Result::unwrap)
+        .collect();
+    assert_eq!(gaps, vec![SmallRange::new(14, 28)]);
+    let a = RangeSet::<u8>::from(SmallRange::new(14, 28));
+    let gaps: Vec<_> = a.gaps(SmallRange::new(0, 14)).map(Result::unwrap).collect();
+    assert_eq!(gaps, vec![SmallRange::new(0, 14)]);
+}
+
+#[test]
+fn test_gaps_past_max_start() {
+    // u8 starts max out at 14, so a gap after a run ending at 20 can't be
+    // stored
+    let a: RangeSet<u8> = [SmallRange::new(0, 6), SmallRange::new(6, 20)]
+        .into_iter()
+        .collect();
+    let gaps: Vec<_> = a.gaps(SmallRange::new(14, 28)).collect();
+    assert_eq!(gaps, vec![Err(SmallRangeError::StartTooLarge)]);
+    assert_eq!(
+        a.complement(SmallRange::new(14, 28)),
+        Err(SmallRangeError::StartTooLarge)
+    );
+
+    // A gap starting at 14 still fits
+    let b = RangeSet::<u8>::from(SmallRange::new(0, 14));
+    assert_eq!(
+        b.complement(SmallRange::new(10, 24)),
+        Ok(RangeSet::from(SmallRange::new(14, 24)))
+    );
+
+    // u16 starts max out at 254
+    let c = RangeSet::<u16>::from(SmallRange::new(200, 300));
+    let gaps: Vec<_> = c.gaps(SmallRange::new(150, 400)).collect();
+    assert_eq!(
+        gaps,
+        vec![
+            Ok(SmallRange::new(150, 200)),
+            Err(SmallRangeError::StartTooLarge)
+        ]
+    );
+}
+
+#[test]
+fn test_complement() {
+    let a = set(&[(10, 20), (30, 40)]);
+    let window = SmallRange::new(0, 50);
+    let complement = a.complement(window).unwrap();
+    assert_eq!(complement, set(&[(0, 10), (20, 30), (40, 50)]));
+    assert_eq!(complement.complement(window), Ok(a.clone()));
+    assert!(a.complement(SmallRange::new(10, 20)).unwrap().is_empty());
+}
+
+// =============================================================================
+// Property-Based Tests
+// =============================================================================
+
+mod proptest_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // Ends stay at or below 255, so every remaining piece starts within the
+    // u16 capacity of 254, while runs can still outgrow the 254 length limit
+    fn op() -> impl Strategy<Value = (bool, u16, u16)> {
+        (any::<bool>(), 0u16..=254).prop_flat_map(|(insert, start)| {
+            (Just(insert), Just(start), start..=(start + 254).min(255))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn matches_model(ops in proptest::collection::vec(op(), 0..40)) {
+            let mut set = RangeSet::<u16>::new();
+            let mut model = [false; 256];
+            for (insert, start, end) in ops {
+                let range = SmallRange::new(start, end);
+                let slots = &mut model[usize::from(start)..usize::from(end)];
+                let changed = slots.iter().any(|&present| present != insert);
+                slots.fill(insert);
+                let reported = if insert { set.insert(range) } else { set.remove(range).unwrap() };
+                prop_assert_eq!(reported, changed);
+            }
+
+            for value in 0..256u16 {
+                prop_assert_eq!(set.contains(value), model[usize::from(value)]);
+            }
+            prop_assert_eq!(set.len(), model.iter().filter(|&&present| present).count());
+
+            // Pieces only touch inside runs too long for one range, where
+            // every piece after the first is full
+            let pieces = ranges(&set);
+            for pair in pieces.windows(2) {
+                prop_assert!(pair[0].end() <= pair[1].start());
+                if pair[0].end() == pair[1].start() {
+                    prop_assert_eq!(pair[1].len(), 254);
+                }
+            }
+            prop_assert!(pieces.iter().all(|range| !range.is_empty()));
+        }
+
+        #[test]
+        fn algebra_matches_model(
+            a in proptest::collection::vec(op(), 0..20),
+            b in proptest::collection::vec(op(), 0..20),
+        ) {
+            fn build(ops: &[(bool, u16, u16)]) -> (RangeSet<u16>, [bool; 256]) {
+                let mut model = [false; 256];
+                let set = ops
+                    .iter()
+                    .map(|&(_, start, end)| {
+                        model[usize::from(start)..usize::from(end)].fill(true);
+                        SmallRange::new(start, end)
+                    })
+                    .collect();
+                (set, model)
+            }
+            fn from_model(keep: impl Fn(usize) -> bool) -> RangeSet<u16> {
+                collapse_consecutive((0..256u16).filter(|&value| keep(usize::from(value))))
+                    .map(Result::unwrap)
+                    .collect()
+            }
+
+            let (a, in_a) = build(&a);
+            let (b, in_b) = build(&b);
+            prop_assert_eq!(a.union(&b), from_model(|v| in_a[v] || in_b[v]));
+            prop_assert_eq!(a.intersection(&b), from_model(|v| in_a[v] && in_b[v]));
+            prop_assert_eq!(a.difference(&b).unwrap(), from_model(|v| in_a[v] && !in_b[v]));
+            prop_assert_eq!(a.symmetric_difference(&b).unwrap(), from_model(|v| in_a[v] != in_b[v]));
+        }
+
+        #[test]
+        fn gaps_match_model(
+            ops in proptest::collection::vec(op(), 0..20),
+            window in op(),
+        ) {
+            let mut model = [false; 256];
+            let set: RangeSet<u16> = ops
+                .iter()
+                .map(|&(_, start, end)| {
+                    model[usize::from(start)..usize::from(end)].fill(true);
+                    SmallRange::new(start, end)
+                })
+                .collect();
+            let (_, start, end) = window;
+            let gaps: Vec<_> = set.gaps(SmallRange::new(start, end)).map(Result::unwrap).collect();
+
+            let expected: Vec<_> =
+                collapse_consecutive((start..end).filter(|&value| !model[usize::from(value)]))
+                    .map(Result::unwrap)
+                    .collect();
+            prop_assert_eq!(gaps, expected);
+        }
+    }
+}
@@ -0,0 +1,54 @@
+extern crate std;
+
+use crate::SmallRange;
+use rayon::prelude::*;
+use std::vec::Vec;
+
+#[test]
+fn test_par_iter_sum() {
+    let range = SmallRange::<u64>::new(1, 100_001);
+    assert_eq!(
+        range.into_par_iter().sum::<u64>(),
+        range.into_iter().sum::<u64>()
+    );
+}
+
+#[test]
+fn test_par_iter_preserves_order() {
+    let range = SmallRange::<u32>::new(500, 20_000);
+    let collected: Vec<u32> = range.into_par_iter().collect();
+    assert_eq!(collected, range.into_iter().collect::<Vec<_>>());
+
+    let reversed: Vec<u32> = (&range).into_par_iter().rev().collect();
+    assert_eq!(reversed, range.into_iter().rev().collect::<Vec<_>>());
+}
+
+#[test]
+fn test_par_iter_indexed() {
+    let range = SmallRange::<u16>::new(10, 200);
+    let iter = range.into_par_iter();
+    assert_eq!(iter.len(), 190);
+    assert_eq!(iter.opt_len(), Some(190));
+
+    let zipped: Vec<(u16, usize)> = range
+        .into_par_iter()
+        .zip((0..190usize).into_par_iter())
+        .collect();
+    assert!(zipped
+        .iter()
+        .all(|&(value, i)| usize::from(value) == 10 + i));
+}
+
+#[test]
+fn test_par_iter_small_chunks() {
+    // Forces many splits, including ones that produce empty halves
+    let range = SmallRange::<u8>::new(SmallRange::<u8>::MAX_START, SmallRange::<u8>::MAX_END);
+    let collected: Vec<u8> = range.into_par_iter().with_max_len(1).collect();
+    assert_eq!(collected, range.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn test_par_iter_empty() {
+    let range = SmallRange::<u64>::new(7, 7);
+    assert_eq!(range.into_par_iter().count(), 0);
+}
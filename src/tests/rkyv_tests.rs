@@ -0,0 +1,96 @@
+extern crate std;
+
+use crate::{ArchivedSmallRange, SmallRange};
+use rkyv::rancor::Error;
+
+// =============================================================================
+// Round-Trip Tests
+// =============================================================================
+
+#[test]
+fn test_rkyv_roundtrip() {
+    let range = SmallRange::<u32>::new(10, 20);
+    let bytes = rkyv::to_bytes::<Error>(&range).unwrap();
+    let archived = rkyv::access::<ArchivedSmallRange<u32>, Error>(&bytes).unwrap();
+    assert_eq!(*archived, range);
+    assert_eq!(archived.start(), 10);
+    assert_eq!(archived.end(), 20);
+    assert_eq!(archived.len(), 10);
+    assert!(!archived.is_empty());
+    assert_eq!(
+        rkyv::deserialize::<SmallRange<u32>, Error>(archived).unwrap(),
+        range
+    );
+}
+
+#[test]
+fn test_rkyv_roundtrip_all_widths() {
+    let bytes = rkyv::to_bytes::<Error>(&SmallRange::<u8>::new(3, 7)).unwrap();
+    let archived = rkyv::access::<ArchivedSmallRange<u8>, Error>(&bytes).unwrap();
+    assert_eq!(archived.to_native(), SmallRange::new(3, 7));
+
+    let bytes = rkyv::to_bytes::<Error>(&SmallRange::<u16>::new(0, 0)).unwrap();
+    let archived = rkyv::access::<ArchivedSmallRange<u16>, Error>(&bytes).unwrap();
+    assert_eq!(archived.to_native(), SmallRange::new(0, 0));
+
+    let range = SmallRange::<u64>::new(1 << 30, (1 << 30) + 5);
+    let bytes = rkyv::to_bytes::<Error>(&range).unwrap();
+    let archived = rkyv::access::<ArchivedSmallRange<u64>, Error>(&bytes).unwrap();
+    assert_eq!(archived.to_native(), range);
+}
+
+#[test]
+fn test_rkyv_archived_is_packed_bits() {
+    let range = SmallRange::<u32>::new(10, 20);
+    let bytes = rkyv::to_bytes::<Error>(&range).unwrap();
+    assert_eq!(bytes.len(), 4);
+    assert_eq!(&bytes[..], &range.to_bits().get().to_le_bytes());
+    assert_eq!(core::mem::size_of::<Option<ArchivedSmallRange<u32>>>(), 4);
+}
+
+#[test]
+fn test_rkyv_debug() {
+    let bytes = rkyv::to_bytes::<Error>(&SmallRange::<u16>::new(1, 4)).unwrap();
+    let archived = rkyv::access::<ArchivedSmallRange<u16>, Error>(&bytes).unwrap();
+    assert_eq!(
+        std::format!("{:?}", archived),
+        "SmallRange { start: 1, end: 4 }"
+    );
+}
+
+// =============================================================================
+// Validation Tests
+// =============================================================================
+
+#[test]
+fn test_rkyv_rejects_invalid_encoding() {
+    let mut bytes = rkyv::util::AlignedVec::<4>::new();
+
+    // Zero bits
+    bytes.extend_from_slice(&0u32.to_le_bytes());
+    assert!(rkyv::access::<ArchivedSmallRange<u32>, Error>(&bytes).is_err());
+
+    // Zero length half
+    bytes.clear();
+    bytes.extend_from_slice(&(11u32 << 16).to_le_bytes());
+    assert!(rkyv::access::<ArchivedSmallRange<u32>, Error>(&bytes).is_err());
+
+    // Zero start half
+    bytes.clear();
+    bytes.extend_from_slice(&11u32.to_le_bytes());
+    assert!(rkyv::access::<ArchivedSmallRange<u32>, Error>(&bytes).is_err());
+
+    // A valid encoding still passes
+    bytes.clear();
+    bytes.extend_from_slice(&((11u32 << 16) | 11).to_le_bytes());
+    let archived = rkyv::access::<ArchivedSmallRange<u32>, Error>(&bytes).unwrap();
+    assert_eq!(archived.to_native(), SmallRange::new(10, 20));
+}
+
+#[test]
+fn test_rkyv_rejects_invalid_u8_encoding() {
+    let bytes = [0xF0u8];
+    assert!(rkyv::access::<ArchivedSmallRange<u8>, Error>(&bytes).is_err());
+    let bytes = [0x0Fu8];
+    assert!(rkyv::access::<ArchivedSmallRange<u8>, Error>(&bytes).is_err());
+}
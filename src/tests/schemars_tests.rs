@@ -0,0 +1,65 @@
+use crate::SmallRange;
+use schemars::{json_schema, JsonSchema, SchemaGenerator};
+
+fn expected(max_start: u64, max_end: u64) -> schemars::Schema {
+    json_schema!({
+        "anyOf": [
+            {
+                "type": "string",
+                "pattern": "^[0-9]+\\.\\.=?[0-9]+$"
+            },
+            {
+                "type": "object",
+                "properties": {
+                    "start": { "type": "integer", "minimum": 0, "maximum": max_start },
+                    "end": { "type": "integer", "minimum": 0, "maximum": max_end }
+                },
+                "required": ["start", "end"],
+                "additionalProperties": false
+            },
+            {
+                "type": "array",
+                "prefixItems": [
+                    { "type": "integer", "minimum": 0, "maximum": max_start },
+                    { "type": "integer", "minimum": 0, "maximum": max_end }
+                ],
+                "minItems": 2,
+                "maxItems": 2
+            }
+        ]
+    })
+}
+
+#[test]
+fn test_schema_bounds_follow_storage() {
+    let mut generator = SchemaGenerator::default();
+    assert_eq!(
+        SmallRange::<u8>::json_schema(&mut generator),
+        expected(14, 28)
+    );
+    assert_eq!(
+        SmallRange::<u16>::json_schema(&mut generator),
+        expected(254, 508)
+    );
+    assert_eq!(
+        SmallRange::<u32>::json_schema(&mut generator),
+        expected(65_534, 131_068)
+    );
+    assert_eq!(
+        SmallRange::<u64>::json_schema(&mut generator),
+        expected(u32::MAX as u64 - 1, 2 * (u32::MAX as u64 - 1))
+    );
+}
+
+#[test]
+fn test_schema_names() {
+    assert_eq!(SmallRange::<u32>::schema_name(), "SmallRange_of_uint32");
+    assert_eq!(
+        SmallRange::<u32>::schema_id(),
+        "small_range::SmallRange<uint32>"
+    );
+    assert_ne!(
+        SmallRange::<u16>::schema_id(),
+        SmallRange::<u32>::schema_id()
+    );
+}
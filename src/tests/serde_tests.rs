@@ -0,0 +1,220 @@
+use crate::SmallRange;
+use serde_test::{
+    assert_de_tokens, assert_de_tokens_error, assert_tokens, Compact, Configure, Readable, Token,
+};
+
+fn readable<T>(value: T) -> Readable<T> {
+    value.readable()
+}
+
+fn compact<T>(value: T) -> Compact<T> {
+    value.compact()
+}
+
+// =============================================================================
+// Human-Readable Tests
+// =============================================================================
+
+#[test]
+fn test_serde_readable_roundtrip() {
+    assert_tokens(
+        &readable(SmallRange::<u32>::new(10, 20)),
+        &[Token::Str("10..20")],
+    );
+    assert_tokens(
+        &readable(SmallRange::<u8>::new(14, 28)),
+        &[Token::Str("14..28")],
+    );
+    assert_tokens(
+        &readable(SmallRange::<u64>::new(0, 0)),
+        &[Token::Str("0..0")],
+    );
+}
+
+#[test]
+fn test_serde_readable_accepts_struct_forms() {
+    let expected = readable(SmallRange::<u16>::new(3, 7));
+    assert_de_tokens(&expected, &[Token::Str("3..=6")]);
+    assert_de_tokens(
+        &expected,
+        &[
+            Token::Seq { len: Some(2) },
+            Token::U16(3),
+            Token::U16(7),
+            Token::SeqEnd,
+        ],
+    );
+    assert_de_tokens(
+        &expected,
+        &[
+            Token::Map { len: Some(2) },
+            Token::Str("end"),
+            Token::U16(7),
+            Token::Str("start"),
+            Token::U16(3),
+            Token::MapEnd,
+        ],
+    );
+}
+
+#[test]
+fn test_serde_readable_rejects_invalid_ranges() {
+    assert_de_tokens_error::<Readable<SmallRange<u32>>>(
+        &[Token::Str("20..10")],
+        "start must not exceed end",
+    );
+    assert_de_tokens_error::<Readable<SmallRange<u16>>>(
+        &[Token::Str("255..255")],
+        "start exceeds half-width capacity",
+    );
+    assert_de_tokens_error::<Readable<SmallRange<u16>>>(
+        &[Token::Str("0..255")],
+        "length exceeds half-width capacity",
+    );
+    assert_de_tokens_error::<Readable<SmallRange<u16>>>(
+        &[Token::Str("0-255")],
+        "missing `..` separator",
+    );
+    assert_de_tokens_error::<Readable<SmallRange<u32>>>(
+        &[
+            Token::Seq { len: Some(2) },
+            Token::U32(20),
+            Token::U32(10),
+            Token::SeqEnd,
+        ],
+        "start must not exceed end",
+    );
+}
+
+#[test]
+fn test_serde_readable_rejects_malformed_input() {
+    assert_de_tokens_error::<Readable<SmallRange<u32>>>(
+        &[
+            Token::Map { len: Some(1) },
+            Token::Str("start"),
+            Token::U32(1),
+            Token::MapEnd,
+        ],
+        "missing field `end`",
+    );
+    assert_de_tokens_error::<Readable<SmallRange<u32>>>(
+        &[Token::Map { len: Some(2) }, Token::Str("begin")],
+        "unknown field `begin`, expected `start` or `end`",
+    );
+    assert_de_tokens_error::<Readable<SmallRange<u32>>>(
+        &[Token::Seq { len: Some(1) }, Token::U32(1), Token::SeqEnd],
+        "invalid length 1, expected a range like \"10..20\" or a struct with start and end",
+    );
+}
+
+// =============================================================================
+// Compact Tests
+// =============================================================================
+
+#[test]
+fn test_serde_compact_roundtrip() {
+    assert_tokens(
+        &compact(SmallRange::<u32>::new(10, 20)),
+        &[Token::U32((11 << 16) | 11)],
+    );
+    assert_tokens(&compact(SmallRange::<u8>::new(0, 0)), &[Token::U8(0x11)]);
+    assert_tokens(
+        &compact(SmallRange::<u64>::new(5, 5)),
+        &[Token::U64((6 << 32) | 1)],
+    );
+}
+
+#[test]
+fn test_serde_compact_rejects_invalid_encoding() {
+    assert_de_tokens_error::<Compact<SmallRange<u32>>>(
+        &[Token::U32(0)],
+        "invalid SmallRange encoding",
+    );
+    assert_de_tokens_error::<Compact<SmallRange<u32>>>(
+        &[Token::U32(11 << 16)],
+        "invalid SmallRange encoding",
+    );
+    assert_de_tokens_error::<Compact<SmallRange<u16>>>(
+        &[Token::U16(0x00FF)],
+        "invalid SmallRange encoding",
+    );
+}
+
+// =============================================================================
+// RangeSet Tests
+// =============================================================================
+
+#[cfg(feature = "alloc")]
+fn range_set(ranges: &[(u32, u32)]) -> crate::RangeSet<u32> {
+    ranges
+        .iter()
+        .map(|&(start, end)| SmallRange::new(start, end))
+        .collect()
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_serde_range_set_roundtrip() {
+    let set = range_set(&[(0, 10), (20, 30)]);
+    assert_tokens(
+        &readable(set.clone()),
+        &[
+            Token::Seq { len: Some(2) },
+            Token::Str("0..10"),
+            Token::Str("20..30"),
+            Token::SeqEnd,
+        ],
+    );
+    assert_tokens(
+        &compact(set),
+        &[
+            Token::Seq { len: Some(2) },
+            Token::U32((1 << 16) | 11),
+            Token::U32((21 << 16) | 11),
+            Token::SeqEnd,
+        ],
+    );
+    assert_tokens(
+        &compact(crate::RangeSet::<u32>::new()),
+        &[Token::Seq { len: Some(0) }, Token::SeqEnd],
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_serde_range_set_joins_touching_ranges() {
+    assert_de_tokens(
+        &readable(range_set(&[(0, 20)])),
+        &[
+            Token::Seq { len: Some(2) },
+            Token::Str("0..10"),
+            Token::Str("10..20"),
+            Token::SeqEnd,
+        ],
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_serde_range_set_rejects_broken_invariant() {
+    let message = "ranges must be non-empty, sorted and non-overlapping";
+    for ranges in [["20..30", "0..10"], ["0..10", "5..15"], ["0..10", "12..12"]] {
+        assert_de_tokens_error::<Readable<crate::RangeSet<u32>>>(
+            &[
+                Token::Seq { len: Some(2) },
+                Token::Str(ranges[0]),
+                Token::Str(ranges[1]),
+                Token::SeqEnd,
+            ],
+            message,
+        );
+    }
+    assert_de_tokens_error::<Readable<crate::RangeSet<u32>>>(
+        &[
+            Token::Seq { len: Some(1) },
+            Token::Str("10..5"),
+            Token::SeqEnd,
+        ],
+        "start must not exceed end",
+    );
+}
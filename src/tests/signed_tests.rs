@@ -0,0 +1,129 @@
+extern crate std;
+
+use crate::SignedSmallRange;
+use core::mem::size_of;
+
+// =============================================================================
+// Layout Tests
+// =============================================================================
+
+#[test]
+fn test_signed_size() {
+    assert_eq!(size_of::<SignedSmallRange<i8>>(), 1);
+    assert_eq!(size_of::<Option<SignedSmallRange<i8>>>(), 1);
+    assert_eq!(size_of::<SignedSmallRange<i32>>(), 4);
+    assert_eq!(size_of::<Option<SignedSmallRange<i64>>>(), 8);
+}
+
+#[test]
+fn test_signed_capacity() {
+    assert_eq!(SignedSmallRange::<i8>::MIN_START, -7);
+    assert_eq!(SignedSmallRange::<i8>::MAX_START, 7);
+    assert_eq!(SignedSmallRange::<i8>::MAX_LEN, 14);
+    assert_eq!(SignedSmallRange::<i16>::MAX_START, 127);
+    assert_eq!(SignedSmallRange::<i32>::MIN_START, -32_767);
+    assert_eq!(
+        SignedSmallRange::<i64>::MAX_START,
+        (u32::MAX as i64 - 1) / 2
+    );
+}
+
+// =============================================================================
+// Construction and Accessor Tests
+// =============================================================================
+
+#[test]
+fn test_signed_roundtrip() {
+    let r = SignedSmallRange::<i32>::new(-100, -84);
+    assert_eq!(r.start(), -100);
+    assert_eq!(r.end(), -84);
+    assert_eq!(r.len(), 16);
+    assert!(!r.is_empty());
+    assert_eq!(r.to_range(), -100..-84);
+
+    let across = SignedSmallRange::<i16>::new(-10, 10);
+    assert_eq!(across.to_range(), -10..10);
+
+    let extreme = SignedSmallRange::<i8>::from_start_len(7, 14);
+    assert_eq!(extreme.to_range(), 7..21);
+    let lowest = SignedSmallRange::<i8>::from_start_len(-7, 0);
+    assert_eq!(lowest.to_range(), -7..-7);
+
+    let empty = SignedSmallRange::<i64>::default();
+    assert!(empty.is_empty());
+    assert_eq!(empty.to_range(), 0..0);
+}
+
+#[test]
+fn test_signed_try_new() {
+    assert!(SignedSmallRange::<i16>::try_new(-127, 127).is_some());
+    assert!(SignedSmallRange::<i16>::try_new(-128, 0).is_none());
+    assert!(SignedSmallRange::<i16>::try_new(128, 128).is_none());
+    assert!(SignedSmallRange::<i16>::try_new(0, 255).is_none());
+    assert!(SignedSmallRange::<i16>::try_new(10, -10).is_none());
+    assert!(SignedSmallRange::<i16>::try_new(-127, i16::MAX).is_none());
+    assert!(SignedSmallRange::<i16>::try_from_start_len(0, 255).is_none());
+}
+
+#[test]
+fn test_signed_contains() {
+    let r = SignedSmallRange::<i32>::new(-5, 5);
+    assert!(r.contains(-5));
+    assert!(r.contains(0));
+    assert!(r.contains(4));
+    assert!(!r.contains(5));
+    assert!(!r.contains(-6));
+}
+
+#[test]
+fn test_signed_ord() {
+    let a = SignedSmallRange::<i32>::new(-10, 30);
+    let b = SignedSmallRange::<i32>::new(-5, 0);
+    let c = SignedSmallRange::<i32>::new(-5, 3);
+    assert!(a < b);
+    assert!(b < c);
+}
+
+#[test]
+fn test_signed_debug() {
+    assert_eq!(
+        std::format!("{:?}", SignedSmallRange::<i32>::new(-2, 1)),
+        "SignedSmallRange { start: -2, end: 1 }"
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "start exceeds signed half-width capacity")]
+fn test_signed_start_overflow_panics() {
+    let _ = SignedSmallRange::<i16>::new(-128, 0);
+}
+
+// =============================================================================
+// Property-Based Tests
+// =============================================================================
+
+mod proptest_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn roundtrip(start in -32_767i32..=32_767, len in 0u32..=65_534) {
+            let r = SignedSmallRange::<i32>::from_start_len(start, len);
+            prop_assert_eq!(r.start(), start);
+            prop_assert_eq!(r.len(), len as usize);
+            prop_assert_eq!(r.end(), start + len as i32);
+        }
+
+        #[test]
+        fn ord_matches_tuple(
+            s1 in -127i16..=127, l1 in 0u16..=254,
+            s2 in -127i16..=127, l2 in 0u16..=254,
+        ) {
+            let a = SignedSmallRange::<i16>::from_start_len(s1, l1);
+            let b = SignedSmallRange::<i16>::from_start_len(s2, l2);
+            prop_assert_eq!(a.cmp(&b), (a.start(), a.end()).cmp(&(b.start(), b.end())));
+        }
+    }
+}
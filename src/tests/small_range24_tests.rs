@@ -0,0 +1,117 @@
+extern crate std;
+
+use crate::{SmallRange, SmallRange24};
+use core::mem::{align_of, size_of};
+
+// =============================================================================
+// Layout Tests
+// =============================================================================
+
+#[test]
+fn test_small_range24_layout() {
+    assert_eq!(size_of::<SmallRange24>(), 3);
+    assert_eq!(align_of::<SmallRange24>(), 1);
+    assert_eq!(size_of::<Option<SmallRange24>>(), 3);
+    assert_eq!(size_of::<[SmallRange24; 10]>(), 30);
+}
+
+// =============================================================================
+// Construction and Accessor Tests
+// =============================================================================
+
+#[test]
+fn test_small_range24_roundtrip() {
+    let r = SmallRange24::new(100, 200);
+    assert_eq!(r.start(), 100);
+    assert_eq!(r.end(), 200);
+    assert_eq!(r.len(), 100);
+    assert!(!r.is_empty());
+    assert_eq!(r.to_range(), 100..200);
+
+    let max = SmallRange24::from_start_len(SmallRange24::MAX_START, SmallRange24::MAX_LEN);
+    assert_eq!(max.start(), 4079);
+    assert_eq!(max.len(), 4095);
+    assert_eq!(max.end(), 8174);
+
+    let empty = SmallRange24::default();
+    assert!(empty.is_empty());
+    assert_eq!(empty.to_range(), 0..0);
+}
+
+#[test]
+fn test_small_range24_try_new() {
+    assert!(SmallRange24::try_new(4079, 8174).is_some());
+    assert!(SmallRange24::try_new(4080, 4080).is_none());
+    assert!(SmallRange24::try_new(0, 4096).is_none());
+    assert!(SmallRange24::try_new(20, 10).is_none());
+    assert!(SmallRange24::try_from_start_len(u16::MAX, 0).is_none());
+}
+
+#[test]
+fn test_small_range24_contains() {
+    let r = SmallRange24::new(10, 20);
+    assert!(r.contains(10));
+    assert!(r.contains(19));
+    assert!(!r.contains(20));
+    assert!(!r.contains(9));
+}
+
+#[test]
+fn test_small_range24_bytes() {
+    let r = SmallRange24::new(0, 1);
+    assert_eq!(r.to_bytes(), [0x01, 0x00, 0x01]);
+    assert_eq!(SmallRange24::from_bytes(r.to_bytes()), Some(r));
+
+    let r = SmallRange24::new(4079, 8174);
+    assert_eq!(r.to_bytes(), [0xFF, 0xFF, 0xFF]);
+
+    assert_eq!(SmallRange24::from_bytes([0, 0, 0]), None);
+    assert_eq!(SmallRange24::from_bytes([0xFF, 0xFF, 0]), None);
+}
+
+#[test]
+fn test_small_range24_const() {
+    const R: SmallRange24 = SmallRange24::new(5, 10);
+    const LEN: usize = R.len();
+    assert_eq!(LEN, 5);
+}
+
+#[test]
+fn test_small_range24_widen() {
+    let r = SmallRange24::new(4000, 8000);
+    assert_eq!(SmallRange::<u32>::from(r), SmallRange::new(4000, 8000));
+}
+
+#[test]
+fn test_small_range24_debug() {
+    assert_eq!(
+        std::format!("{:?}", SmallRange24::new(10, 20)),
+        "SmallRange24 { start: 10, end: 20 }"
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "start exceeds 12-bit capacity")]
+fn test_small_range24_new_panics_on_start_overflow() {
+    let _ = SmallRange24::new(4080, 4080);
+}
+
+// =============================================================================
+// Property-Based Tests
+// =============================================================================
+
+mod proptest_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn roundtrip(start in 0u16..=4079, len in 0u16..=4095) {
+            let r = SmallRange24::from_start_len(start, len);
+            prop_assert_eq!(r.start(), start);
+            prop_assert_eq!(r.len(), len as usize);
+            prop_assert_eq!(SmallRange24::from_bytes(r.to_bytes()), Some(r));
+        }
+    }
+}
@@ -0,0 +1,140 @@
+extern crate std;
+
+use crate::{SmallRange, SmallRangeError, SmallRangeInclusive};
+use core::mem::size_of;
+
+// =============================================================================
+// Layout Tests
+// =============================================================================
+
+#[test]
+fn test_inclusive_size() {
+    assert_eq!(size_of::<SmallRangeInclusive<u16>>(), 2);
+    assert_eq!(size_of::<Option<SmallRangeInclusive<u16>>>(), 2);
+    assert_eq!(size_of::<Option<SmallRangeInclusive<u64>>>(), 8);
+}
+
+// =============================================================================
+// Construction and Accessor Tests
+// =============================================================================
+
+#[test]
+fn test_inclusive_accessors() {
+    let r = SmallRangeInclusive::<u32>::new(10, 19);
+    assert_eq!(r.start(), 10);
+    assert_eq!(r.end(), 19);
+    assert_eq!(r.len(), 10);
+    assert!(!r.is_empty());
+    assert!(r.contains(10));
+    assert!(r.contains(19));
+    assert!(!r.contains(20));
+    assert!(!r.contains(9));
+
+    let single = SmallRangeInclusive::<u32>::new(5, 5);
+    assert_eq!(single.len(), 1);
+    assert!(single.contains(5));
+}
+
+#[test]
+fn test_inclusive_capacity() {
+    let max = SmallRangeInclusive::<u16>::new(254, 508);
+    assert_eq!(max.end(), 508);
+    assert_eq!(max.len(), 255);
+
+    assert!(SmallRangeInclusive::<u16>::try_new(0, 254).is_some());
+    assert!(SmallRangeInclusive::<u16>::try_new(0, 255).is_none());
+    assert!(SmallRangeInclusive::<u16>::try_new(255, 255).is_none());
+    assert!(SmallRangeInclusive::<u16>::try_new(10, 9).is_none());
+}
+
+// =============================================================================
+// Conversion Tests
+// =============================================================================
+
+#[test]
+fn test_inclusive_range_inclusive_roundtrip() {
+    let r = SmallRangeInclusive::<u16>::try_from(0..=254).unwrap();
+    assert_eq!(r.to_range_inclusive(), 0..=254);
+    let std_range: core::ops::RangeInclusive<u16> = r.into();
+    assert_eq!(std_range, 0..=254);
+
+    assert_eq!(
+        SmallRangeInclusive::<u16>::try_from(0..=255),
+        Err(SmallRangeError::LengthTooLarge)
+    );
+    assert_eq!(
+        SmallRangeInclusive::<u16>::try_from(300..=300),
+        Err(SmallRangeError::StartTooLarge)
+    );
+
+    #[allow(clippy::reversed_empty_ranges)]
+    let empty = 10u16..=9;
+    assert_eq!(
+        SmallRangeInclusive::try_from(empty),
+        Err(SmallRangeError::StartAfterEnd)
+    );
+
+    let mut exhausted = 5u16..=5;
+    exhausted.next();
+    assert_eq!(
+        SmallRangeInclusive::try_from(exhausted),
+        Err(SmallRangeError::StartAfterEnd)
+    );
+}
+
+#[test]
+fn test_inclusive_small_range_conversion() {
+    let small = SmallRange::<u16>::new(10, 20);
+    let inclusive = SmallRangeInclusive::try_from(small).unwrap();
+    assert_eq!(inclusive.to_range_inclusive(), 10..=19);
+    assert_eq!(inclusive.to_small_range(), Some(small));
+
+    assert_eq!(
+        SmallRangeInclusive::try_from(SmallRange::<u16>::new(5, 5)),
+        Err(SmallRangeError::StartAfterEnd)
+    );
+
+    // 255 values don't fit a half-open u16 range
+    assert_eq!(
+        SmallRangeInclusive::<u16>::new(0, 254).to_small_range(),
+        None
+    );
+}
+
+#[test]
+fn test_inclusive_ord() {
+    let a = SmallRangeInclusive::<u32>::new(5, 30);
+    let b = SmallRangeInclusive::<u32>::new(10, 15);
+    let c = SmallRangeInclusive::<u32>::new(10, 20);
+    assert!(a < b);
+    assert!(b < c);
+}
+
+#[test]
+fn test_inclusive_formatting() {
+    let r = SmallRangeInclusive::<u32>::new(10, 19);
+    assert_eq!(std::format!("{}", r), "10..=19");
+    assert_eq!(
+        std::format!("{:?}", r),
+        "SmallRangeInclusive { start: 10, end: 19 }"
+    );
+}
+
+// =============================================================================
+// Property-Based Tests
+// =============================================================================
+
+mod proptest_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn range_inclusive_roundtrip(start in 0u16..=254, span in 0u16..=254) {
+            let std_range = start..=start + span;
+            let r = SmallRangeInclusive::try_from(std_range.clone()).unwrap();
+            prop_assert_eq!(r.to_range_inclusive(), std_range);
+            prop_assert_eq!(r.len(), span as usize + 1);
+        }
+    }
+}
@@ -1,12 +1,13 @@
 extern crate alloc;
 extern crate std;
 
-use crate::SmallRange;
+use crate::{collapse_consecutive, SmallRange, SmallRangeError};
 use alloc::format;
+use alloc::string::ToString;
 use alloc::vec;
 use alloc::vec::Vec;
 use core::mem::size_of;
-use core::ops::Range;
+use core::ops::{Bound, Range};
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 
@@ -46,6 +47,19 @@ fn test_space_savings() {
     );
 }
 
+#[test]
+fn test_type_aliases() {
+    use crate::{SmallRange16, SmallRange32, SmallRange64, SmallRangeUsize};
+
+    assert_eq!(size_of::<SmallRange16>(), 2);
+    assert_eq!(size_of::<SmallRange32>(), 4);
+    assert_eq!(size_of::<SmallRange64>(), 8);
+    assert_eq!(size_of::<SmallRangeUsize>(), size_of::<usize>());
+
+    let r: SmallRange32 = SmallRange::new(10, 20);
+    assert_eq!(SmallRange32::new_const(10, 20), r);
+}
+
 // =============================================================================
 // Roundtrip Encoding/Decoding Tests
 // =============================================================================
@@ -146,6 +160,96 @@ fn test_maximum_values_u64() {
     assert_eq!(r.len(), max as usize);
 }
 
+// =============================================================================
+// Capacity Constant Tests
+// =============================================================================
+
+#[test]
+fn test_capacity_constants() {
+    assert_eq!(SmallRange::<u16>::MAX_START, 254);
+    assert_eq!(SmallRange::<u16>::MAX_LEN, 254);
+    assert_eq!(SmallRange::<u16>::MAX_END, 508);
+    assert_eq!(SmallRange::<u32>::MAX_START, 0xFFFE);
+    assert_eq!(SmallRange::<u64>::MAX_LEN, 0xFFFF_FFFE);
+    assert_eq!(SmallRange::<u64>::MAX_END, 0x1_FFFF_FFFC);
+
+    let max =
+        SmallRange::<u32>::from_start_len(SmallRange::<u32>::MAX_START, SmallRange::<u32>::MAX_LEN);
+    assert_eq!(max.end(), SmallRange::<u32>::MAX_END);
+}
+
+#[test]
+fn test_fits() {
+    assert!(SmallRange::<u16>::fits(0, 0));
+    assert!(SmallRange::<u16>::fits(254, 508));
+    assert!(SmallRange::<u16>::fits(0, 254));
+    assert!(!SmallRange::<u16>::fits(255, 255));
+    assert!(!SmallRange::<u16>::fits(0, 255));
+    assert!(!SmallRange::<u16>::fits(20, 10));
+    assert!(!SmallRange::<u16>::fits(u16::MAX, u16::MAX));
+}
+
+// =============================================================================
+// const fn Tests
+// =============================================================================
+
+#[test]
+fn test_const_fns() {
+    const SPAN16: SmallRange<u16> = SmallRange::<u16>::new_const(254, 508);
+    const SPAN32: SmallRange<u32> = SmallRange::<u32>::new_const(10, 20);
+    const SPAN64: SmallRange<u64> = SmallRange::<u64>::new_const(1000, 5000);
+    const SPANUSIZE: SmallRange<usize> = SmallRange::<usize>::new_const(0, 0);
+    const END32: u32 = SPAN32.end_const();
+
+    assert_eq!(SPAN16, SmallRange::new(254, 508));
+    assert_eq!(SPAN32, SmallRange::new(10, 20));
+    assert_eq!(SPAN64, SmallRange::new(1000, 5000));
+    assert_eq!(SPANUSIZE, SmallRange::default());
+    assert_eq!(END32, 20);
+
+    assert_eq!(SPAN16.start_const(), 254);
+    assert_eq!(SPAN16.end_const(), 508);
+    assert_eq!(SPAN16.len_const(), 254);
+    assert_eq!(SPAN64.len_const(), SPAN64.len());
+}
+
+// =============================================================================
+// u8 Storage Tests
+// =============================================================================
+
+#[test]
+fn test_u8_storage() {
+    assert_eq!(size_of::<SmallRange<u8>>(), 1);
+    assert_eq!(size_of::<Option<SmallRange<u8>>>(), 1);
+    assert_eq!(SmallRange::<u8>::MAX_START, 14);
+    assert_eq!(SmallRange::<u8>::MAX_LEN, 14);
+    assert_eq!(SmallRange::<u8>::MAX_END, 28);
+
+    let r = SmallRange::<u8>::new(3, 10);
+    assert_eq!(r.start(), 3);
+    assert_eq!(r.end(), 10);
+    assert_eq!(r.len(), 7);
+    assert_eq!(r.to_bits().get(), 0x48);
+
+    let max = SmallRange::<u8>::new(14, 28);
+    assert_eq!(max.to_parts(), (14, 28));
+    assert_eq!(SmallRange::<u8>::try_new(15, 16), None);
+    assert_eq!(SmallRange::<u8>::try_new(0, 15), None);
+}
+
+#[test]
+fn test_u8_conversions() {
+    let r = SmallRange::<u8>::new(3, 10);
+    assert_eq!(r.widen::<u64>(), SmallRange::new(3, 10));
+    assert_eq!(SmallRange::<u32>::new(3, 10).try_narrow::<u8>(), Some(r));
+    assert_eq!(SmallRange::<u32>::new(3, 30).try_narrow::<u8>(), None);
+    assert_eq!(r.to_le_bytes(), [0x48]);
+    assert_eq!(SmallRange::<u8>::from_le_bytes([0x48]), Some(r));
+
+    const CONST: SmallRange<u8> = SmallRange::<u8>::new_const(3, 10);
+    assert_eq!(CONST, r);
+}
+
 // =============================================================================
 // Empty Range Tests
 // =============================================================================
@@ -174,6 +278,46 @@ fn test_single_element_range() {
     assert_eq!(r.end(), 43);
 }
 
+// =============================================================================
+// midpoint() Tests
+// =============================================================================
+
+#[test]
+fn test_midpoint() {
+    assert_eq!(SmallRange::<u32>::new(0, 10).midpoint(), Some(5));
+    assert_eq!(SmallRange::<u32>::new(10, 15).midpoint(), Some(12)); // odd length
+    assert_eq!(SmallRange::<u32>::new(10, 12).midpoint(), Some(11)); // upper middle
+    assert_eq!(SmallRange::<u32>::new(42, 43).midpoint(), Some(42)); // single element
+    assert_eq!(SmallRange::<u32>::new(5, 5).midpoint(), None); // empty
+}
+
+#[test]
+fn test_midpoint_at_capacity() {
+    assert_eq!(SmallRange::<u16>::new(0, 254).midpoint(), Some(127));
+    assert_eq!(SmallRange::<u16>::new(254, 254 + 254).midpoint(), Some(381));
+}
+
+// =============================================================================
+// get() Tests
+// =============================================================================
+
+#[test]
+fn test_get() {
+    let r = SmallRange::<u32>::new(10, 15);
+
+    assert_eq!(r.get(0), Some(10));
+    assert_eq!(r.get(2), Some(12));
+    assert_eq!(r.get(4), Some(14));
+    assert_eq!(r.get(5), None);
+    assert_eq!(r.get(usize::MAX), None);
+}
+
+#[test]
+fn test_get_empty() {
+    let r = SmallRange::<u32>::new(10, 10);
+    assert_eq!(r.get(0), None);
+}
+
 // =============================================================================
 // Default Tests
 // =============================================================================
@@ -201,264 +345,2641 @@ fn test_to_range() {
     assert_eq!(empty.to_range(), 5..5);
 }
 
+#[test]
+fn test_to_inclusive() {
+    assert_eq!(SmallRange::<u32>::new(10, 20).to_inclusive(), Some(10..=19));
+    assert_eq!(SmallRange::<u32>::new(0, 1).to_inclusive(), Some(0..=0));
+    assert_eq!(SmallRange::<u32>::new(10, 10).to_inclusive(), None);
+    assert_eq!(
+        SmallRange::<u16>::new(254, 508).to_inclusive(),
+        Some(254..=507)
+    );
+}
+
+#[test]
+fn test_to_inclusive_roundtrip() {
+    let range = SmallRange::<u64>::new(1000, 5000);
+    let inclusive = range.to_inclusive().unwrap();
+    assert_eq!(SmallRange::try_from(inclusive), Ok(range));
+}
+
+#[test]
+fn test_to_parts() {
+    let span = SmallRange::<u32>::new(10, 20);
+    assert_eq!(span.to_parts(), (10, 20));
+    assert_eq!(span.to_start_len(), (10, 10));
+
+    let empty = SmallRange::<u16>::new(254, 254);
+    assert_eq!(empty.to_parts(), (254, 254));
+    assert_eq!(empty.to_start_len(), (254, 0));
+
+    let (start, end): (u64, u64) = SmallRange::<u64>::new(3, 9).into();
+    assert_eq!((start, end), (3, 9));
+}
+
+#[test]
+fn test_bits_roundtrip() {
+    let span = SmallRange::<u32>::new(10, 20);
+    assert_eq!(span.to_bits().get(), (11 << 16) | 11);
+    assert_eq!(SmallRange::from_bits(span.to_bits().get()), Some(span));
+
+    let max = SmallRange::<u16>::new(254, 508);
+    assert_eq!(max.to_bits().get(), 0xFFFF);
+    assert_eq!(SmallRange::<u16>::from_bits(0xFFFF), Some(max));
+    assert_eq!(
+        SmallRange::<u16>::from_bits(0x0101),
+        Some(SmallRange::new(0, 0))
+    );
+}
+
+#[test]
+fn test_from_bits_invalid() {
+    assert_eq!(SmallRange::<u16>::from_bits(0), None);
+    assert_eq!(SmallRange::<u16>::from_bits(0x0100), None);
+    assert_eq!(SmallRange::<u16>::from_bits(0x00FF), None);
+    assert_eq!(SmallRange::<u64>::from_bits(1 << 32), None);
+}
+
 // =============================================================================
-// Iterator Tests
+// Storage Conversion Tests
 // =============================================================================
 
 #[test]
-fn test_iteration() {
-    let r = SmallRange::<u32>::new(5, 10);
-    let collected: Vec<_> = r.into_iter().collect();
-    assert_eq!(collected, vec![5, 6, 7, 8, 9]);
+fn test_widen() {
+    let narrow = SmallRange::<u16>::new(10, 20);
+    assert_eq!(narrow.widen::<u32>(), SmallRange::new(10, 20));
+    assert_eq!(narrow.widen::<u64>(), SmallRange::new(10, 20));
+    assert_eq!(narrow.widen::<usize>(), SmallRange::new(10, 20));
+
+    let mid = SmallRange::<u32>::new(1000, 60000);
+    assert_eq!(mid.widen::<u64>(), SmallRange::new(1000, 60000));
+    assert_eq!(mid.widen::<usize>(), SmallRange::new(1000, 60000));
+
+    let word = SmallRange::<usize>::new(1000, 60000);
+    assert_eq!(word.widen::<u64>(), SmallRange::new(1000, 60000));
 }
 
 #[test]
-fn test_iteration_empty() {
-    let r = SmallRange::<u32>::new(5, 5);
-    let collected: Vec<_> = r.into_iter().collect();
-    assert!(collected.is_empty());
+fn test_widen_extremes() {
+    // Largest start and length for u16
+    let max = SmallRange::<u16>::new(254, 508);
+    let wide: SmallRange<u32> = max.into();
+    assert_eq!(wide.start(), 254);
+    assert_eq!(wide.end(), 508);
+
+    let empty = SmallRange::<u16>::default();
+    assert_eq!(SmallRange::<u64>::from(empty), SmallRange::default());
 }
 
 #[test]
-fn test_iteration_single() {
-    let r = SmallRange::<u32>::new(42, 43);
-    let collected: Vec<_> = r.into_iter().collect();
-    assert_eq!(collected, vec![42]);
+fn test_try_narrow() {
+    let wide = SmallRange::<u64>::new(10, 20);
+    assert_eq!(wide.try_narrow::<u16>(), Some(SmallRange::new(10, 20)));
+    assert_eq!(wide.try_narrow::<u32>(), Some(SmallRange::new(10, 20)));
+    assert_eq!(wide.try_narrow::<usize>(), Some(SmallRange::new(10, 20)));
+
+    let word = SmallRange::<usize>::new(1000, 60000);
+    assert_eq!(word.try_narrow::<u32>(), Some(SmallRange::new(1000, 60000)));
+    assert_eq!(word.try_narrow::<u16>(), None);
+
+    // Largest values that fit in u16
+    let edge = SmallRange::<u32>::new(254, 508);
+    assert_eq!(edge.try_narrow::<u16>(), Some(SmallRange::new(254, 508)));
 }
 
 #[test]
-fn test_iteration_by_ref() {
-    let r = SmallRange::<u32>::new(0, 3);
-    let collected: Vec<_> = (&r).into_iter().collect();
-    assert_eq!(collected, vec![0, 1, 2]);
-
-    // Can iterate again since we borrowed
-    let collected2: Vec<_> = (&r).into_iter().collect();
-    assert_eq!(collected2, vec![0, 1, 2]);
+fn test_try_from_narrow_errors() {
+    assert_eq!(
+        SmallRange::<u16>::try_from(SmallRange::<u32>::new(255, 256)),
+        Err(SmallRangeError::StartTooLarge)
+    );
+    assert_eq!(
+        SmallRange::<u16>::try_from(SmallRange::<u32>::new(0, 255)),
+        Err(SmallRangeError::LengthTooLarge)
+    );
+    // Start doesn't even fit the target storage type
+    assert_eq!(
+        SmallRange::<u32>::try_from(SmallRange::<u64>::new(100_000, 100_001)),
+        Err(SmallRangeError::StartTooLarge)
+    );
+    assert_eq!(
+        SmallRange::<u16>::try_from(SmallRange::<u64>::new(0, 100_000)),
+        Err(SmallRangeError::LengthTooLarge)
+    );
 }
 
 // =============================================================================
-// Debug Formatting Tests
+// PartialEq<Range> Tests
 // =============================================================================
 
 #[test]
-fn test_debug_format() {
-    let r = SmallRange::<u32>::new(10, 20);
-    let debug_str = format!("{:?}", r);
-    assert!(debug_str.contains("SmallRange"));
-    assert!(debug_str.contains("start"));
-    assert!(debug_str.contains("end"));
-    assert!(debug_str.contains("10"));
-    assert!(debug_str.contains("20"));
+fn test_eq_std_range() {
+    let small = SmallRange::<u32>::new(10, 20);
+    assert_eq!(small, 10..20);
+    assert_eq!(10..20, small);
+    assert_ne!(small, 10..21);
+    assert_ne!(11..20, small);
+
+    // Empty ranges compare by start, like Range itself
+    assert_eq!(SmallRange::<u32>::new(5, 5), 5..5);
+    assert_ne!(SmallRange::<u32>::new(5, 5), 6..6);
 }
 
 // =============================================================================
-// Equality and Hash Tests
+// Ord Tests
 // =============================================================================
 
 #[test]
-fn test_equality() {
-    let a = SmallRange::<u32>::new(10, 20);
-    let b = SmallRange::<u32>::new(10, 20);
-    let c = SmallRange::<u32>::new(10, 21);
-
-    assert_eq!(a, b);
-    assert_ne!(a, c);
+fn test_ord_start_then_end() {
+    let a = SmallRange::<u32>::new(5, 30);
+    let b = SmallRange::<u32>::new(10, 15);
+    let c = SmallRange::<u32>::new(10, 20);
+    assert!(a < b);
+    assert!(b < c);
+    assert!(SmallRange::<u32>::new(10, 10) < b);
+    assert_eq!(c.cmp(&c), core::cmp::Ordering::Equal);
 }
 
 #[test]
-fn test_hash_consistency() {
-    fn hash<T: Hash>(t: &T) -> u64 {
-        let mut hasher = DefaultHasher::new();
-        t.hash(&mut hasher);
-        hasher.finish()
-    }
+fn test_sort_and_binary_search() {
+    let mut spans: Vec<SmallRange<u16>> = vec![
+        SmallRange::new(200, 250),
+        SmallRange::new(0, 254),
+        SmallRange::new(200, 201),
+        SmallRange::new(3, 3),
+    ];
+    spans.sort();
+    assert_eq!(
+        spans,
+        [
+            SmallRange::new(0, 254),
+            SmallRange::new(3, 3),
+            SmallRange::new(200, 201),
+            SmallRange::new(200, 250),
+        ]
+    );
+    assert_eq!(spans.binary_search(&SmallRange::new(200, 201)), Ok(2));
+    assert_eq!(spans.binary_search(&SmallRange::new(4, 5)), Err(2));
+}
 
-    let a = SmallRange::<u32>::new(10, 20);
-    let b = SmallRange::<u32>::new(10, 20);
+#[test]
+fn test_sort_key() {
+    let a = SmallRange::<u32>::new(5, 30);
+    let b = SmallRange::<u32>::new(10, 15);
+    let c = SmallRange::<u32>::new(10, 20);
+    assert!(a.sort_key() < b.sort_key());
+    assert!(b.sort_key() < c.sort_key());
+    assert_ne!(SmallRange::<u16>::default().sort_key(), 0);
 
-    assert_eq!(hash(&a), hash(&b));
+    let mut spans = vec![c, a, b];
+    spans.sort_unstable_by_key(|r| r.sort_key());
+    assert_eq!(spans, [a, b, c]);
 }
 
+// =============================================================================
+// Display Tests
+// =============================================================================
+
 #[test]
-fn test_copy_clone() {
-    let original = SmallRange::<u32>::new(10, 20);
-    let copied = original; // Copy
-    let cloned = original.clone(); // Clone
+fn test_display() {
+    assert_eq!(format!("{}", SmallRange::<u32>::new(10, 20)), "10..20");
+    assert_eq!(format!("{}", SmallRange::<u16>::new(5, 5)), "5..5");
+    assert_eq!(format!("{}", SmallRange::<u64>::default()), "0..0");
+}
 
-    assert_eq!(original, copied);
-    assert_eq!(original, cloned);
+#[test]
+fn test_display_matches_range_debug() {
+    let small = SmallRange::<u32>::new(7, 300);
+    assert_eq!(format!("{}", small), format!("{:?}", small.to_range()));
+    assert_eq!(format!("{:>4}", small), format!("{:>4?}", small.to_range()));
 }
 
 // =============================================================================
-// try_new() Tests
+// Parse Tests
 // =============================================================================
 
 #[test]
-fn test_try_new_valid() {
-    let r = SmallRange::<u32>::try_new(10, 20);
-    assert!(r.is_some());
-    let r = r.unwrap();
-    assert_eq!(r.start(), 10);
-    assert_eq!(r.end(), 20);
+fn test_parse() {
+    assert_eq!(
+        SmallRange::<u32>::parse("10..20"),
+        Ok(SmallRange::new(10, 20))
+    );
+    assert_eq!(
+        SmallRange::<u32>::parse("10..=19"),
+        Ok(SmallRange::new(10, 20))
+    );
+    assert_eq!(SmallRange::<u32>::parse("5..5"), Ok(SmallRange::new(5, 5)));
+    assert_eq!(
+        "0..254".parse::<SmallRange<u16>>(),
+        Ok(SmallRange::new(0, 254))
+    );
 }
 
 #[test]
-fn test_try_new_empty_range() {
-    let r = SmallRange::<u32>::try_new(10, 10);
-    assert!(r.is_some());
-    assert!(r.unwrap().is_empty());
+fn test_parse_errors() {
+    use crate::ParseSmallRangeError;
+
+    assert_eq!(
+        SmallRange::<u32>::parse("10"),
+        Err(ParseSmallRangeError::MissingSeparator)
+    );
+    assert_eq!(
+        SmallRange::<u32>::parse("..20"),
+        Err(ParseSmallRangeError::InvalidStart)
+    );
+    assert_eq!(
+        SmallRange::<u32>::parse("10.."),
+        Err(ParseSmallRangeError::InvalidEnd)
+    );
+    assert_eq!(
+        SmallRange::<u32>::parse(" 10..20"),
+        Err(ParseSmallRangeError::InvalidStart)
+    );
+    assert_eq!(
+        SmallRange::<u32>::parse("10...20"),
+        Err(ParseSmallRangeError::InvalidEnd)
+    );
+    assert_eq!(
+        SmallRange::<u32>::parse("-1..20"),
+        Err(ParseSmallRangeError::InvalidStart)
+    );
+    assert_eq!(
+        SmallRange::<u32>::parse("20..10"),
+        Err(ParseSmallRangeError::Range(SmallRangeError::StartAfterEnd))
+    );
+    assert_eq!(
+        SmallRange::<u16>::parse("0..=254"),
+        Err(ParseSmallRangeError::Range(SmallRangeError::LengthTooLarge))
+    );
+    assert_eq!(
+        SmallRange::<u16>::parse("0..70000"),
+        Err(ParseSmallRangeError::InvalidEnd)
+    );
 }
 
 #[test]
-fn test_try_new_invalid_start_exceeds_end() {
-    let r = SmallRange::<u32>::try_new(20, 10);
-    assert!(r.is_none());
+fn test_parse_display_roundtrip() {
+    let span = SmallRange::<u64>::new(1234, 5678);
+    assert_eq!(span.to_string().parse(), Ok(span));
 }
 
+// =============================================================================
+// Byte Conversion Tests
+// =============================================================================
+
 #[test]
-fn test_try_new_start_exceeds_capacity() {
-    // u16 max start is 254 (LOW_MASK - 1 = 255 - 1)
-    let r = SmallRange::<u16>::try_new(255, 255);
-    assert!(r.is_none());
+fn test_bytes_roundtrip() {
+    let span = SmallRange::<u32>::new(10, 20);
+    assert_eq!(span.to_le_bytes(), [11, 0, 11, 0]);
+    assert_eq!(span.to_be_bytes(), [0, 11, 0, 11]);
+    assert_eq!(
+        SmallRange::<u32>::from_le_bytes(span.to_le_bytes()),
+        Some(span)
+    );
+    assert_eq!(
+        SmallRange::<u32>::from_be_bytes(span.to_be_bytes()),
+        Some(span)
+    );
 
-    // 254 should work
-    let r = SmallRange::<u16>::try_new(254, 254);
-    assert!(r.is_some());
+    let short = SmallRange::<u16>::new(1, 3);
+    assert_eq!(short.to_le_bytes(), [3, 2]);
+    assert_eq!(short.to_be_bytes(), [2, 3]);
+
+    let long = SmallRange::<u64>::new(1000, 5000);
+    assert_eq!(
+        SmallRange::<u64>::from_le_bytes(long.to_le_bytes()),
+        Some(long)
+    );
+    let word = SmallRange::<usize>::new(1000, 5000);
+    assert_eq!(
+        SmallRange::<usize>::from_be_bytes(word.to_be_bytes()),
+        Some(word)
+    );
 }
 
 #[test]
-fn test_try_new_length_exceeds_capacity() {
-    // u16 max length is 254
-    let r = SmallRange::<u16>::try_new(0, 255);
-    assert!(r.is_none());
-
-    // 254 should work
-    let r = SmallRange::<u16>::try_new(0, 254);
-    assert!(r.is_some());
+fn test_from_bytes_invalid() {
+    assert_eq!(SmallRange::<u16>::from_le_bytes([0, 0]), None);
+    assert_eq!(SmallRange::<u16>::from_le_bytes([0, 1]), None);
+    assert_eq!(SmallRange::<u32>::from_be_bytes([0, 0, 0, 1]), None);
 }
 
 // =============================================================================
-// contains() Tests
+// Iterator Tests
 // =============================================================================
 
 #[test]
-fn test_contains_basic() {
+fn test_iteration() {
     let r = SmallRange::<u32>::new(5, 10);
-
-    // Values inside range
-    assert!(r.contains(5)); // start is included
-    assert!(r.contains(6));
-    assert!(r.contains(9)); // last value before end
-
-    // Values outside range
-    assert!(!r.contains(4)); // before start
-    assert!(!r.contains(10)); // end is excluded
-    assert!(!r.contains(11)); // after end
+    let collected: Vec<_> = r.into_iter().collect();
+    assert_eq!(collected, vec![5, 6, 7, 8, 9]);
 }
 
 #[test]
-fn test_contains_empty_range() {
+fn test_iteration_empty() {
     let r = SmallRange::<u32>::new(5, 5);
-    assert!(!r.contains(4));
-    assert!(!r.contains(5)); // empty range contains nothing
-    assert!(!r.contains(6));
+    let collected: Vec<_> = r.into_iter().collect();
+    assert!(collected.is_empty());
 }
 
 #[test]
-fn test_contains_single_element() {
+fn test_iteration_single() {
     let r = SmallRange::<u32>::new(42, 43);
-    assert!(!r.contains(41));
-    assert!(r.contains(42));
-    assert!(!r.contains(43));
+    let collected: Vec<_> = r.into_iter().collect();
+    assert_eq!(collected, vec![42]);
 }
 
 #[test]
-fn test_contains_zero_start() {
-    let r = SmallRange::<u32>::new(0, 5);
-    assert!(r.contains(0));
-    assert!(r.contains(4));
-    assert!(!r.contains(5));
-}
+fn test_iteration_by_ref() {
+    let r = SmallRange::<u32>::new(0, 3);
+    let collected: Vec<_> = (&r).into_iter().collect();
+    assert_eq!(collected, vec![0, 1, 2]);
 
-// =============================================================================
-// overlaps() Tests
-// =============================================================================
+    // Can iterate again since we borrowed
+    let collected2: Vec<_> = (&r).into_iter().collect();
+    assert_eq!(collected2, vec![0, 1, 2]);
+}
+
+#[test]
+fn test_iter_does_not_consume() {
+    let r = SmallRange::<u64>::new(1 << 30, (1 << 30) + 3);
+    assert_eq!(
+        r.iter().collect::<Vec<_>>(),
+        vec![1 << 30, (1 << 30) + 1, (1 << 30) + 2]
+    );
+    assert_eq!(r.iter().len(), 3);
+    assert!(r.iter().eq(r.to_range()));
+    assert_eq!(r.len(), 3);
+}
+
+#[test]
+fn test_rev_iter() {
+    let r = SmallRange::<u32>::new(5, 9);
+    assert_eq!(r.rev_iter().collect::<Vec<_>>(), vec![8, 7, 6, 5]);
+    assert_eq!(r.rev_iter().len(), 4);
+    assert_eq!(r.rev_iter().rev().collect::<Vec<_>>(), vec![5, 6, 7, 8]);
+    assert_eq!(SmallRange::<u32>::new(5, 5).rev_iter().next(), None);
+}
+
+#[test]
+fn test_collect_values() {
+    let span: SmallRange<u32> = [7, 3, 12, 3].into_iter().collect();
+    assert_eq!(span, SmallRange::new(3, 13));
+    let single: SmallRange<u8> = core::iter::once(5).collect();
+    assert_eq!(single, SmallRange::new(5, 6));
+    let empty: SmallRange<u32> = core::iter::empty::<u32>().collect();
+    assert_eq!(empty, SmallRange::default());
+}
+
+#[test]
+fn test_try_from_values() {
+    assert_eq!(
+        SmallRange::<u32>::try_from_values([7, 3, 12]),
+        Ok(SmallRange::new(3, 13))
+    );
+    assert_eq!(
+        SmallRange::<u32>::try_from_values([]),
+        Ok(SmallRange::default())
+    );
+    // u8 holds starts up to 14 and lengths up to 14
+    assert_eq!(
+        SmallRange::<u8>::try_from_values([14, 27]),
+        Ok(SmallRange::new(14, 28))
+    );
+    assert_eq!(
+        SmallRange::<u8>::try_from_values([15]),
+        Err(SmallRangeError::StartTooLarge)
+    );
+    assert_eq!(
+        SmallRange::<u8>::try_from_values([0, 14]),
+        Err(SmallRangeError::LengthTooLarge)
+    );
+    assert_eq!(
+        SmallRange::<u8>::try_from_values([u8::MAX]),
+        Err(SmallRangeError::StartTooLarge)
+    );
+}
+
+#[test]
+fn test_collect_ranges_into_hull() {
+    let children = [
+        SmallRange::<u32>::new(12, 20),
+        SmallRange::new(4, 7),
+        SmallRange::new(100, 100),
+        SmallRange::new(8, 9),
+    ];
+    let parent: SmallRange<u32> = children.iter().collect();
+    assert_eq!(parent, SmallRange::new(4, 20));
+    let owned: SmallRange<u32> = children.into_iter().collect();
+    assert_eq!(owned, parent);
+
+    let empty: SmallRange<u32> = [SmallRange::new(5, 5)].into_iter().collect();
+    assert_eq!(empty, SmallRange::default());
+}
+
+#[test]
+fn test_try_hull_of() {
+    assert_eq!(
+        SmallRange::try_hull_of([SmallRange::<u8>::new(14, 16), SmallRange::new(2, 4)]),
+        Ok(SmallRange::new(2, 16))
+    );
+    assert_eq!(SmallRange::<u8>::try_hull_of([]), Ok(SmallRange::default()));
+    assert_eq!(
+        SmallRange::try_hull_of([SmallRange::<u8>::new(0, 1), SmallRange::new(14, 28)]),
+        Err(SmallRangeError::LengthTooLarge)
+    );
+}
+
+fn collapse<T: crate::SmallRangeStorage>(
+    values: impl IntoIterator<Item = T>,
+) -> Result<Vec<SmallRange<T>>, SmallRangeError>
+where
+    usize: num_traits::AsPrimitive<T>,
+{
+    collapse_consecutive(values).collect()
+}
+
+#[test]
+fn test_collapse_consecutive() {
+    assert_eq!(
+        collapse([1u32, 2, 3, 7, 8, 12]),
+        Ok(vec![
+            SmallRange::new(1, 4),
+            SmallRange::new(7, 9),
+            SmallRange::new(12, 13)
+        ])
+    );
+    assert_eq!(collapse_consecutive(Vec::<u32>::new()).next(), None);
+    assert_eq!(collapse([0u8]), Ok(vec![SmallRange::new(0, 1)]));
+}
+
+#[test]
+fn test_collapse_consecutive_duplicates_and_unsorted() {
+    assert_eq!(
+        collapse([4u32, 4, 5, 5, 5, 6, 9, 9]),
+        Ok(vec![SmallRange::new(4, 7), SmallRange::new(9, 10)])
+    );
+
+    // Unsorted input is still fully covered, just not merged
+    assert_eq!(
+        collapse([5u32, 6, 2, 3, 5]),
+        Ok(vec![
+            SmallRange::new(5, 7),
+            SmallRange::new(2, 4),
+            SmallRange::new(5, 6)
+        ])
+    );
+}
+
+#[test]
+fn test_collapse_consecutive_splits_long_runs() {
+    // u8 holds at most 14 values per range, split from the run's end
+    assert_eq!(
+        collapse(0u8..20),
+        Ok(vec![SmallRange::new(0, 6), SmallRange::new(6, 20)])
+    );
+    assert_eq!(
+        collapse(0u8..28),
+        Ok(vec![SmallRange::new(0, 14), SmallRange::new(14, 28)])
+    );
+    // Splitting from the start would leave 114..400, which can't be stored
+    assert_eq!(
+        collapse(100u16..400),
+        Ok(vec![SmallRange::new(100, 146), SmallRange::new(146, 400)])
+    );
+}
+
+#[test]
+fn test_collapse_consecutive_size_hint() {
+    let iter = collapse_consecutive([1u32, 2, 3, 7]);
+    assert_eq!(iter.size_hint(), (1, Some(4)));
+    assert_eq!(
+        collapse_consecutive(Vec::<u32>::new()).size_hint(),
+        (0, Some(0))
+    );
+
+    // The rest of a long run is already known
+    let mut iter = collapse_consecutive(0u8..28);
+    iter.next();
+    assert_eq!(iter.size_hint(), (1, Some(1)));
+}
+
+#[test]
+fn test_collapse_consecutive_past_capacity() {
+    // u8 starts max out at 14 and ends at 28
+    let runs: Vec<_> = collapse_consecutive([10u8, 15, 16]).collect();
+    assert_eq!(
+        runs,
+        vec![
+            Ok(SmallRange::new(10, 11)),
+            Err(SmallRangeError::StartTooLarge),
+            Err(SmallRangeError::StartTooLarge)
+        ]
+    );
+    let runs: Vec<_> = collapse_consecutive(14u8..30).collect();
+    assert_eq!(
+        runs,
+        vec![
+            Ok(SmallRange::new(14, 28)),
+            Err(SmallRangeError::StartTooLarge),
+            Err(SmallRangeError::StartTooLarge)
+        ]
+    );
+
+    // A lone u16 value past 254
+    assert_eq!(
+        collapse_consecutive([300u16]).collect::<Vec<_>>(),
+        vec![Err(SmallRangeError::StartTooLarge)]
+    );
+}
+
+#[test]
+fn test_iteration_u64_exact_size() {
+    fn exact_len<I: ExactSizeIterator>(iter: I) -> usize {
+        iter.len()
+    }
+
+    let r = SmallRange::<u64>::new(1 << 30, (1 << 30) + 1_000);
+    assert_eq!(exact_len(r.into_iter()), 1_000);
+    let mut iter = r.into_iter();
+    iter.next();
+    iter.next_back();
+    assert_eq!(iter.len(), 998);
+    assert_eq!(iter.size_hint(), (998, Some(998)));
+}
+
+#[test]
+fn test_iteration_double_ended() {
+    let r = SmallRange::<u16>::new(3, 8);
+    assert_eq!(r.into_iter().rev().collect::<Vec<_>>(), vec![7, 6, 5, 4, 3]);
+
+    let mut iter = r.into_iter();
+    assert_eq!(iter.next(), Some(3));
+    assert_eq!(iter.next_back(), Some(7));
+    assert_eq!(iter.next(), Some(4));
+    assert_eq!(iter.next_back(), Some(6));
+    assert_eq!(iter.next(), Some(5));
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_iteration_nth() {
+    let r = SmallRange::<u64>::new(100, 110);
+    let mut iter = r.into_iter();
+    assert_eq!(iter.nth(1), Some(101));
+    assert_eq!(iter.nth(2), Some(104));
+    assert_eq!(iter.nth_back(2), Some(107));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.nth(2), None);
+    assert_eq!(iter.len(), 0);
+
+    let mut iter = r.into_iter();
+    assert_eq!(iter.nth_back(10), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_iteration_shortcuts() {
+    let r = SmallRange::<u32>::new(5, 10);
+    assert_eq!(r.into_iter().count(), 5);
+    assert_eq!(r.into_iter().last(), Some(9));
+    assert_eq!(r.into_iter().min(), Some(5));
+    assert_eq!(r.into_iter().max(), Some(9));
+    let empty = SmallRange::<u32>::new(5, 5);
+    assert_eq!(empty.into_iter().last(), None);
+    assert_eq!(empty.into_iter().max(), None);
+}
+
+#[test]
+fn test_iteration_fused() {
+    let mut iter = SmallRange::<u8>::new(0, 1).into_iter();
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn test_iteration_at_capacity() {
+    let max = SmallRange::<u8>::MAX_END;
+    let r = SmallRange::<u8>::new(SmallRange::<u8>::MAX_START, max);
+    assert_eq!(r.into_iter().last(), Some(max - 1));
+    assert_eq!(r.into_iter().len(), SmallRange::<u8>::MAX_LEN as usize);
+}
+
+// =============================================================================
+// Debug Formatting Tests
+// =============================================================================
+
+#[test]
+fn test_debug_format() {
+    let r = SmallRange::<u32>::new(10, 20);
+    let debug_str = format!("{:?}", r);
+    assert!(debug_str.contains("SmallRange"));
+    assert!(debug_str.contains("start"));
+    assert!(debug_str.contains("end"));
+    assert!(debug_str.contains("10"));
+    assert!(debug_str.contains("20"));
+}
+
+#[test]
+fn test_compact_debug() {
+    let span = SmallRange::<u32>::new(10, 20);
+    assert_eq!(format!("{:?}", span.compact_debug()), "10..20");
+    assert_eq!(format!("{:#?}", span.compact_debug()), "10..20");
+    assert_eq!(
+        format!("{:x?}", SmallRange::<u32>::new(10, 20).compact_debug()),
+        "a..14"
+    );
+
+    let spans: Vec<_> = [SmallRange::<u16>::new(0, 4), SmallRange::new(5, 5)]
+        .iter()
+        .map(SmallRange::compact_debug)
+        .collect();
+    assert_eq!(format!("{:?}", spans), "[0..4, 5..5]");
+}
+
+// =============================================================================
+// Equality and Hash Tests
+// =============================================================================
+
+#[test]
+fn test_equality() {
+    let a = SmallRange::<u32>::new(10, 20);
+    let b = SmallRange::<u32>::new(10, 20);
+    let c = SmallRange::<u32>::new(10, 21);
+
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+}
+
+#[test]
+fn test_hash_consistency() {
+    fn hash<T: Hash>(t: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        t.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let a = SmallRange::<u32>::new(10, 20);
+    let b = SmallRange::<u32>::new(10, 20);
+
+    assert_eq!(hash(&a), hash(&b));
+}
+
+#[test]
+#[allow(clippy::clone_on_copy)]
+fn test_copy_clone() {
+    let original = SmallRange::<u32>::new(10, 20);
+    let copied = original; // Copy
+    let cloned = original.clone(); // Clone
+
+    assert_eq!(original, copied);
+    assert_eq!(original, cloned);
+}
+
+// =============================================================================
+// try_new() Tests
+// =============================================================================
+
+#[test]
+fn test_try_new_valid() {
+    let r = SmallRange::<u32>::try_new(10, 20);
+    assert!(r.is_some());
+    let r = r.unwrap();
+    assert_eq!(r.start(), 10);
+    assert_eq!(r.end(), 20);
+}
+
+#[test]
+fn test_try_new_empty_range() {
+    let r = SmallRange::<u32>::try_new(10, 10);
+    assert!(r.is_some());
+    assert!(r.unwrap().is_empty());
+}
+
+#[test]
+fn test_try_new_invalid_start_exceeds_end() {
+    let r = SmallRange::<u32>::try_new(20, 10);
+    assert!(r.is_none());
+}
+
+#[test]
+fn test_try_new_start_exceeds_capacity() {
+    // u16 max start is 254 (LOW_MASK - 1 = 255 - 1)
+    let r = SmallRange::<u16>::try_new(255, 255);
+    assert!(r.is_none());
+
+    // 254 should work
+    let r = SmallRange::<u16>::try_new(254, 254);
+    assert!(r.is_some());
+}
+
+#[test]
+fn test_try_new_near_storage_max() {
+    // Must not overflow when adding the +1 encoding offset
+    assert!(SmallRange::<u16>::try_new(u16::MAX, u16::MAX).is_none());
+    assert!(SmallRange::<u64>::try_new(u64::MAX, u64::MAX).is_none());
+    assert!(SmallRange::<u16>::try_new(0, u16::MAX).is_none());
+}
+
+#[test]
+fn test_try_new_length_exceeds_capacity() {
+    // u16 max length is 254
+    let r = SmallRange::<u16>::try_new(0, 255);
+    assert!(r.is_none());
+
+    // 254 should work
+    let r = SmallRange::<u16>::try_new(0, 254);
+    assert!(r.is_some());
+}
+
+#[test]
+fn test_try_new_checked() {
+    assert_eq!(
+        SmallRange::<u16>::try_new_checked(10, 20),
+        Ok(SmallRange::new(10, 20))
+    );
+    assert_eq!(
+        SmallRange::<u16>::try_new_checked(20, 10),
+        Err(SmallRangeError::StartAfterEnd)
+    );
+    assert_eq!(
+        SmallRange::<u16>::try_new_checked(255, 256),
+        Err(SmallRangeError::StartTooLarge)
+    );
+    assert_eq!(
+        SmallRange::<u16>::try_new_checked(0, 255),
+        Err(SmallRangeError::LengthTooLarge)
+    );
+    assert_eq!(
+        SmallRange::<u16>::try_new_checked(u16::MAX, u16::MAX),
+        Err(SmallRangeError::StartTooLarge)
+    );
+}
+
+#[test]
+fn test_error_display() {
+    assert_eq!(
+        SmallRangeError::StartAfterEnd.to_string(),
+        "start must not exceed end"
+    );
+    assert_eq!(
+        SmallRangeError::StartTooLarge.to_string(),
+        "start exceeds half-width capacity"
+    );
+    assert_eq!(
+        SmallRangeError::LengthTooLarge.to_string(),
+        "length exceeds half-width capacity"
+    );
+}
+
+#[test]
+fn test_parse_error_display() {
+    use crate::ParseSmallRangeError;
+    use core::error::Error;
+
+    let err = ParseSmallRangeError::Range(SmallRangeError::StartAfterEnd);
+    assert_eq!(err.to_string(), "start must not exceed end");
+    // The inner error is already in the message
+    assert!(err.source().is_none());
+    assert_eq!(
+        ParseSmallRangeError::MissingSeparator.to_string(),
+        "missing `..` separator"
+    );
+}
+
+// =============================================================================
+// new_clamped() Tests
+// =============================================================================
+
+#[test]
+fn test_new_clamped() {
+    assert_eq!(
+        SmallRange::<u16>::new_clamped(10, 20),
+        SmallRange::new(10, 20)
+    );
+    // Swapped
+    assert_eq!(
+        SmallRange::<u16>::new_clamped(20, 10),
+        SmallRange::new(10, 20)
+    );
+    // Length truncated
+    assert_eq!(
+        SmallRange::<u16>::new_clamped(0, 1000),
+        SmallRange::new(0, 254)
+    );
+    // Start clamped
+    assert_eq!(
+        SmallRange::<u16>::new_clamped(300, 400),
+        SmallRange::new(254, 400)
+    );
+    assert_eq!(
+        SmallRange::<u16>::new_clamped(u16::MAX, u16::MAX),
+        SmallRange::new(254, 508)
+    );
+    assert_eq!(
+        SmallRange::<u64>::new_clamped(u64::MAX, 0),
+        SmallRange::new(0, 0xFFFF_FFFE)
+    );
+}
+
+// =============================================================================
+// new_unchecked() Tests
+// =============================================================================
+#[test]
+fn test_new_unchecked() {
+    // SAFETY: all inputs are ordered and within capacity
+    unsafe {
+        assert_eq!(
+            SmallRange::<u32>::new_unchecked(10, 20),
+            SmallRange::new(10, 20)
+        );
+        assert_eq!(
+            SmallRange::<u16>::new_unchecked(254, 508),
+            SmallRange::new(254, 508)
+        );
+        assert_eq!(
+            SmallRange::<u64>::new_unchecked(0, 0),
+            SmallRange::default()
+        );
+    }
+}
+
+// =============================================================================
+// from_start_len() Tests
+// =============================================================================
+
+#[test]
+fn test_from_start_len() {
+    let r = SmallRange::<u32>::from_start_len(10, 5);
+    assert_eq!(r.start(), 10);
+    assert_eq!(r.end(), 15);
+    assert_eq!(r.len(), 5);
+
+    assert_eq!(
+        SmallRange::<u32>::from_start_len(7, 0),
+        SmallRange::new(7, 7)
+    );
+
+    // Max start and max length together
+    let r = SmallRange::<u16>::from_start_len(254, 254);
+    assert_eq!(r.end(), 508);
+}
+
+#[test]
+fn test_try_from_start_len() {
+    assert_eq!(
+        SmallRange::<u16>::try_from_start_len(254, 254),
+        Some(SmallRange::new(254, 508))
+    );
+    assert_eq!(SmallRange::<u16>::try_from_start_len(255, 0), None);
+    assert_eq!(SmallRange::<u16>::try_from_start_len(0, 255), None);
+    assert_eq!(
+        SmallRange::<u16>::try_from_start_len(u16::MAX, u16::MAX),
+        None
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "length+1 exceeds half-width capacity")]
+fn test_from_start_len_panics_on_length_overflow() {
+    SmallRange::<u16>::from_start_len(0, 255);
+}
+
+// =============================================================================
+// centered_at() Tests
+// =============================================================================
+
+#[test]
+fn test_centered_at() {
+    assert_eq!(
+        SmallRange::<u32>::centered_at(100, 16),
+        Some(SmallRange::new(84, 116))
+    );
+    assert_eq!(
+        SmallRange::<u32>::centered_at(100, 0),
+        Some(SmallRange::new(100, 100))
+    );
+
+    // Clamped at zero
+    assert_eq!(
+        SmallRange::<u32>::centered_at(5, 16),
+        Some(SmallRange::new(0, 21))
+    );
+}
+
+#[test]
+fn test_centered_at_exceeds_capacity() {
+    // Length 2 * 128 = 256 exceeds u16's 254
+    assert_eq!(SmallRange::<u16>::centered_at(200, 128), None);
+    assert_eq!(
+        SmallRange::<u16>::centered_at(200, 127),
+        Some(SmallRange::new(73, 327))
+    );
+
+    // End overflows the storage type
+    assert_eq!(SmallRange::<u16>::centered_at(u16::MAX, 1), None);
+}
+
+// =============================================================================
+// try_from_bounds() Tests
+// =============================================================================
+
+#[test]
+fn test_try_from_bounds_all_forms() {
+    let len = 100u32;
+    assert_eq!(
+        SmallRange::try_from_bounds(.., len),
+        Some(SmallRange::new(0, 100))
+    );
+    assert_eq!(
+        SmallRange::try_from_bounds(10.., len),
+        Some(SmallRange::new(10, 100))
+    );
+    assert_eq!(
+        SmallRange::try_from_bounds(..20, len),
+        Some(SmallRange::new(0, 20))
+    );
+    assert_eq!(
+        SmallRange::try_from_bounds(..=19, len),
+        Some(SmallRange::new(0, 20))
+    );
+    assert_eq!(
+        SmallRange::try_from_bounds(10..20, len),
+        Some(SmallRange::new(10, 20))
+    );
+    assert_eq!(
+        SmallRange::try_from_bounds(10..=19, len),
+        Some(SmallRange::new(10, 20))
+    );
+    assert_eq!(
+        SmallRange::try_from_bounds((Bound::Excluded(9), Bound::Excluded(20)), len),
+        Some(SmallRange::new(10, 20))
+    );
+}
+
+#[test]
+fn test_try_from_bounds_invalid() {
+    let len = 100u32;
+    // End past the domain
+    assert_eq!(SmallRange::try_from_bounds(90..101, len), None);
+    assert_eq!(SmallRange::try_from_bounds(90..=100, len), None);
+    // Start after end
+    assert_eq!(
+        SmallRange::try_from_bounds((Bound::Included(20), Bound::Excluded(10)), len),
+        None
+    );
+    // Overflowing bounds
+    assert_eq!(
+        SmallRange::<u16>::try_from_bounds(..=u16::MAX, u16::MAX),
+        None
+    );
+    assert_eq!(
+        SmallRange::<u16>::try_from_bounds((Bound::Excluded(u16::MAX), Bound::Unbounded), u16::MAX),
+        None
+    );
+    // Exceeds capacity
+    assert_eq!(SmallRange::<u16>::try_from_bounds(.., 255), None);
+    assert_eq!(
+        SmallRange::<u16>::try_from_bounds(.., 254),
+        Some(SmallRange::new(0, 254))
+    );
+}
+
+// =============================================================================
+// TryFrom<RangeInclusive> Tests
+// =============================================================================
+
+#[test]
+fn test_try_from_range_inclusive() {
+    assert_eq!(
+        SmallRange::<u32>::try_from(10..=19),
+        Ok(SmallRange::new(10, 20))
+    );
+    assert_eq!(
+        SmallRange::<u32>::try_from(0..=0),
+        Ok(SmallRange::new(0, 1))
+    );
+    // Empty inclusive range
+    #[allow(clippy::reversed_empty_ranges)]
+    let empty = 10u32..=9;
+    assert_eq!(SmallRange::try_from(empty), Ok(SmallRange::new(10, 10)));
+    // Largest representable u16 range
+    assert_eq!(
+        SmallRange::<u16>::try_from(0..=253),
+        Ok(SmallRange::new(0, 254))
+    );
+}
+
+#[test]
+fn test_try_from_range_inclusive_exhausted() {
+    let mut range = 5u32..=5;
+    assert_eq!(range.next(), Some(5));
+    assert_eq!(SmallRange::try_from(range), Ok(SmallRange::new(6, 6)));
+}
+
+#[test]
+fn test_try_from_range_inclusive_errors() {
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = 10u32..=5;
+    assert_eq!(
+        SmallRange::try_from(reversed),
+        Err(SmallRangeError::StartAfterEnd)
+    );
+    assert_eq!(
+        SmallRange::<u16>::try_from(0..=254),
+        Err(SmallRangeError::LengthTooLarge)
+    );
+    assert_eq!(
+        SmallRange::<u16>::try_from(10..=u16::MAX),
+        Err(SmallRangeError::LengthTooLarge)
+    );
+    assert_eq!(
+        SmallRange::<u16>::try_from(300..=u16::MAX),
+        Err(SmallRangeError::StartTooLarge)
+    );
+}
+
+// =============================================================================
+// contains() Tests
+// =============================================================================
+
+#[test]
+fn test_contains_basic() {
+    let r = SmallRange::<u32>::new(5, 10);
+
+    // Values inside range
+    assert!(r.contains(5)); // start is included
+    assert!(r.contains(6));
+    assert!(r.contains(9)); // last value before end
+
+    // Values outside range
+    assert!(!r.contains(4)); // before start
+    assert!(!r.contains(10)); // end is excluded
+    assert!(!r.contains(11)); // after end
+}
+
+#[test]
+fn test_contains_empty_range() {
+    let r = SmallRange::<u32>::new(5, 5);
+    assert!(!r.contains(4));
+    assert!(!r.contains(5)); // empty range contains nothing
+    assert!(!r.contains(6));
+}
+
+#[test]
+fn test_contains_single_element() {
+    let r = SmallRange::<u32>::new(42, 43);
+    assert!(!r.contains(41));
+    assert!(r.contains(42));
+    assert!(!r.contains(43));
+}
+
+#[test]
+fn test_contains_zero_start() {
+    let r = SmallRange::<u32>::new(0, 5);
+    assert!(r.contains(0));
+    assert!(r.contains(4));
+    assert!(!r.contains(5));
+}
+
+// =============================================================================
+// position_of() Tests
+// =============================================================================
+
+#[test]
+fn test_position_of() {
+    let r = SmallRange::<u32>::new(10, 15);
+
+    assert_eq!(r.position_of(10), Some(0));
+    assert_eq!(r.position_of(12), Some(2));
+    assert_eq!(r.position_of(14), Some(4));
+    assert_eq!(r.position_of(15), None); // end is excluded
+    assert_eq!(r.position_of(9), None);
+
+    // Empty ranges contain nothing
+    assert_eq!(SmallRange::<u32>::new(10, 10).position_of(10), None);
+}
+
+// =============================================================================
+// cmp_value() Tests
+// =============================================================================
+
+#[test]
+fn test_cmp_value_basic() {
+    use core::cmp::Ordering;
+
+    let r = SmallRange::<u32>::new(10, 20);
+
+    assert_eq!(r.cmp_value(0), Ordering::Greater);
+    assert_eq!(r.cmp_value(9), Ordering::Greater);
+    assert_eq!(r.cmp_value(10), Ordering::Equal); // start is included
+    assert_eq!(r.cmp_value(19), Ordering::Equal);
+    assert_eq!(r.cmp_value(20), Ordering::Less); // end is excluded
+    assert_eq!(r.cmp_value(100), Ordering::Less);
+}
+
+#[test]
+fn test_cmp_value_empty() {
+    use core::cmp::Ordering;
+
+    let r = SmallRange::<u32>::new(10, 10);
+
+    assert_eq!(r.cmp_value(9), Ordering::Greater);
+    assert_eq!(r.cmp_value(10), Ordering::Less);
+    assert_eq!(r.cmp_value(11), Ordering::Less);
+}
+
+#[test]
+fn test_cmp_value_binary_search() {
+    let spans = [
+        SmallRange::<u32>::new(0, 10),
+        SmallRange::new(10, 25),
+        SmallRange::new(40, 50),
+        SmallRange::new(60, 61),
+    ];
+
+    assert_eq!(spans.binary_search_by(|r| r.cmp_value(0)), Ok(0));
+    assert_eq!(spans.binary_search_by(|r| r.cmp_value(10)), Ok(1));
+    assert_eq!(spans.binary_search_by(|r| r.cmp_value(49)), Ok(2));
+    assert_eq!(spans.binary_search_by(|r| r.cmp_value(60)), Ok(3));
+    assert_eq!(spans.binary_search_by(|r| r.cmp_value(30)), Err(2));
+    assert_eq!(spans.binary_search_by(|r| r.cmp_value(99)), Err(4));
+}
+
+// =============================================================================
+// clamp_value() Tests
+// =============================================================================
+
+#[test]
+fn test_clamp_value_basic() {
+    let r = SmallRange::<u32>::new(10, 20);
+
+    assert_eq!(r.clamp_value(0), 10);
+    assert_eq!(r.clamp_value(10), 10);
+    assert_eq!(r.clamp_value(15), 15);
+    assert_eq!(r.clamp_value(19), 19);
+    assert_eq!(r.clamp_value(20), 19); // end is excluded
+    assert_eq!(r.clamp_value(u32::MAX), 19);
+}
+
+#[test]
+fn test_clamp_value_single_and_empty() {
+    let single = SmallRange::<u32>::new(42, 43);
+    assert_eq!(single.clamp_value(0), 42);
+    assert_eq!(single.clamp_value(100), 42);
+
+    let empty = SmallRange::<u32>::new(7, 7);
+    assert_eq!(empty.clamp_value(0), 7);
+    assert_eq!(empty.clamp_value(7), 7);
+    assert_eq!(empty.clamp_value(100), 7);
+}
+
+// =============================================================================
+// distance_to() Tests
+// =============================================================================
+
+#[test]
+fn test_distance_to_basic() {
+    let r = SmallRange::<u32>::new(10, 20);
+
+    assert_eq!(r.distance_to(10), 0);
+    assert_eq!(r.distance_to(15), 0);
+    assert_eq!(r.distance_to(19), 0);
+
+    assert_eq!(r.distance_to(9), 1);
+    assert_eq!(r.distance_to(0), 10);
+    assert_eq!(r.distance_to(20), 1); // end is excluded
+    assert_eq!(r.distance_to(30), 11);
+}
+
+#[test]
+fn test_distance_to_empty() {
+    let r = SmallRange::<u32>::new(10, 10);
+
+    assert_eq!(r.distance_to(10), 0);
+    assert_eq!(r.distance_to(4), 6);
+    assert_eq!(r.distance_to(15), 5);
+}
+
+// =============================================================================
+// expand_to_include() Tests
+// =============================================================================
+
+#[test]
+fn test_expand_to_include_basic() {
+    let r = SmallRange::<u32>::new(10, 20);
+
+    assert_eq!(r.expand_to_include(25), Some(SmallRange::new(10, 26)));
+    assert_eq!(r.expand_to_include(20), Some(SmallRange::new(10, 21)));
+    assert_eq!(r.expand_to_include(5), Some(SmallRange::new(5, 20)));
+    assert_eq!(r.expand_to_include(10), Some(r));
+    assert_eq!(r.expand_to_include(19), Some(r));
+}
+
+#[test]
+fn test_expand_to_include_empty() {
+    let empty = SmallRange::<u32>::new(100, 100);
+
+    // Empty range position is ignored
+    assert_eq!(empty.expand_to_include(5), Some(SmallRange::new(5, 6)));
+    assert_eq!(
+        empty.expand_to_include(500),
+        Some(SmallRange::new(500, 501))
+    );
+}
+
+#[test]
+fn test_expand_to_include_exceeds_capacity() {
+    let r = SmallRange::<u16>::new(0, 10);
+
+    // Length 254 is the u16 maximum
+    assert_eq!(r.expand_to_include(253), Some(SmallRange::new(0, 254)));
+    assert_eq!(r.expand_to_include(254), None);
+
+    // Single-value range at a start beyond capacity
+    assert_eq!(SmallRange::<u16>::default().expand_to_include(255), None);
+
+    // value + 1 overflows the storage type itself
+    assert_eq!(
+        SmallRange::<u16>::default().expand_to_include(u16::MAX),
+        None
+    );
+}
+
+// =============================================================================
+// contains_range() / is_subset_of() Tests
+// =============================================================================
+
+#[test]
+fn test_contains_range_basic() {
+    let outer = SmallRange::<u32>::new(10, 20);
+
+    assert!(outer.contains_range(&SmallRange::new(12, 18)));
+    assert!(outer.contains_range(&SmallRange::new(10, 20))); // itself
+    assert!(outer.contains_range(&SmallRange::new(10, 11))); // at start
+    assert!(outer.contains_range(&SmallRange::new(19, 20))); // at end
+
+    assert!(!outer.contains_range(&SmallRange::new(9, 15))); // sticks out left
+    assert!(!outer.contains_range(&SmallRange::new(15, 21))); // sticks out right
+    assert!(!outer.contains_range(&SmallRange::new(0, 100))); // superset
+    assert!(!outer.contains_range(&SmallRange::new(30, 40))); // disjoint
+}
+
+#[test]
+fn test_contains_range_empty() {
+    let outer = SmallRange::<u32>::new(10, 20);
+    let empty_inside = SmallRange::<u32>::new(15, 15);
+    let empty_outside = SmallRange::<u32>::new(50, 50);
+
+    // Empty ranges are contained in everything
+    assert!(outer.contains_range(&empty_inside));
+    assert!(outer.contains_range(&empty_outside));
+    assert!(empty_inside.contains_range(&empty_outside));
+
+    // An empty range contains no non-empty range
+    assert!(!empty_inside.contains_range(&SmallRange::new(15, 16)));
+}
+
+#[test]
+fn test_is_subset_of() {
+    let outer = SmallRange::<u32>::new(0, 100);
+    let inner = SmallRange::<u32>::new(25, 75);
+
+    assert!(inner.is_subset_of(&outer));
+    assert!(!outer.is_subset_of(&inner));
+    assert!(outer.is_subset_of(&outer));
+    assert!(SmallRange::<u32>::new(500, 500).is_subset_of(&inner));
+}
+
+// =============================================================================
+// overlaps() Tests
+// =============================================================================
 
 #[test]
 fn test_overlaps_basic() {
     let a = SmallRange::<u32>::new(0, 10);
     let b = SmallRange::<u32>::new(5, 15);
     let c = SmallRange::<u32>::new(10, 20);
-    let d = SmallRange::<u32>::new(20, 30);
+    let d = SmallRange::<u32>::new(20, 30);
+
+    // a and b overlap at 5..10
+    assert!(a.overlaps(&b));
+    assert!(b.overlaps(&a)); // symmetric
+
+    // a ends where c starts - no overlap
+    assert!(!a.overlaps(&c));
+    assert!(!c.overlaps(&a));
+
+    // b and c overlap at 10..15
+    assert!(b.overlaps(&c));
+    assert!(c.overlaps(&b));
+
+    // a and d are far apart
+    assert!(!a.overlaps(&d));
+    assert!(!d.overlaps(&a));
+}
+
+#[test]
+fn test_overlaps_adjacent() {
+    let a = SmallRange::<u32>::new(0, 10);
+    let b = SmallRange::<u32>::new(10, 20);
+
+    // Adjacent ranges don't overlap (a.end == b.start but end is exclusive)
+    assert!(!a.overlaps(&b));
+    assert!(!b.overlaps(&a));
+}
+
+#[test]
+fn test_overlaps_contained() {
+    let outer = SmallRange::<u32>::new(0, 100);
+    let inner = SmallRange::<u32>::new(25, 75);
+
+    // Contained ranges overlap
+    assert!(outer.overlaps(&inner));
+    assert!(inner.overlaps(&outer));
+}
+
+#[test]
+fn test_overlaps_identical() {
+    let a = SmallRange::<u32>::new(10, 20);
+    let b = SmallRange::<u32>::new(10, 20);
+
+    // Identical non-empty ranges overlap
+    assert!(a.overlaps(&b));
+}
+
+#[test]
+fn test_overlaps_empty_range() {
+    let empty = SmallRange::<u32>::new(10, 10);
+    let normal = SmallRange::<u32>::new(5, 15);
+
+    // Empty range doesn't overlap with anything
+    assert!(!empty.overlaps(&normal));
+    assert!(!normal.overlaps(&empty));
+
+    // Empty range doesn't even overlap with itself
+    assert!(!empty.overlaps(&empty));
+}
+
+#[test]
+fn test_overlaps_single_point_shared() {
+    // These share the point at position 10
+    let a = SmallRange::<u32>::new(5, 11);
+    let b = SmallRange::<u32>::new(10, 15);
+
+    assert!(a.overlaps(&b));
+    assert!(b.overlaps(&a));
+}
+
+// =============================================================================
+// overlap_len() Tests
+// =============================================================================
+
+#[test]
+fn test_overlap_len_basic() {
+    let a = SmallRange::<u32>::new(0, 10);
+
+    assert_eq!(a.overlap_len(&SmallRange::new(5, 15)), 5);
+    assert_eq!(SmallRange::<u32>::new(5, 15).overlap_len(&a), 5); // symmetric
+    assert_eq!(a.overlap_len(&SmallRange::new(2, 4)), 2); // contained
+    assert_eq!(a.overlap_len(&a), 10); // identical
+    assert_eq!(a.overlap_len(&SmallRange::new(9, 10)), 1);
+}
+
+#[test]
+fn test_overlap_len_no_overlap() {
+    let a = SmallRange::<u32>::new(0, 10);
+
+    assert_eq!(a.overlap_len(&SmallRange::new(10, 20)), 0); // adjacent
+    assert_eq!(a.overlap_len(&SmallRange::new(50, 60)), 0); // disjoint
+    assert_eq!(a.overlap_len(&SmallRange::new(5, 5)), 0); // empty
+}
+
+// =============================================================================
+// is_adjacent() Tests
+// =============================================================================
+
+#[test]
+fn test_is_adjacent_basic() {
+    let a = SmallRange::<u32>::new(0, 10);
+    let b = SmallRange::<u32>::new(10, 20);
+
+    assert!(a.is_adjacent(&b));
+    assert!(b.is_adjacent(&a)); // symmetric
+}
+
+#[test]
+fn test_is_adjacent_not_touching() {
+    let a = SmallRange::<u32>::new(0, 10);
+
+    // Overlapping ranges are not adjacent
+    assert!(!a.is_adjacent(&SmallRange::new(5, 15)));
+    assert!(!a.is_adjacent(&a));
+
+    // A gap between ranges
+    assert!(!a.is_adjacent(&SmallRange::new(11, 20)));
+}
+
+#[test]
+fn test_is_adjacent_empty() {
+    let a = SmallRange::<u32>::new(0, 10);
+    let empty = SmallRange::<u32>::new(10, 10);
+
+    // Empty ranges are never adjacent, even at the boundary
+    assert!(!a.is_adjacent(&empty));
+    assert!(!empty.is_adjacent(&a));
+}
+
+// =============================================================================
+// union() Tests
+// =============================================================================
+
+#[test]
+fn test_union_overlapping() {
+    let a = SmallRange::<u32>::new(0, 10);
+    let b = SmallRange::<u32>::new(5, 15);
+
+    assert_eq!(a.union(&b), Some(SmallRange::new(0, 15)));
+    assert_eq!(b.union(&a), Some(SmallRange::new(0, 15))); // symmetric
+
+    // Contained range merges into the outer one
+    let inner = SmallRange::<u32>::new(3, 7);
+    assert_eq!(a.union(&inner), Some(a));
+}
+
+#[test]
+fn test_union_adjacent() {
+    let a = SmallRange::<u32>::new(0, 10);
+    let b = SmallRange::<u32>::new(10, 20);
+
+    // Touching ranges merge even though they don't overlap
+    assert_eq!(a.union(&b), Some(SmallRange::new(0, 20)));
+    assert_eq!(b.union(&a), Some(SmallRange::new(0, 20)));
+}
+
+#[test]
+fn test_union_disjoint() {
+    let a = SmallRange::<u32>::new(0, 10);
+    let b = SmallRange::<u32>::new(11, 20);
+
+    assert_eq!(a.union(&b), None);
+    assert_eq!(b.union(&a), None);
+}
+
+#[test]
+fn test_union_empty() {
+    let a = SmallRange::<u32>::new(0, 10);
+    let empty = SmallRange::<u32>::new(50, 50);
+
+    // Empty ranges contribute nothing, wherever they are
+    assert_eq!(a.union(&empty), Some(a));
+    assert_eq!(empty.union(&a), Some(a));
+    assert_eq!(empty.union(&empty), Some(empty));
+}
+
+#[test]
+fn test_union_exceeds_capacity() {
+    // Both fit in u16, but the merged length (0..300) does not
+    let a = SmallRange::<u16>::new(0, 200);
+    let b = SmallRange::<u16>::new(200, 300);
+
+    assert_eq!(a.union(&b), None);
+}
+
+// =============================================================================
+// hull() Tests
+// =============================================================================
+
+#[test]
+fn test_hull_disjoint() {
+    let a = SmallRange::<u32>::new(0, 10);
+    let b = SmallRange::<u32>::new(30, 40);
+
+    // Gap between the ranges is covered
+    assert_eq!(a.hull(&b), SmallRange::new(0, 40));
+    assert_eq!(b.hull(&a), SmallRange::new(0, 40));
+}
+
+#[test]
+fn test_hull_overlapping_and_contained() {
+    let a = SmallRange::<u32>::new(0, 10);
+    let b = SmallRange::<u32>::new(5, 15);
+    let inner = SmallRange::<u32>::new(3, 7);
+
+    assert_eq!(a.hull(&b), SmallRange::new(0, 15));
+    assert_eq!(a.hull(&inner), a);
+    assert_eq!(a.hull(&a), a);
+}
+
+#[test]
+fn test_hull_empty() {
+    let a = SmallRange::<u32>::new(10, 20);
+    let empty = SmallRange::<u32>::new(100, 100);
+
+    // Empty ranges don't stretch the hull
+    assert_eq!(a.hull(&empty), a);
+    assert_eq!(empty.hull(&a), a);
+}
+
+// =============================================================================
+// difference() Tests
+// =============================================================================
+
+#[test]
+fn test_difference_hole_in_middle() {
+    let a = SmallRange::<u32>::new(0, 20);
+    let b = SmallRange::<u32>::new(5, 10);
+
+    assert_eq!(
+        a.difference(&b).unwrap(),
+        (Some(SmallRange::new(0, 5)), Some(SmallRange::new(10, 20)))
+    );
+}
+
+#[test]
+fn test_difference_partial_overlap() {
+    let a = SmallRange::<u32>::new(10, 20);
+
+    // Other covers the start of a: only the right piece remains
+    let front = SmallRange::<u32>::new(5, 15);
+    assert_eq!(
+        a.difference(&front).unwrap(),
+        (None, Some(SmallRange::new(15, 20)))
+    );
+
+    // Other covers the end of a: only the left piece remains
+    let back = SmallRange::<u32>::new(15, 25);
+    assert_eq!(
+        a.difference(&back).unwrap(),
+        (Some(SmallRange::new(10, 15)), None)
+    );
+}
+
+#[test]
+fn test_difference_disjoint() {
+    let a = SmallRange::<u32>::new(10, 20);
+
+    // Other entirely after a: a is returned as the left piece
+    let after = SmallRange::<u32>::new(20, 30);
+    assert_eq!(a.difference(&after).unwrap(), (Some(a), None));
+
+    // Other entirely before a: a is returned as the right piece
+    let before = SmallRange::<u32>::new(0, 10);
+    assert_eq!(a.difference(&before).unwrap(), (None, Some(a)));
+}
+
+#[test]
+fn test_difference_fully_covered() {
+    let a = SmallRange::<u32>::new(10, 20);
+
+    assert_eq!(a.difference(&a).unwrap(), (None, None));
+    assert_eq!(
+        a.difference(&SmallRange::new(0, 100)).unwrap(),
+        (None, None)
+    );
+}
+
+#[test]
+fn test_difference_empty() {
+    let a = SmallRange::<u32>::new(10, 20);
+    let empty = SmallRange::<u32>::new(15, 15);
+
+    // Removing an empty range leaves a intact (not split at 15)
+    assert_eq!(a.difference(&empty).unwrap(), (Some(a), None));
+
+    // Nothing remains of an empty range
+    assert_eq!(empty.difference(&a).unwrap(), (None, None));
+}
+
+#[test]
+fn test_difference_at_capacity_edge() {
+    // u8 starts max out at 14: 20..28 can't be stored, 14..28 can
+    let a = SmallRange::<u8>::new(14, 28);
+    assert_eq!(
+        a.difference(&SmallRange::new(14, 20)),
+        Err(SmallRangeError::StartTooLarge)
+    );
+    assert_eq!(a.difference(&SmallRange::new(0, 14)), Ok((None, Some(a))));
+    assert_eq!(
+        SmallRange::<u8>::new(10, 24).difference(&SmallRange::new(12, 14)),
+        Ok((Some(SmallRange::new(10, 12)), Some(SmallRange::new(14, 24))))
+    );
+
+    // u16 starts max out at 254
+    let b = SmallRange::<u16>::new(100, 354);
+    assert_eq!(
+        b.difference(&SmallRange::new(250, 300)),
+        Err(SmallRangeError::StartTooLarge)
+    );
+    assert_eq!(
+        b.difference(&SmallRange::new(250, 254)),
+        Ok((
+            Some(SmallRange::new(100, 250)),
+            Some(SmallRange::new(254, 354))
+        ))
+    );
+}
+
+// =============================================================================
+// symmetric_difference() Tests
+// =============================================================================
+
+#[test]
+fn test_symmetric_difference_overlapping() {
+    let a = SmallRange::<u32>::new(0, 10);
+    let b = SmallRange::<u32>::new(5, 15);
+    let expected = (Some(SmallRange::new(0, 5)), Some(SmallRange::new(10, 15)));
+
+    assert_eq!(a.symmetric_difference(&b).unwrap(), expected);
+    assert_eq!(b.symmetric_difference(&a).unwrap(), expected); // symmetric
+}
+
+#[test]
+fn test_symmetric_difference_shared_endpoint() {
+    let a = SmallRange::<u32>::new(0, 10);
+
+    // Same start: only the right piece
+    let b = SmallRange::<u32>::new(0, 4);
+    assert_eq!(
+        a.symmetric_difference(&b).unwrap(),
+        (None, Some(SmallRange::new(4, 10)))
+    );
+
+    // Same end: only the left piece
+    let c = SmallRange::<u32>::new(6, 10);
+    assert_eq!(
+        a.symmetric_difference(&c).unwrap(),
+        (Some(SmallRange::new(0, 6)), None)
+    );
+
+    // Identical: nothing
+    assert_eq!(a.symmetric_difference(&a).unwrap(), (None, None));
+}
+
+#[test]
+fn test_symmetric_difference_disjoint() {
+    let a = SmallRange::<u32>::new(0, 10);
+    let b = SmallRange::<u32>::new(20, 30);
+
+    // Lower range always comes first
+    assert_eq!(a.symmetric_difference(&b).unwrap(), (Some(a), Some(b)));
+    assert_eq!(b.symmetric_difference(&a).unwrap(), (Some(a), Some(b)));
+
+    // Adjacent ranges come back as two touching pieces
+    let c = SmallRange::<u32>::new(10, 20);
+    assert_eq!(a.symmetric_difference(&c).unwrap(), (Some(a), Some(c)));
+}
+
+#[test]
+fn test_symmetric_difference_empty() {
+    let a = SmallRange::<u32>::new(10, 20);
+    let empty = SmallRange::<u32>::new(15, 15);
+
+    assert_eq!(a.symmetric_difference(&empty).unwrap(), (Some(a), None));
+    assert_eq!(empty.symmetric_difference(&a).unwrap(), (Some(a), None));
+    assert_eq!(empty.symmetric_difference(&empty).unwrap(), (None, None));
+}
+
+#[test]
+fn test_symmetric_difference_at_capacity_edge() {
+    // u8: the right piece 14..20 fits, 15..20 doesn't
+    let a = SmallRange::<u8>::new(0, 14);
+    assert_eq!(
+        a.symmetric_difference(&SmallRange::new(10, 20)),
+        Ok((Some(SmallRange::new(0, 10)), Some(SmallRange::new(14, 20))))
+    );
+    let b = SmallRange::<u8>::new(1, 15);
+    assert_eq!(
+        b.symmetric_difference(&SmallRange::new(10, 20)),
+        Err(SmallRangeError::StartTooLarge)
+    );
+
+    // u16: 254..400 fits, 255..400 doesn't
+    let c = SmallRange::<u16>::new(200, 400);
+    assert_eq!(
+        SmallRange::new(0, 254).symmetric_difference(&c),
+        Ok((
+            Some(SmallRange::new(0, 200)),
+            Some(SmallRange::new(254, 400))
+        ))
+    );
+    assert_eq!(
+        SmallRange::new(1, 255).symmetric_difference(&c),
+        Err(SmallRangeError::StartTooLarge)
+    );
+}
+
+// =============================================================================
+// gap_between() Tests
+// =============================================================================
+
+#[test]
+fn test_gap_between_disjoint() {
+    let a = SmallRange::<u32>::new(0, 10);
+    let b = SmallRange::<u32>::new(25, 30);
+
+    assert_eq!(a.gap_between(&b), Some(SmallRange::new(10, 25)));
+    assert_eq!(b.gap_between(&a), Some(SmallRange::new(10, 25))); // symmetric
+
+    // Single-value gap
+    let c = SmallRange::<u32>::new(11, 20);
+    assert_eq!(a.gap_between(&c), Some(SmallRange::new(10, 11)));
+}
+
+#[test]
+fn test_gap_between_no_gap() {
+    let a = SmallRange::<u32>::new(0, 10);
+
+    assert_eq!(a.gap_between(&SmallRange::new(10, 20)), None); // touching
+    assert_eq!(a.gap_between(&SmallRange::new(5, 15)), None); // overlapping
+    assert_eq!(a.gap_between(&a), None);
+}
+
+#[test]
+fn test_gap_between_empty() {
+    let a = SmallRange::<u32>::new(0, 10);
+    let empty = SmallRange::<u32>::new(50, 50);
+
+    assert_eq!(a.gap_between(&empty), None);
+    assert_eq!(empty.gap_between(&a), None);
+}
+
+// =============================================================================
+// relation() Tests
+// =============================================================================
+
+#[test]
+fn test_relation_all_variants() {
+    use crate::IntervalRelation::*;
+
+    let a = SmallRange::<u32>::new(10, 20);
+    let cases = [
+        (SmallRange::new(25, 30), Before),
+        (SmallRange::new(20, 30), Meets),
+        (SmallRange::new(15, 30), Overlaps),
+        (SmallRange::new(10, 30), Starts),
+        (SmallRange::new(5, 30), During),
+        (SmallRange::new(5, 20), Finishes),
+        (SmallRange::new(10, 20), Equals),
+        (SmallRange::new(15, 20), FinishedBy),
+        (SmallRange::new(12, 18), Contains),
+        (SmallRange::new(10, 15), StartedBy),
+        (SmallRange::new(5, 15), OverlappedBy),
+        (SmallRange::new(5, 10), MetBy),
+        (SmallRange::new(0, 5), After),
+    ];
+
+    for (b, expected) in cases {
+        assert_eq!(a.relation(&b), expected, "{:?} vs {:?}", a, b);
+        assert_eq!(b.relation(&a), expected.inverse(), "{:?} vs {:?}", b, a);
+    }
+}
+
+#[test]
+fn test_relation_empty() {
+    use crate::IntervalRelation::*;
+
+    let a = SmallRange::<u32>::new(10, 20);
+
+    // Empty ranges behave like points
+    assert_eq!(a.relation(&SmallRange::new(15, 15)), Contains);
+    assert_eq!(a.relation(&SmallRange::new(10, 10)), MetBy);
+    assert_eq!(a.relation(&SmallRange::new(20, 20)), Meets);
+    assert_eq!(a.relation(&SmallRange::new(30, 30)), Before);
+
+    let empty = SmallRange::<u32>::new(5, 5);
+    assert_eq!(empty.relation(&empty), Equals);
+}
+
+#[test]
+fn test_relation_inverse_is_involution() {
+    use crate::IntervalRelation::*;
+
+    for relation in [
+        Before,
+        Meets,
+        Overlaps,
+        Starts,
+        During,
+        Finishes,
+        Equals,
+        FinishedBy,
+        Contains,
+        StartedBy,
+        OverlappedBy,
+        MetBy,
+        After,
+    ] {
+        assert_eq!(relation.inverse().inverse(), relation);
+    }
+}
+
+// =============================================================================
+// split_at() Tests
+// =============================================================================
+
+#[test]
+fn test_split_at_interior() {
+    let r = SmallRange::<u32>::new(10, 20);
+    let (left, right) = r.split_at(13);
+
+    assert_eq!(left, SmallRange::new(10, 13));
+    assert_eq!(right, SmallRange::new(13, 20));
+    assert_eq!(left.len() + right.len(), r.len());
+}
+
+#[test]
+fn test_split_at_endpoints() {
+    let r = SmallRange::<u32>::new(10, 20);
+
+    assert_eq!(r.split_at(10), (SmallRange::new(10, 10), r));
+    assert_eq!(r.split_at(20), (r, SmallRange::new(20, 20)));
+}
+
+#[test]
+#[should_panic(expected = "mid out of range")]
+fn test_split_at_panics_out_of_range() {
+    SmallRange::<u32>::new(10, 20).split_at(21);
+}
+
+#[test]
+fn test_checked_split_at() {
+    let r = SmallRange::<u32>::new(10, 20);
+
+    assert_eq!(
+        r.checked_split_at(15),
+        Some((SmallRange::new(10, 15), SmallRange::new(15, 20)))
+    );
+    assert_eq!(r.checked_split_at(9), None);
+    assert_eq!(r.checked_split_at(21), None);
+}
+
+#[test]
+fn test_checked_split_at_exceeds_capacity() {
+    // The right piece would start at 300, beyond u16's max start of 254
+    let r = SmallRange::<u16>::new(200, 400);
+
+    assert_eq!(r.checked_split_at(300), None);
+    assert!(r.checked_split_at(254).is_some());
+}
+
+// =============================================================================
+// take_front() / take_back() Tests
+// =============================================================================
+
+#[test]
+fn test_take_front() {
+    let r = SmallRange::<u32>::new(10, 20);
+
+    assert_eq!(
+        r.take_front(3),
+        Some((SmallRange::new(10, 13), SmallRange::new(13, 20)))
+    );
+    assert_eq!(r.take_front(0), Some((SmallRange::new(10, 10), r)));
+    assert_eq!(r.take_front(10), Some((r, SmallRange::new(20, 20))));
+    assert_eq!(r.take_front(11), None);
+}
+
+#[test]
+fn test_take_back() {
+    let r = SmallRange::<u32>::new(10, 20);
+
+    assert_eq!(
+        r.take_back(3),
+        Some((SmallRange::new(17, 20), SmallRange::new(10, 17)))
+    );
+    assert_eq!(r.take_back(0), Some((SmallRange::new(20, 20), r)));
+    assert_eq!(r.take_back(10), Some((r, SmallRange::new(10, 10))));
+    assert_eq!(r.take_back(11), None);
+}
+
+#[test]
+fn test_take_exceeds_capacity() {
+    // Pieces starting past 254 can't be represented in u16
+    let r = SmallRange::<u16>::new(200, 400);
+
+    assert_eq!(r.take_front(100), None);
+    assert_eq!(r.take_back(100), None);
+    assert!(r.take_front(54).is_some());
+    assert!(r.take_back(146).is_some());
+}
+
+// =============================================================================
+// bisect() Tests
+// =============================================================================
+
+#[test]
+fn test_bisect_even_and_odd() {
+    let (left, right) = SmallRange::<u32>::new(10, 20).bisect();
+    assert_eq!(left, SmallRange::new(10, 15));
+    assert_eq!(right, SmallRange::new(15, 20));
+
+    let (left, right) = SmallRange::<u32>::new(10, 21).bisect();
+    assert_eq!(left, SmallRange::new(10, 15));
+    assert_eq!(right, SmallRange::new(15, 21)); // right gets the extra element
+}
+
+#[test]
+fn test_bisect_small() {
+    let single = SmallRange::<u32>::new(42, 43);
+    assert_eq!(single.bisect(), (SmallRange::new(42, 42), single));
+
+    let empty = SmallRange::<u32>::new(42, 42);
+    assert_eq!(empty.bisect(), (empty, empty));
+}
+
+// =============================================================================
+// partition() Tests
+// =============================================================================
+
+#[test]
+fn test_partition_even() {
+    let parts: Vec<_> = SmallRange::<u32>::new(0, 12).partition(3).collect();
+    assert_eq!(
+        parts,
+        vec![
+            SmallRange::new(0, 4),
+            SmallRange::new(4, 8),
+            SmallRange::new(8, 12)
+        ]
+    );
+}
+
+#[test]
+fn test_partition_uneven() {
+    // 11 = 3 + 3 + 3 + 2: the first len % parts pieces are longer
+    let parts: Vec<_> = SmallRange::<u32>::new(5, 16).partition(4).collect();
+    assert_eq!(
+        parts,
+        vec![
+            SmallRange::new(5, 8),
+            SmallRange::new(8, 11),
+            SmallRange::new(11, 14),
+            SmallRange::new(14, 16)
+        ]
+    );
+}
+
+#[test]
+fn test_partition_more_parts_than_len() {
+    let parts: Vec<_> = SmallRange::<u32>::new(0, 2).partition(4).collect();
+    assert_eq!(
+        parts,
+        vec![
+            SmallRange::new(0, 1),
+            SmallRange::new(1, 2),
+            SmallRange::new(2, 2),
+            SmallRange::new(2, 2)
+        ]
+    );
+}
+
+#[test]
+fn test_partition_exact_size() {
+    let mut iter = SmallRange::<u32>::new(0, 100).partition(7);
+    assert_eq!(iter.len(), 7);
+    iter.next();
+    assert_eq!(iter.len(), 6);
+    assert_eq!(iter.by_ref().count(), 6);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+#[should_panic(expected = "parts must be non-zero")]
+fn test_partition_panics_on_zero_parts() {
+    SmallRange::<u32>::new(0, 10).partition(0);
+}
+
+// =============================================================================
+// chunks() Tests
+// =============================================================================
+
+#[test]
+fn test_chunks_with_remainder() {
+    let chunks: Vec<_> = SmallRange::<u32>::new(10, 20).chunks(4).collect();
+    assert_eq!(
+        chunks,
+        vec![
+            SmallRange::new(10, 14),
+            SmallRange::new(14, 18),
+            SmallRange::new(18, 20)
+        ]
+    );
+}
+
+#[test]
+fn test_chunks_exact_multiple() {
+    let chunks: Vec<_> = SmallRange::<u32>::new(0, 9).chunks(3).collect();
+    assert_eq!(
+        chunks,
+        vec![
+            SmallRange::new(0, 3),
+            SmallRange::new(3, 6),
+            SmallRange::new(6, 9)
+        ]
+    );
+}
+
+#[test]
+fn test_chunks_larger_than_range() {
+    let r = SmallRange::<u32>::new(0, 5);
+    let chunks: Vec<_> = r.chunks(100).collect();
+    assert_eq!(chunks, vec![r]);
+
+    // No overflow when start + chunk_len exceeds the storage type
+    let chunks: Vec<_> = r.chunks(u32::MAX).collect();
+    assert_eq!(chunks, vec![r]);
+}
+
+#[test]
+fn test_chunks_empty() {
+    assert_eq!(SmallRange::<u32>::new(5, 5).chunks(3).count(), 0);
+}
+
+#[test]
+fn test_chunks_reverse_and_exact_size() {
+    let r = SmallRange::<u32>::new(10, 20);
+
+    let mut iter = r.chunks(4);
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next_back(), Some(SmallRange::new(18, 20))); // remainder first
+    assert_eq!(iter.next_back(), Some(SmallRange::new(14, 18)));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next(), Some(SmallRange::new(10, 14)));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+#[should_panic(expected = "chunk_len must be non-zero")]
+fn test_chunks_panics_on_zero() {
+    SmallRange::<u32>::new(0, 10).chunks(0);
+}
+
+// =============================================================================
+// windows() Tests
+// =============================================================================
+
+#[test]
+fn test_windows_basic() {
+    let windows: Vec<_> = SmallRange::<u32>::new(10, 15).windows(3).collect();
+    assert_eq!(
+        windows,
+        vec![
+            SmallRange::new(10, 13),
+            SmallRange::new(11, 14),
+            SmallRange::new(12, 15)
+        ]
+    );
+}
+
+#[test]
+fn test_windows_size_edge_cases() {
+    let r = SmallRange::<u32>::new(10, 15);
+
+    // Window covering the whole range
+    assert_eq!(r.windows(5).collect::<Vec<_>>(), vec![r]);
+
+    // Window longer than the range
+    assert_eq!(r.windows(6).count(), 0);
+
+    // Single-value windows
+    assert_eq!(r.windows(1).count(), 5);
+
+    // Empty range
+    assert_eq!(SmallRange::<u32>::new(5, 5).windows(1).count(), 0);
+}
+
+#[test]
+fn test_windows_reverse_and_exact_size() {
+    let mut iter = SmallRange::<u32>::new(0, 5).windows(2);
+
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.next_back(), Some(SmallRange::new(3, 5)));
+    assert_eq!(iter.next(), Some(SmallRange::new(0, 2)));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.next_back(), Some(SmallRange::new(2, 4)));
+    assert_eq!(iter.next(), Some(SmallRange::new(1, 3)));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+#[should_panic(expected = "size must be non-zero")]
+fn test_windows_panics_on_zero() {
+    SmallRange::<u32>::new(0, 10).windows(0);
+}
+
+// =============================================================================
+// iter_step() Tests
+// =============================================================================
+
+#[test]
+fn test_iter_step_basic() {
+    let r = SmallRange::<u32>::new(10, 20);
+
+    assert_eq!(r.iter_step(3).collect::<Vec<_>>(), vec![10, 13, 16, 19]);
+    assert_eq!(r.iter_step(5).collect::<Vec<_>>(), vec![10, 15]);
+    assert_eq!(
+        r.iter_step(1).collect::<Vec<_>>(),
+        r.into_iter().collect::<Vec<_>>()
+    );
+    assert_eq!(r.iter_step(100).collect::<Vec<_>>(), vec![10]);
+}
+
+#[test]
+fn test_iter_step_empty() {
+    assert_eq!(SmallRange::<u32>::new(5, 5).iter_step(2).count(), 0);
+}
+
+#[test]
+fn test_iter_step_u64_exact_size() {
+    // Range<u64>::step_by isn't ExactSizeIterator; iter_step is
+    let mut iter = SmallRange::<u64>::new(0, 100).iter_step(7);
+
+    assert_eq!(iter.len(), 15);
+    assert_eq!(iter.next_back(), Some(98));
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.len(), 13);
+    assert_eq!(iter.next_back(), Some(91));
+}
+
+#[test]
+fn test_iter_step_at_capacity() {
+    // Stepping must not overflow past the last value
+    let r = SmallRange::<u16>::new(254, 254 + 254);
+    let values: Vec<_> = r.iter_step(200).collect();
+    assert_eq!(values, vec![254, 454]);
+}
+
+#[test]
+fn test_iter_step_larger_than_len() {
+    // A step wider than usize on 32-bit targets must not be truncated
+    let r = SmallRange::<u64>::new(10, 20);
+    let iter = r.iter_step(1 << 40);
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![10]);
+    assert_eq!(r.iter_step(u64::MAX).collect::<Vec<_>>(), vec![10]);
+}
+
+#[test]
+#[should_panic(expected = "step must be non-zero")]
+fn test_iter_step_panics_on_zero() {
+    SmallRange::<u32>::new(0, 10).iter_step(0);
+}
+
+// =============================================================================
+// align_start_up() / align_end_down() Tests
+// =============================================================================
+
+#[test]
+fn test_align_start_up() {
+    let r = SmallRange::<u32>::new(100, 300);
+
+    assert_eq!(r.align_start_up(1), Some(r));
+    assert_eq!(r.align_start_up(4), Some(r)); // already aligned
+    assert_eq!(r.align_start_up(16), Some(SmallRange::new(112, 300)));
+    assert_eq!(r.align_start_up(256), Some(SmallRange::new(256, 300)));
+    assert_eq!(r.align_start_up(512), None);
+
+    // Rounding exactly to the end leaves an empty range
+    let r = SmallRange::<u32>::new(100, 128);
+    assert_eq!(r.align_start_up(64), Some(SmallRange::new(128, 128)));
+}
+
+#[test]
+fn test_align_start_up_exceeds_capacity() {
+    // Rounded start 256 is beyond u16's max start of 254
+    let r = SmallRange::<u16>::new(250, 300);
+    assert_eq!(r.align_start_up(128), None);
+    assert_eq!(r.align_start_up(2), Some(SmallRange::new(250, 300)));
+}
+
+#[test]
+fn test_align_end_down() {
+    let r = SmallRange::<u32>::new(100, 300);
+
+    assert_eq!(r.align_end_down(1), Some(r));
+    assert_eq!(r.align_end_down(4), Some(r)); // already aligned
+    assert_eq!(r.align_end_down(64), Some(SmallRange::new(100, 256)));
+    assert_eq!(r.align_end_down(128), Some(SmallRange::new(100, 256)));
+    assert_eq!(r.align_end_down(256), Some(SmallRange::new(100, 256)));
+    assert_eq!(r.align_end_down(512), None);
+}
+
+#[test]
+fn test_align_both_ends() {
+    let r = SmallRange::<u32>::new(100, 300);
+    let aligned = r.align_start_up(64).and_then(|r| r.align_end_down(64));
+    assert_eq!(aligned, Some(SmallRange::new(128, 256)));
+}
+
+#[test]
+#[should_panic(expected = "align must be a power of two")]
+fn test_align_panics_on_non_power_of_two() {
+    let _ = SmallRange::<u32>::new(0, 100).align_start_up(12);
+}
+
+// =============================================================================
+// aligned_subrange() Tests
+// =============================================================================
+
+#[test]
+fn test_aligned_subrange_power_of_two() {
+    let r = SmallRange::<u32>::new(100, 300);
+
+    assert_eq!(r.aligned_subrange(1), Some(r));
+    assert_eq!(r.aligned_subrange(64), Some(SmallRange::new(128, 256)));
+    assert_eq!(r.aligned_subrange(128), Some(SmallRange::new(128, 256)));
+    assert_eq!(r.aligned_subrange(256), None);
+
+    // Already aligned on both ends
+    let r = SmallRange::<u32>::new(64, 192);
+    assert_eq!(r.aligned_subrange(64), Some(r));
+}
+
+#[test]
+fn test_aligned_subrange_arbitrary_alignment() {
+    let r = SmallRange::<u32>::new(10, 100);
+
+    assert_eq!(r.aligned_subrange(3), Some(SmallRange::new(12, 99)));
+    assert_eq!(r.aligned_subrange(45), Some(SmallRange::new(45, 90)));
+    assert_eq!(r.aligned_subrange(91), None);
+}
+
+#[test]
+fn test_aligned_subrange_no_block() {
+    // Rounded start and end meet at 64: no complete block
+    assert_eq!(SmallRange::<u32>::new(50, 70).aligned_subrange(64), None);
+    assert_eq!(SmallRange::<u32>::new(5, 5).aligned_subrange(1), None);
+}
+
+#[test]
+#[should_panic(expected = "align must be non-zero")]
+fn test_aligned_subrange_panics_on_zero() {
+    let _ = SmallRange::<u32>::new(0, 100).aligned_subrange(0);
+}
+
+// =============================================================================
+// pages() Tests
+// =============================================================================
+
+#[test]
+fn test_pages_head_full_tail() {
+    let pieces: Vec<_> = SmallRange::<u32>::new(10, 45).pages(16).collect();
+    assert_eq!(
+        pieces,
+        vec![
+            SmallRange::new(10, 16),
+            SmallRange::new(16, 32),
+            SmallRange::new(32, 45)
+        ]
+    );
+}
+
+#[test]
+fn test_pages_aligned() {
+    let pieces: Vec<_> = SmallRange::<u32>::new(16, 48).pages(16).collect();
+    assert_eq!(
+        pieces,
+        vec![SmallRange::new(16, 32), SmallRange::new(32, 48)]
+    );
+}
+
+#[test]
+fn test_pages_within_single_page() {
+    let r = SmallRange::<u32>::new(20, 30);
+    assert_eq!(r.pages(16).collect::<Vec<_>>(), vec![r]);
+
+    // Ending exactly on a boundary
+    let r = SmallRange::<u32>::new(20, 32);
+    assert_eq!(r.pages(16).collect::<Vec<_>>(), vec![r]);
+
+    assert_eq!(SmallRange::<u32>::new(20, 20).pages(16).count(), 0);
+}
+
+#[test]
+fn test_pages_reverse_and_exact_size() {
+    let mut iter = SmallRange::<u32>::new(10, 45).pages(16);
+
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next_back(), Some(SmallRange::new(32, 45)));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.next_back(), Some(SmallRange::new(16, 32)));
+    assert_eq!(iter.next_back(), Some(SmallRange::new(10, 16)));
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+#[should_panic(expected = "page_size must be non-zero")]
+fn test_pages_panics_on_zero() {
+    SmallRange::<u32>::new(0, 100).pages(0);
+}
+
+// =============================================================================
+// checked_translate() Tests
+// =============================================================================
+
+#[test]
+fn test_checked_translate() {
+    let r = SmallRange::<u32>::new(10, 20);
+
+    assert_eq!(r.checked_translate(0), Some(r));
+    assert_eq!(r.checked_translate(5), Some(SmallRange::new(15, 25)));
+
+    // Empty ranges move too
+    let empty = SmallRange::<u32>::new(10, 10);
+    assert_eq!(empty.checked_translate(5), Some(SmallRange::new(15, 15)));
+}
+
+#[test]
+fn test_checked_translate_exceeds_capacity() {
+    let r = SmallRange::<u16>::new(200, 210);
+
+    assert_eq!(r.checked_translate(54), Some(SmallRange::new(254, 264)));
+    assert_eq!(r.checked_translate(55), None);
+
+    // Overflow of the storage type itself
+    assert_eq!(r.checked_translate(u16::MAX), None);
+}
+
+#[test]
+fn test_checked_translate_signed() {
+    let r = SmallRange::<u32>::new(10, 20);
+
+    assert_eq!(r.checked_translate_signed(0), Some(r));
+    assert_eq!(r.checked_translate_signed(7), Some(SmallRange::new(17, 27)));
+    assert_eq!(
+        r.checked_translate_signed(-10),
+        Some(SmallRange::new(0, 10))
+    );
+    assert_eq!(r.checked_translate_signed(-11), None);
+    assert_eq!(r.checked_translate_signed(70000), None);
+    assert_eq!(r.checked_translate_signed(i64::MIN), None);
+    assert_eq!(r.checked_translate_signed(i64::MAX), None);
+}
+
+// =============================================================================
+// Add / Sub Operator Tests
+// =============================================================================
+
+#[test]
+fn test_add_offset() {
+    let r = SmallRange::<u32>::new(10, 20);
+
+    assert_eq!(r + 0, r);
+    assert_eq!(r + 16, SmallRange::new(26, 36));
+    assert_eq!((r + 16).len(), r.len());
+
+    let mut shifted = r;
+    shifted += 5;
+    assert_eq!(shifted, SmallRange::new(15, 25));
+}
+
+#[test]
+fn test_sub_offset() {
+    let r = SmallRange::<u32>::new(10, 20);
+
+    assert_eq!(r - 0, r);
+    assert_eq!(r - 10, SmallRange::new(0, 10));
+
+    let mut shifted = r;
+    shifted -= 5;
+    assert_eq!(shifted, SmallRange::new(5, 15));
+}
+
+// =============================================================================
+// checked_scale() / checked_mul_len() Tests
+// =============================================================================
+
+#[test]
+fn test_checked_scale() {
+    let r = SmallRange::<u32>::new(10, 20);
+
+    assert_eq!(r.checked_scale(1), Some(r));
+    assert_eq!(r.checked_scale(4), Some(SmallRange::new(40, 80)));
+    assert_eq!(r.checked_scale(0), Some(SmallRange::new(0, 0)));
+}
+
+#[test]
+fn test_checked_scale_exceeds_capacity() {
+    let r = SmallRange::<u16>::new(100, 110);
+
+    assert_eq!(r.checked_scale(2), Some(SmallRange::new(200, 220)));
+    assert_eq!(r.checked_scale(3), None); // start 300 > 254
+    assert_eq!(r.checked_scale(u16::MAX), None); // storage overflow
+}
+
+#[test]
+fn test_checked_mul_len() {
+    let r = SmallRange::<u32>::new(10, 20);
+
+    assert_eq!(r.checked_mul_len(1), Some(r));
+    assert_eq!(r.checked_mul_len(3), Some(SmallRange::new(10, 40)));
+    assert_eq!(r.checked_mul_len(0), Some(SmallRange::new(10, 10)));
+
+    // Length 300 exceeds u16 capacity
+    assert_eq!(SmallRange::<u16>::new(0, 100).checked_mul_len(3), None);
+}
+
+// =============================================================================
+// expand() / shrink() Tests
+// =============================================================================
+
+#[test]
+fn test_expand() {
+    let r = SmallRange::<u32>::new(40, 45);
+
+    assert_eq!(r.expand(0), r);
+    assert_eq!(r.expand(10), SmallRange::new(30, 55));
+    assert_eq!(r.expand(40), SmallRange::new(0, 85));
+}
+
+#[test]
+fn test_checked_expand() {
+    let r = SmallRange::<u32>::new(40, 45);
+
+    assert_eq!(r.checked_expand(10), Some(SmallRange::new(30, 55)));
+    assert_eq!(r.checked_expand(40), Some(SmallRange::new(0, 85)));
+    assert_eq!(r.checked_expand(41), None); // start underflows
+
+    // Length 250 + 2*3 exceeds u16's 254
+    let wide = SmallRange::<u16>::new(3, 253);
+    assert_eq!(wide.checked_expand(2), Some(SmallRange::new(1, 255)));
+    assert_eq!(wide.checked_expand(3), None);
+}
+
+#[test]
+fn test_saturating_expand() {
+    let r = SmallRange::<u32>::new(5, 10);
+
+    assert_eq!(r.saturating_expand(3), SmallRange::new(2, 13));
+    assert_eq!(r.saturating_expand(10), SmallRange::new(0, 20));
+
+    // Length clamped to capacity
+    let wide = SmallRange::<u16>::new(10, 200);
+    assert_eq!(wide.saturating_expand(100), SmallRange::new(0, 254));
+    assert_eq!(wide.saturating_expand(u16::MAX), SmallRange::new(0, 254));
+}
+
+#[test]
+fn test_shrink() {
+    let r = SmallRange::<u32>::new(30, 55);
+
+    assert_eq!(r.shrink(0), r);
+    assert_eq!(r.shrink(10), SmallRange::new(40, 45));
+    assert_eq!(
+        SmallRange::<u32>::new(30, 50).shrink(10),
+        SmallRange::new(40, 40)
+    );
+}
+
+#[test]
+fn test_checked_shrink() {
+    let r = SmallRange::<u32>::new(30, 55);
+
+    assert_eq!(r.checked_shrink(10), Some(SmallRange::new(40, 45)));
+    assert_eq!(r.checked_shrink(12), Some(SmallRange::new(42, 43)));
+    assert_eq!(r.checked_shrink(13), None);
+    assert_eq!(r.checked_shrink(u32::MAX), None);
+
+    // Shrunk start 260 exceeds u16's max start of 254
+    assert_eq!(SmallRange::<u16>::new(200, 400).checked_shrink(60), None);
+}
+
+#[test]
+fn test_saturating_shrink() {
+    let r = SmallRange::<u32>::new(30, 55);
+
+    assert_eq!(r.saturating_shrink(10), SmallRange::new(40, 45));
+    assert_eq!(r.saturating_shrink(13), SmallRange::new(42, 42));
+    assert_eq!(r.saturating_shrink(u32::MAX), SmallRange::new(42, 42));
+
+    let empty = SmallRange::<u32>::new(7, 7);
+    assert_eq!(empty.saturating_shrink(1), empty);
+}
+
+// =============================================================================
+// with_start() / with_end() / with_len() Tests
+// =============================================================================
+
+#[test]
+fn test_with_start() {
+    let r = SmallRange::<u32>::new(10, 20);
+
+    assert_eq!(r.with_start(0), Some(SmallRange::new(0, 20)));
+    assert_eq!(r.with_start(20), Some(SmallRange::new(20, 20)));
+    assert_eq!(r.with_start(21), None); // past the end
 
-    // a and b overlap at 5..10
-    assert!(a.overlaps(&b));
-    assert!(b.overlaps(&a)); // symmetric
+    // Length 300 exceeds u16 capacity
+    assert_eq!(SmallRange::<u16>::new(250, 300).with_start(0), None);
+}
 
-    // a ends where c starts - no overlap
-    assert!(!a.overlaps(&c));
-    assert!(!c.overlaps(&a));
+#[test]
+fn test_with_end() {
+    let r = SmallRange::<u32>::new(10, 20);
 
-    // b and c overlap at 10..15
-    assert!(b.overlaps(&c));
-    assert!(c.overlaps(&b));
+    assert_eq!(r.with_end(30), Some(SmallRange::new(10, 30)));
+    assert_eq!(r.with_end(10), Some(SmallRange::new(10, 10)));
+    assert_eq!(r.with_end(9), None); // before the start
+    assert_eq!(SmallRange::<u16>::new(0, 10).with_end(255), None);
+}
 
-    // a and d are far apart
-    assert!(!a.overlaps(&d));
-    assert!(!d.overlaps(&a));
+#[test]
+fn test_with_len() {
+    let r = SmallRange::<u32>::new(10, 20);
+
+    assert_eq!(r.with_len(0), Some(SmallRange::new(10, 10)));
+    assert_eq!(r.with_len(5), Some(SmallRange::new(10, 15)));
+    assert_eq!(r.with_len(65534), Some(SmallRange::new(10, 65544)));
+    assert_eq!(r.with_len(65535), None);
+    assert_eq!(r.with_len(usize::MAX), None);
 }
 
+// =============================================================================
+// set_start() / set_end() Tests
+// =============================================================================
+
 #[test]
-fn test_overlaps_adjacent() {
-    let a = SmallRange::<u32>::new(0, 10);
-    let b = SmallRange::<u32>::new(10, 20);
+fn test_set_start_and_end() {
+    let mut r = SmallRange::<u32>::new(10, 20);
 
-    // Adjacent ranges don't overlap (a.end == b.start but end is exclusive)
-    assert!(!a.overlaps(&b));
-    assert!(!b.overlaps(&a));
+    r.set_start(5);
+    assert_eq!(r, SmallRange::new(5, 20));
+
+    r.set_end(8);
+    assert_eq!(r, SmallRange::new(5, 8));
+
+    r.set_start(8);
+    assert!(r.is_empty());
 }
 
 #[test]
-fn test_overlaps_contained() {
-    let outer = SmallRange::<u32>::new(0, 100);
-    let inner = SmallRange::<u32>::new(25, 75);
+fn test_set_in_place_within_vec() {
+    let mut spans = vec![SmallRange::<u32>::new(0, 10), SmallRange::new(20, 30)];
+    for span in &mut spans {
+        span.set_end(span.end() + 1);
+    }
+    assert_eq!(spans, vec![SmallRange::new(0, 11), SmallRange::new(20, 31)]);
+}
 
-    // Contained ranges overlap
-    assert!(outer.overlaps(&inner));
-    assert!(inner.overlaps(&outer));
+#[test]
+fn test_try_set_start() {
+    let mut r = SmallRange::<u16>::new(100, 200);
+
+    assert_eq!(r.try_set_start(201), Err(SmallRangeError::StartAfterEnd));
+    assert_eq!(r, SmallRange::new(100, 200)); // unchanged on error
+
+    let mut far = SmallRange::<u16>::new(200, 400);
+    assert_eq!(far.try_set_start(260), Err(SmallRangeError::StartTooLarge));
+    assert_eq!(far.try_set_start(100), Err(SmallRangeError::LengthTooLarge));
+    assert_eq!(far, SmallRange::new(200, 400));
+
+    assert_eq!(r.try_set_start(150), Ok(()));
+    assert_eq!(r, SmallRange::new(150, 200));
 }
 
 #[test]
-fn test_overlaps_identical() {
-    let a = SmallRange::<u32>::new(10, 20);
-    let b = SmallRange::<u32>::new(10, 20);
+fn test_try_set_end() {
+    let mut r = SmallRange::<u16>::new(100, 200);
 
-    // Identical non-empty ranges overlap
-    assert!(a.overlaps(&b));
+    assert_eq!(r.try_set_end(99), Err(SmallRangeError::StartAfterEnd));
+    assert_eq!(r.try_set_end(355), Err(SmallRangeError::LengthTooLarge));
+    assert_eq!(r, SmallRange::new(100, 200)); // unchanged on error
+
+    assert_eq!(r.try_set_end(354), Ok(()));
+    assert_eq!(r, SmallRange::new(100, 354));
 }
 
+// =============================================================================
+// translate_to() Tests
+// =============================================================================
+
 #[test]
-fn test_overlaps_empty_range() {
-    let empty = SmallRange::<u32>::new(10, 10);
-    let normal = SmallRange::<u32>::new(5, 15);
+fn test_translate_to() {
+    let r = SmallRange::<u32>::new(100, 164);
 
-    // Empty range doesn't overlap with anything
-    assert!(!empty.overlaps(&normal));
-    assert!(!normal.overlaps(&empty));
+    assert_eq!(r.translate_to(0), SmallRange::new(0, 64));
+    assert_eq!(r.translate_to(100), r);
+    assert_eq!(r.translate_to(1000), SmallRange::new(1000, 1064));
 
-    // Empty range doesn't even overlap with itself
-    assert!(!empty.overlaps(&empty));
+    let empty = SmallRange::<u32>::new(5, 5);
+    assert_eq!(empty.translate_to(50), SmallRange::new(50, 50));
 }
 
 #[test]
-fn test_overlaps_single_point_shared() {
-    // These share the point at position 10
-    let a = SmallRange::<u32>::new(5, 11);
-    let b = SmallRange::<u32>::new(10, 15);
+fn test_checked_translate_to() {
+    let r = SmallRange::<u16>::new(10, 20);
 
-    assert!(a.overlaps(&b));
-    assert!(b.overlaps(&a));
+    assert_eq!(r.checked_translate_to(0), Some(SmallRange::new(0, 10)));
+    assert_eq!(r.checked_translate_to(254), Some(SmallRange::new(254, 264)));
+    assert_eq!(r.checked_translate_to(255), None); // start exceeds capacity
+    assert_eq!(r.checked_translate_to(u16::MAX), None); // storage overflow
 }
 
 // =============================================================================
@@ -486,6 +3007,48 @@ fn test_new_panics_on_length_overflow() {
     SmallRange::<u16>::new(0, 255);
 }
 
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "start+1 exceeds half-width capacity")]
+fn test_add_panics_on_capacity_overflow() {
+    let _ = SmallRange::<u16>::new(200, 210) + 100;
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "attempt to subtract with overflow")]
+fn test_sub_panics_on_underflow() {
+    let _ = SmallRange::<u32>::new(10, 20) - 11;
+}
+
+#[cfg(feature = "strict")]
+#[test]
+#[should_panic(expected = "attempt to add with overflow")]
+fn test_strict_add_overflow_panics() {
+    let _ = SmallRange::<u16>::new(1, 10) + u16::MAX;
+}
+
+#[cfg(feature = "strict")]
+#[test]
+#[should_panic(expected = "start+1 exceeds half-width capacity")]
+fn test_strict_from_start_len_max_panics() {
+    let _ = SmallRange::<u16>::from_start_len(u16::MAX, u16::MAX);
+}
+
+#[cfg(feature = "strict")]
+#[test]
+#[should_panic(expected = "attempt to add with overflow")]
+fn test_strict_collect_max_value_panics() {
+    let _: SmallRange<u8> = core::iter::once(u8::MAX).collect();
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "start must not exceed end")]
+fn test_new_const_panics_on_invalid_range() {
+    let _ = SmallRange::<u32>::new_const(20, 10);
+}
+
 // =============================================================================
 // Property-Based Tests
 // =============================================================================
@@ -566,5 +3129,395 @@ mod proptest_tests {
 
             prop_assert_eq!(a.overlaps(&b), b.overlaps(&a));
         }
+
+        #[test]
+        fn union_is_symmetric(
+            start1 in 0u32..1000,
+            len1 in 1u32..1000,
+            start2 in 0u32..1000,
+            len2 in 1u32..1000
+        ) {
+            let a = SmallRange::<u32>::new(start1, start1 + len1);
+            let b = SmallRange::<u32>::new(start2, start2 + len2);
+
+            prop_assert_eq!(a.union(&b), b.union(&a));
+        }
+
+        #[test]
+        fn hull_contains_both(
+            start1 in 0u32..1000,
+            len1 in 1u32..1000,
+            start2 in 0u32..1000,
+            len2 in 1u32..1000
+        ) {
+            let a = SmallRange::<u32>::new(start1, start1 + len1);
+            let b = SmallRange::<u32>::new(start2, start2 + len2);
+            let hull = a.hull(&b);
+
+            prop_assert_eq!(hull.start(), start1.min(start2));
+            prop_assert_eq!(hull.end(), (start1 + len1).max(start2 + len2));
+            prop_assert_eq!(hull, b.hull(&a));
+        }
+
+        #[test]
+        fn difference_matches_pointwise(
+            start1 in 0u32..100,
+            len1 in 0u32..100,
+            start2 in 0u32..100,
+            len2 in 0u32..100,
+            value in 0u32..250
+        ) {
+            let a = SmallRange::<u32>::new(start1, start1 + len1);
+            let b = SmallRange::<u32>::new(start2, start2 + len2);
+            let (left, right) = a.difference(&b).unwrap();
+
+            let in_result = left.is_some_and(|r| r.contains(value))
+                || right.is_some_and(|r| r.contains(value));
+            prop_assert_eq!(in_result, a.contains(value) && !b.contains(value));
+        }
+
+        #[test]
+        fn symmetric_difference_matches_pointwise(
+            start1 in 0u32..100,
+            len1 in 0u32..100,
+            start2 in 0u32..100,
+            len2 in 0u32..100,
+            value in 0u32..250
+        ) {
+            let a = SmallRange::<u32>::new(start1, start1 + len1);
+            let b = SmallRange::<u32>::new(start2, start2 + len2);
+            let (left, right) = a.symmetric_difference(&b).unwrap();
+
+            let in_result = left.is_some_and(|r| r.contains(value))
+                || right.is_some_and(|r| r.contains(value));
+            prop_assert_eq!(in_result, a.contains(value) != b.contains(value));
+        }
+
+        #[test]
+        fn contains_range_matches_pointwise(
+            start1 in 0u32..100,
+            len1 in 0u32..100,
+            start2 in 0u32..100,
+            len2 in 0u32..100
+        ) {
+            let a = SmallRange::<u32>::new(start1, start1 + len1);
+            let b = SmallRange::<u32>::new(start2, start2 + len2);
+
+            let expected = b.into_iter().all(|value| a.contains(value));
+            prop_assert_eq!(a.contains_range(&b), expected);
+            prop_assert_eq!(b.is_subset_of(&a), expected);
+        }
+
+        #[test]
+        fn gap_between_matches_pointwise(
+            start1 in 0u32..100,
+            len1 in 1u32..100,
+            start2 in 0u32..100,
+            len2 in 1u32..100,
+            value in 0u32..250
+        ) {
+            let a = SmallRange::<u32>::new(start1, start1 + len1);
+            let b = SmallRange::<u32>::new(start2, start2 + len2);
+            let hull = a.hull(&b);
+
+            let in_gap = a.gap_between(&b).is_some_and(|gap| gap.contains(value));
+            prop_assert_eq!(in_gap, hull.contains(value) && !a.contains(value) && !b.contains(value));
+        }
+
+        #[test]
+        fn relation_inverse_is_consistent(
+            start1 in 0u32..50,
+            len1 in 0u32..50,
+            start2 in 0u32..50,
+            len2 in 0u32..50
+        ) {
+            let a = SmallRange::<u32>::new(start1, start1 + len1);
+            let b = SmallRange::<u32>::new(start2, start2 + len2);
+
+            prop_assert_eq!(b.relation(&a), a.relation(&b).inverse());
+        }
+
+        #[test]
+        fn overlap_len_matches_pointwise(
+            start1 in 0u32..100,
+            len1 in 0u32..100,
+            start2 in 0u32..100,
+            len2 in 0u32..100
+        ) {
+            let a = SmallRange::<u32>::new(start1, start1 + len1);
+            let b = SmallRange::<u32>::new(start2, start2 + len2);
+
+            let expected = a.into_iter().filter(|&value| b.contains(value)).count();
+            prop_assert_eq!(a.overlap_len(&b), expected);
+            prop_assert_eq!(a.overlap_len(&b) > 0, a.overlaps(&b));
+        }
+
+        #[test]
+        fn cmp_value_matches_contains(start in 0u32..1000, len in 0u32..1000, value in 0u32..2000) {
+            let r = SmallRange::<u32>::new(start, start + len);
+
+            prop_assert_eq!(r.cmp_value(value) == core::cmp::Ordering::Equal, r.contains(value));
+        }
+
+        #[test]
+        fn distance_to_is_zero_iff_contained(start in 0u32..1000, len in 1u32..1000, value in 0u32..3000) {
+            let r = SmallRange::<u32>::new(start, start + len);
+
+            prop_assert_eq!(r.distance_to(value) == 0, r.contains(value));
+        }
+
+        #[test]
+        fn midpoint_is_contained(start in 0u32..65000, len in 0u32..65000) {
+            let end = start.saturating_add(len).min(65534);
+            let r = SmallRange::<u32>::new(start, end);
+
+            match r.midpoint() {
+                Some(mid) => prop_assert!(r.contains(mid)),
+                None => prop_assert!(r.is_empty()),
+            }
+        }
+
+        #[test]
+        fn iteration_matches_range(start in 0u32..1000, len in 0u32..100, n in 0usize..120) {
+            let r = SmallRange::<u32>::new(start, start + len);
+
+            prop_assert!(r.into_iter().eq(r.to_range()));
+            prop_assert!(r.into_iter().rev().eq(r.to_range().rev()));
+            prop_assert_eq!(r.into_iter().nth(n), r.to_range().nth(n));
+            prop_assert_eq!(r.into_iter().nth_back(n), r.to_range().nth_back(n));
+        }
+
+        #[test]
+        fn get_matches_iteration(start in 0u32..1000, len in 0u32..100) {
+            let r = SmallRange::<u32>::new(start, start + len);
+
+            for (i, value) in r.into_iter().enumerate() {
+                prop_assert_eq!(r.get(i), Some(value));
+            }
+            prop_assert_eq!(r.get(r.len()), None);
+        }
+
+        #[test]
+        fn position_of_inverts_get(start in 0u32..1000, len in 0u32..1000, value in 0u32..2000) {
+            let r = SmallRange::<u32>::new(start, start + len);
+
+            match r.position_of(value) {
+                Some(index) => prop_assert_eq!(r.get(index), Some(value)),
+                None => prop_assert!(!r.contains(value)),
+            }
+        }
+
+        #[test]
+        fn bisect_covers_original(start in 0u32..30000, len in 0u32..30000) {
+            let r = SmallRange::<u32>::new(start, start + len);
+            let (left, right) = r.bisect();
+
+            prop_assert_eq!(left.start(), r.start());
+            prop_assert_eq!(left.end(), right.start());
+            prop_assert_eq!(right.end(), r.end());
+            prop_assert!(right.len() - left.len() <= 1);
+        }
+
+        #[test]
+        fn partition_covers_original(start in 0u32..30000, len in 0u32..30000, parts in 1usize..100) {
+            let r = SmallRange::<u32>::new(start, start + len);
+            let pieces: Vec<_> = r.partition(parts).collect();
+
+            prop_assert_eq!(pieces.len(), parts);
+            prop_assert_eq!(pieces[0].start(), r.start());
+            prop_assert_eq!(pieces[parts - 1].end(), r.end());
+            for pair in pieces.windows(2) {
+                prop_assert_eq!(pair[0].end(), pair[1].start());
+                prop_assert!(pair[0].len() >= pair[1].len());
+                prop_assert!(pair[0].len() - pair[1].len() <= 1);
+            }
+        }
+
+        #[test]
+        fn chunks_cover_original(start in 0u32..30000, len in 0u32..30000, chunk_len in 1u32..100) {
+            let r = SmallRange::<u32>::new(start, start + len);
+            let chunks: Vec<_> = r.chunks(chunk_len).collect();
+
+            prop_assert_eq!(chunks.len(), r.chunks(chunk_len).len());
+            prop_assert_eq!(chunks.iter().map(|c| c.len()).sum::<usize>(), r.len());
+            for pair in chunks.windows(2) {
+                prop_assert_eq!(pair[0].end(), pair[1].start());
+                prop_assert_eq!(pair[0].len(), chunk_len as usize);
+            }
+
+            let mut reversed: Vec<_> = r.chunks(chunk_len).rev().collect();
+            reversed.reverse();
+            prop_assert_eq!(reversed, chunks);
+        }
+
+        #[test]
+        fn iter_step_matches_std_step_by(start in 0u32..30000, len in 0u32..1000, step in 1u32..50) {
+            let r = SmallRange::<u32>::new(start, start + len);
+            let expected: Vec<_> = (start..start + len).step_by(step as usize).collect();
+
+            prop_assert_eq!(r.iter_step(step).len(), expected.len());
+            prop_assert_eq!(r.iter_step(step).collect::<Vec<_>>(), expected.clone());
+
+            let mut reversed: Vec<_> = r.iter_step(step).rev().collect();
+            reversed.reverse();
+            prop_assert_eq!(reversed, expected);
+        }
+
+        #[test]
+        fn aligned_subrange_is_maximal(start in 0u32..1000, len in 0u32..1000, align in 1u32..200) {
+            let r = SmallRange::<u32>::new(start, start + len);
+
+            match r.aligned_subrange(align) {
+                Some(sub) => {
+                    prop_assert!(r.contains_range(&sub));
+                    prop_assert_eq!(sub.start() % align, 0);
+                    prop_assert_eq!(sub.end() % align, 0);
+                    prop_assert!(sub.start() < r.start() + align);
+                    prop_assert!(sub.end() + align > r.end());
+                }
+                None => {
+                    let first = r.start().div_ceil(align) * align;
+                    prop_assert!(first + align > r.end());
+                }
+            }
+        }
+
+        #[test]
+        fn pages_split_at_boundaries(start in 0u32..30000, len in 0u32..3000, page_size in 1u32..500) {
+            let r = SmallRange::<u32>::new(start, start + len);
+            let pieces: Vec<_> = r.pages(page_size).collect();
+
+            prop_assert_eq!(pieces.len(), r.pages(page_size).len());
+            prop_assert_eq!(pieces.iter().map(|p| p.len()).sum::<usize>(), r.len());
+            for piece in &pieces {
+                prop_assert!(!piece.is_empty());
+                prop_assert_eq!(piece.start() / page_size, (piece.end() - 1) / page_size);
+            }
+            for pair in pieces.windows(2) {
+                prop_assert_eq!(pair[0].end(), pair[1].start());
+                prop_assert_eq!(pair[0].end() % page_size, 0);
+            }
+
+            let mut reversed: Vec<_> = r.pages(page_size).rev().collect();
+            reversed.reverse();
+            prop_assert_eq!(reversed, pieces);
+        }
+
+        #[test]
+        fn checked_translate_signed_roundtrip(start in 0u32..30000, len in 0u32..30000, delta in -30000i64..30000) {
+            let r = SmallRange::<u32>::new(start, start + len);
+
+            if let Some(moved) = r.checked_translate_signed(delta) {
+                prop_assert_eq!(moved.len(), r.len());
+                prop_assert_eq!(moved.start() as i64, start as i64 + delta);
+                prop_assert_eq!(moved.checked_translate_signed(-delta), Some(r));
+            }
+        }
+
+        #[test]
+        fn widen_preserves_bounds(start in 0u16..=254, len in 0u16..=254) {
+            let narrow = SmallRange::<u16>::from_start_len(start, len);
+            let wide = narrow.widen::<u64>();
+            prop_assert_eq!(wide.start(), u64::from(narrow.start()));
+            prop_assert_eq!(wide.end(), u64::from(narrow.end()));
+        }
+
+        #[test]
+        fn narrow_roundtrip(start in 0u64..400, len in 0u64..400) {
+            let wide = SmallRange::<u64>::from_start_len(start, len);
+            match wide.try_narrow::<u16>() {
+                Some(narrow) => {
+                    prop_assert!(start <= 254 && len <= 254);
+                    prop_assert_eq!(narrow.widen::<u64>(), wide);
+                }
+                None => prop_assert!(start > 254 || len > 254),
+            }
+        }
+
+        #[test]
+        fn eq_std_range_matches_to_range(a in 0u32..1000, b in 0u32..1000, c in 0u32..1000, d in 0u32..1000) {
+            let small = SmallRange::<u32>::new(a.min(b), a.max(b));
+            let other = c.min(d)..c.max(d);
+            prop_assert_eq!(small == other, small.to_range() == other);
+            prop_assert_eq!(other == small, small == other);
+        }
+
+        #[test]
+        fn ord_matches_start_end_tuple(
+            s1 in 0u32..65534, l1 in 0u32..65534,
+            s2 in 0u32..65534, l2 in 0u32..65534,
+        ) {
+            let a = SmallRange::<u32>::from_start_len(s1, l1);
+            let b = SmallRange::<u32>::from_start_len(s2, l2);
+            prop_assert_eq!(a.cmp(&b), (a.start(), a.end()).cmp(&(b.start(), b.end())));
+        }
+
+        #[test]
+        fn sort_key_matches_ord(
+            s1 in 0u16..=254, l1 in 0u16..=254,
+            s2 in 0u16..=254, l2 in 0u16..=254,
+        ) {
+            let a = SmallRange::<u16>::from_start_len(s1, l1);
+            let b = SmallRange::<u16>::from_start_len(s2, l2);
+            prop_assert_eq!(a.sort_key().cmp(&b.sort_key()), a.cmp(&b));
+        }
+
+        #[test]
+        fn parse_display_roundtrip(start in 0u32..65534, len in 0u32..65534) {
+            let span = SmallRange::<u32>::from_start_len(start, len);
+            prop_assert_eq!(format!("{}", span).parse::<SmallRange<u32>>(), Ok(span));
+        }
+
+        #[test]
+        fn to_parts_matches_accessors(start in 0u32..65534, len in 0u32..65534) {
+            let span = SmallRange::<u32>::from_start_len(start, len);
+            prop_assert_eq!(span.to_parts(), (span.start(), span.end()));
+            prop_assert_eq!(span.to_start_len(), (start, len));
+        }
+
+        #[test]
+        fn from_bits_accepts_exactly_nonzero_halves(bits: u16) {
+            let valid = bits >> 8 != 0 && bits & 0xFF != 0;
+            match SmallRange::<u16>::from_bits(bits) {
+                Some(span) => {
+                    prop_assert!(valid);
+                    prop_assert_eq!(span.to_bits().get(), bits);
+                }
+                None => prop_assert!(!valid),
+            }
+        }
+
+        #[test]
+        fn try_new_checked_agrees_with_try_new(start: u16, end: u16) {
+            prop_assert_eq!(
+                SmallRange::<u16>::try_new_checked(start, end).ok(),
+                SmallRange::<u16>::try_new(start, end)
+            );
+        }
+
+        #[test]
+        fn new_clamped_is_exact_when_valid(a: u16, b: u16) {
+            let clamped = SmallRange::<u16>::new_clamped(a, b);
+            if let Some(exact) = SmallRange::<u16>::try_new(a.min(b), a.max(b)) {
+                prop_assert_eq!(clamped, exact);
+            }
+            prop_assert!(clamped.start() >= a.min(b).min(254));
+        }
+
+        #[test]
+        fn fits_agrees_with_try_new(start: u16, end: u16) {
+            prop_assert_eq!(
+                SmallRange::<u16>::fits(start, end),
+                SmallRange::<u16>::try_new(start, end).is_some()
+            );
+        }
+
+        #[test]
+        fn u8_roundtrip(start in 0u8..=14, len in 0u8..=14) {
+            let r = SmallRange::<u8>::from_start_len(start, len);
+            prop_assert_eq!(r.start(), start);
+            prop_assert_eq!(r.end(), start + len);
+            prop_assert_eq!(r.len(), len as usize);
+        }
     }
 }
@@ -0,0 +1,129 @@
+extern crate std;
+
+use crate::{SmallRange, SmallRect};
+use core::mem::size_of;
+
+// =============================================================================
+// Layout Tests
+// =============================================================================
+
+#[test]
+fn test_small_rect_size() {
+    assert_eq!(size_of::<SmallRect>(), 8);
+    assert_eq!(size_of::<Option<SmallRect>>(), 8);
+}
+
+// =============================================================================
+// Construction and Accessor Tests
+// =============================================================================
+
+#[test]
+fn test_small_rect_accessors() {
+    let r = SmallRect::from_origin_size(10, 20, 30, 40);
+    assert_eq!(r.x(), 10);
+    assert_eq!(r.y(), 20);
+    assert_eq!(r.width(), 30);
+    assert_eq!(r.height(), 40);
+    assert_eq!(r.area(), 1200);
+    assert_eq!(r.x_range(), SmallRange::new(10, 40));
+    assert_eq!(r.y_range(), SmallRange::new(20, 60));
+    assert!(!r.is_empty());
+    assert_eq!(
+        r,
+        SmallRect::new(SmallRange::new(10, 40), SmallRange::new(20, 60))
+    );
+
+    let max = SmallRect::from_origin_size(0, 0, 65_534, 65_534);
+    assert_eq!(max.area(), 65_534 * 65_534);
+
+    assert!(SmallRect::default().is_empty());
+    assert!(SmallRect::from_origin_size(5, 5, 10, 0).is_empty());
+}
+
+#[test]
+fn test_small_rect_try_from_origin_size() {
+    assert!(SmallRect::try_from_origin_size(65_534, 65_534, 65_534, 65_534).is_some());
+    assert!(SmallRect::try_from_origin_size(65_535, 0, 1, 1).is_none());
+    assert!(SmallRect::try_from_origin_size(0, 0, 1, 65_535).is_none());
+}
+
+#[test]
+fn test_small_rect_contains() {
+    let r = SmallRect::from_origin_size(10, 10, 5, 5);
+    assert!(r.contains(10, 10));
+    assert!(r.contains(14, 14));
+    assert!(!r.contains(15, 14));
+    assert!(!r.contains(14, 15));
+    assert!(!r.contains(9, 12));
+
+    assert!(r.contains_rect(&SmallRect::from_origin_size(11, 11, 2, 2)));
+    assert!(r.contains_rect(&r));
+    assert!(!r.contains_rect(&SmallRect::from_origin_size(11, 11, 5, 2)));
+    assert!(r.contains_rect(&SmallRect::from_origin_size(100, 100, 0, 5)));
+}
+
+#[test]
+fn test_small_rect_intersection() {
+    let a = SmallRect::from_origin_size(0, 0, 10, 10);
+
+    let b = SmallRect::from_origin_size(5, 8, 10, 10);
+    assert!(a.intersects(&b));
+    assert_eq!(
+        a.intersection(&b),
+        Some(SmallRect::from_origin_size(5, 8, 5, 2))
+    );
+    assert_eq!(a.intersection(&b), b.intersection(&a));
+
+    let inside = SmallRect::from_origin_size(2, 2, 3, 3);
+    assert_eq!(a.intersection(&inside), Some(inside));
+
+    // Overlapping on x only
+    let below = SmallRect::from_origin_size(0, 10, 10, 10);
+    assert!(!a.intersects(&below));
+    assert_eq!(a.intersection(&below), None);
+
+    // Empty rectangles intersect nothing
+    let empty = SmallRect::from_origin_size(5, 5, 0, 3);
+    assert!(!a.intersects(&empty));
+    assert_eq!(a.intersection(&empty), None);
+}
+
+#[test]
+fn test_small_rect_debug() {
+    assert_eq!(
+        std::format!("{:?}", SmallRect::from_origin_size(1, 2, 3, 4)),
+        "SmallRect { x: 1..4, y: 2..6 }"
+    );
+}
+
+// =============================================================================
+// Property-Based Tests
+// =============================================================================
+
+mod proptest_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn rect() -> impl Strategy<Value = SmallRect> {
+        (0u32..100, 0u32..100, 0u32..50, 0u32..50)
+            .prop_map(|(x, y, w, h)| SmallRect::from_origin_size(x, y, w, h))
+    }
+
+    proptest! {
+        #[test]
+        fn intersection_matches_points(a in rect(), b in rect(), x in 0u32..150, y in 0u32..150) {
+            let both = a.contains(x, y) && b.contains(x, y);
+            match a.intersection(&b) {
+                Some(i) => {
+                    prop_assert!(a.intersects(&b));
+                    prop_assert_eq!(i.contains(x, y), both);
+                    prop_assert!(a.contains_rect(&i) && b.contains_rect(&i));
+                }
+                None => {
+                    prop_assert!(!a.intersects(&b));
+                    prop_assert!(!both);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,60 @@
+extern crate std;
+
+use crate::{SmallRange, Spanned};
+use core::mem::size_of;
+use std::string::String;
+
+// =============================================================================
+// Layout Tests
+// =============================================================================
+
+#[test]
+fn test_spanned_size() {
+    assert_eq!(size_of::<Spanned<u32>>(), 8);
+    assert_eq!(size_of::<Option<Spanned<u32>>>(), 8);
+    assert_eq!(size_of::<Spanned<u8, u16>>(), 4);
+    assert_eq!(size_of::<Option<Spanned<(), u64>>>(), 8);
+}
+
+// =============================================================================
+// Accessor and Combinator Tests
+// =============================================================================
+
+#[test]
+fn test_spanned_accessors() {
+    let mut s = Spanned::new(42u8, SmallRange::<u32>::new(10, 12));
+    assert_eq!(s.span(), SmallRange::new(10, 12));
+    assert_eq!(*s.value(), 42);
+    *s.value_mut() += 1;
+    assert_eq!(s.into_parts(), (43, SmallRange::new(10, 12)));
+    assert_eq!(s.into_inner(), 43);
+
+    let moved = s.with_span(SmallRange::new(0, 2));
+    assert_eq!(moved.span(), SmallRange::new(0, 2));
+    assert_eq!(*moved.value(), 43);
+
+    assert_eq!(Spanned::from((43u8, SmallRange::new(0, 2))), moved);
+}
+
+#[test]
+fn test_spanned_map_and_borrow() {
+    let mut name = Spanned::new(String::from("main"), SmallRange::<u32>::new(3, 7));
+
+    let borrowed = name.as_ref();
+    assert_eq!(borrowed.value().len(), 4);
+    assert_eq!(borrowed.span(), SmallRange::new(3, 7));
+
+    name.as_mut().into_inner().push('!');
+    assert_eq!(name.value(), "main!");
+
+    let len = name.map(|s| s.len());
+    assert_eq!(len, Spanned::new(5, SmallRange::new(3, 7)));
+}
+
+#[test]
+fn test_spanned_debug() {
+    assert_eq!(
+        std::format!("{:?}", Spanned::new('x', SmallRange::<u32>::new(1, 2))),
+        "Spanned { value: 'x', span: SmallRange { start: 1, end: 2 } }"
+    );
+}
@@ -0,0 +1,72 @@
+extern crate std;
+
+use crate::SmallRange;
+use speedy::{Endianness, Readable, Writable};
+
+// =============================================================================
+// Round-Trip Tests
+// =============================================================================
+
+#[test]
+fn test_speedy_roundtrip() {
+    for endianness in [Endianness::LittleEndian, Endianness::BigEndian] {
+        let range = SmallRange::<u32>::new(10, 20);
+        let bytes = range.write_to_vec_with_ctx(endianness).unwrap();
+        let decoded = SmallRange::<u32>::read_from_buffer_with_ctx(endianness, &bytes).unwrap();
+        assert_eq!(decoded, range);
+
+        let range = SmallRange::<u64>::new(1 << 30, (1 << 30) + 5);
+        let bytes = range.write_to_vec_with_ctx(endianness).unwrap();
+        let decoded = SmallRange::<u64>::read_from_buffer_with_ctx(endianness, &bytes).unwrap();
+        assert_eq!(decoded, range);
+
+        let range = SmallRange::<u8>::new(0, 0);
+        let bytes = range.write_to_vec_with_ctx(endianness).unwrap();
+        let decoded = SmallRange::<u8>::read_from_buffer_with_ctx(endianness, &bytes).unwrap();
+        assert_eq!(decoded, range);
+    }
+}
+
+#[test]
+fn test_speedy_writes_packed_bits() {
+    let range = SmallRange::<u32>::new(10, 20);
+    let bits = range.to_bits().get();
+    assert_eq!(
+        range
+            .write_to_vec_with_ctx(Endianness::LittleEndian)
+            .unwrap(),
+        bits.to_le_bytes()
+    );
+    assert_eq!(
+        range.write_to_vec_with_ctx(Endianness::BigEndian).unwrap(),
+        bits.to_be_bytes()
+    );
+    assert_eq!(Writable::<Endianness>::bytes_needed(&range).unwrap(), 4);
+}
+
+#[test]
+fn test_speedy_in_collection() {
+    let ranges = std::vec![SmallRange::<u16>::new(1, 4), SmallRange::new(7, 9)];
+    let bytes = ranges.write_to_vec_with_ctx(Endianness::BigEndian).unwrap();
+    let decoded: std::vec::Vec<SmallRange<u16>> =
+        Readable::read_from_buffer_with_ctx(Endianness::BigEndian, &bytes).unwrap();
+    assert_eq!(decoded, ranges);
+}
+
+// =============================================================================
+// Validation Tests
+// =============================================================================
+
+#[test]
+fn test_speedy_rejects_invalid_encoding() {
+    for bits in [0u32, 11 << 16, 11] {
+        let result = SmallRange::<u32>::read_from_buffer_with_ctx(
+            Endianness::LittleEndian,
+            &bits.to_le_bytes(),
+        );
+        assert!(result.is_err());
+    }
+    assert!(
+        SmallRange::<u32>::read_from_buffer_with_ctx(Endianness::LittleEndian, &[1, 2]).is_err()
+    );
+}
@@ -0,0 +1,57 @@
+use crate::SmallRange;
+use proptest::prelude::*;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+
+proptest! {
+    #[test]
+    fn any_is_valid(range in any::<SmallRange<u32>>()) {
+        prop_assert!(SmallRange::<u32>::fits(range.start(), range.end()));
+    }
+
+    #[test]
+    fn arbitrary_covers_u8(range in SmallRange::<u8>::arbitrary()) {
+        prop_assert!(range.start() <= SmallRange::<u8>::MAX_START);
+        prop_assert!(range.len() <= SmallRange::<u8>::MAX_LEN as usize);
+    }
+
+    #[test]
+    fn within_stays_in_bounds(range in SmallRange::<u64>::strategy_within(1_000..1_010)) {
+        prop_assert!(range.start() >= 1_000);
+        prop_assert!(range.end() <= 1_010);
+    }
+
+    #[test]
+    fn within_clamps_to_capacity(range in SmallRange::<u16>::strategy_within(0..u16::MAX)) {
+        prop_assert!(SmallRange::<u16>::fits(range.start(), range.end()));
+    }
+
+    #[test]
+    fn max_len_is_respected(range in SmallRange::<u32>::strategy_with_max_len(4)) {
+        prop_assert!(range.len() <= 4);
+    }
+}
+
+#[test]
+fn test_within_empty_bounds() {
+    let mut runner = TestRunner::deterministic();
+    let strategy = SmallRange::<u32>::strategy_within(7..7);
+    for _ in 0..16 {
+        let range = strategy.new_tree(&mut runner).unwrap().current();
+        assert_eq!(range, SmallRange::new(7, 7));
+    }
+}
+
+#[test]
+fn test_arbitrary_shrinks_toward_origin() {
+    let mut runner = TestRunner::deterministic();
+    let mut tree = any::<SmallRange<u32>>().new_tree(&mut runner).unwrap();
+    while tree.simplify() {}
+    assert_eq!(tree.current(), SmallRange::new(0, 0));
+}
+
+#[test]
+#[should_panic(expected = "bounds start exceeds half-width capacity")]
+fn test_within_rejects_start_beyond_capacity() {
+    let _ = SmallRange::<u8>::strategy_within(200..250);
+}
@@ -0,0 +1,64 @@
+use crate::{SmallRange, SmallRangeError};
+use text_size::{TextRange, TextSize};
+
+fn text(start: u32, end: u32) -> TextRange {
+    TextRange::new(TextSize::from(start), TextSize::from(end))
+}
+
+#[test]
+fn test_into_text_range() {
+    assert_eq!(TextRange::from(SmallRange::<u8>::new(1, 5)), text(1, 5));
+    assert_eq!(
+        TextRange::from(SmallRange::<u16>::new(254, 508)),
+        text(254, 508)
+    );
+    assert_eq!(
+        TextRange::from(SmallRange::<u32>::new(65_534, 131_068)),
+        text(65_534, 131_068)
+    );
+}
+
+#[test]
+fn test_from_text_range() {
+    assert_eq!(
+        SmallRange::<u32>::try_from(text(10, 20)),
+        Ok(SmallRange::new(10, 20))
+    );
+    assert_eq!(
+        SmallRange::<u64>::try_from(text(0, u32::MAX)),
+        Err(SmallRangeError::LengthTooLarge)
+    );
+    assert_eq!(
+        SmallRange::<u64>::try_from(text(1 << 31, u32::MAX)),
+        Ok(SmallRange::new(1 << 31, u32::MAX as u64))
+    );
+    assert_eq!(
+        SmallRange::<usize>::try_from(text(3, 3)),
+        Ok(SmallRange::new(3, 3))
+    );
+
+    assert_eq!(
+        SmallRange::<u8>::try_from(text(15, 15)),
+        Err(SmallRangeError::StartTooLarge)
+    );
+    assert_eq!(
+        SmallRange::<u8>::try_from(text(300, 300)),
+        Err(SmallRangeError::StartTooLarge)
+    );
+    assert_eq!(
+        SmallRange::<u8>::try_from(text(0, 300)),
+        Err(SmallRangeError::LengthTooLarge)
+    );
+    assert_eq!(
+        SmallRange::<u16>::try_from(text(0, 255)),
+        Err(SmallRangeError::LengthTooLarge)
+    );
+}
+
+#[test]
+fn test_text_range_roundtrip() {
+    for (start, end) in [(0, 0), (5, 9), (100, 354)] {
+        let small = SmallRange::<u16>::try_from(text(start, end)).unwrap();
+        assert_eq!(TextRange::from(small), text(start, end));
+    }
+}
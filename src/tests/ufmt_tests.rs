@@ -0,0 +1,38 @@
+extern crate std;
+
+use crate::SmallRange;
+use std::string::String;
+use ufmt::uwrite;
+
+#[test]
+fn test_ufmt_display() {
+    let mut s = String::new();
+    uwrite!(s, "{}", SmallRange::<u32>::new(10, 20)).unwrap();
+    assert_eq!(s, "10..20");
+
+    let mut s = String::new();
+    uwrite!(s, "{}", SmallRange::<u8>::new(0, 0)).unwrap();
+    assert_eq!(s, "0..0");
+}
+
+#[test]
+fn test_ufmt_display_matches_core_fmt() {
+    for range in [
+        SmallRange::<u64>::new(0, 1),
+        SmallRange::new(1 << 20, (1 << 20) + 7),
+        SmallRange::new(SmallRange::<u64>::MAX_START, SmallRange::<u64>::MAX_START),
+    ] {
+        let mut s = String::new();
+        uwrite!(s, "{}", range).unwrap();
+        assert_eq!(s, std::format!("{}", range));
+    }
+}
+
+#[test]
+fn test_ufmt_debug() {
+    let range = SmallRange::<u16>::new(1, 4);
+    let mut s = String::new();
+    uwrite!(s, "{:?}", range).unwrap();
+    assert_eq!(s, "SmallRange { start: 1, end: 4 }");
+    assert_eq!(s, std::format!("{:?}", range));
+}
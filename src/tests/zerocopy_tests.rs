@@ -0,0 +1,72 @@
+use crate::SmallRange;
+use zerocopy::{Immutable, IntoBytes, KnownLayout, TryFromBytes};
+
+#[derive(Debug, TryFromBytes, IntoBytes, Immutable, KnownLayout)]
+#[repr(C)]
+struct Header {
+    magic: u32,
+    span: SmallRange<u32>,
+}
+
+// =============================================================================
+// IntoBytes Tests
+// =============================================================================
+
+#[test]
+fn test_zerocopy_as_bytes() {
+    let range = SmallRange::<u32>::new(10, 20);
+    assert_eq!(range.as_bytes(), &range.to_bits().get().to_ne_bytes());
+
+    let ranges = [SmallRange::<u16>::new(1, 4), SmallRange::new(7, 9)];
+    assert_eq!(ranges.as_bytes().len(), 4);
+}
+
+// =============================================================================
+// TryFromBytes Tests
+// =============================================================================
+
+#[test]
+fn test_zerocopy_try_from_bytes_roundtrip() {
+    let range = SmallRange::<u64>::new(1 << 30, (1 << 30) + 5);
+    let bytes = range.to_bits().get().to_ne_bytes();
+    assert_eq!(
+        SmallRange::<u64>::try_read_from_bytes(&bytes).unwrap(),
+        range
+    );
+    assert_eq!(
+        *SmallRange::<u64>::try_ref_from_bytes(&bytes[..]).unwrap(),
+        range
+    );
+}
+
+#[test]
+fn test_zerocopy_rejects_zero() {
+    assert!(SmallRange::<u32>::try_read_from_bytes(&[0; 4]).is_err());
+    assert!(SmallRange::<u32>::try_read_from_bytes(&[1; 3]).is_err());
+}
+
+#[test]
+fn test_zerocopy_header() {
+    let header = Header {
+        magic: 0xC0FF_EE00,
+        span: SmallRange::new(100, 164),
+    };
+    let bytes = header.as_bytes();
+    assert_eq!(bytes.len(), 8);
+    let parsed = Header::try_ref_from_bytes(bytes).unwrap();
+    assert_eq!(parsed.magic, 0xC0FF_EE00);
+    assert_eq!(parsed.span, SmallRange::new(100, 164));
+
+    let mut corrupt = [0u8; 8];
+    corrupt[..4].copy_from_slice(&header.magic.to_ne_bytes());
+    assert!(Header::try_ref_from_bytes(&corrupt[..]).is_err());
+}
+
+#[test]
+fn test_zerocopy_only_checks_niche() {
+    // A zero length half is non-zero overall, so zerocopy accepts it; the
+    // README points untrusted input at `from_bits` instead
+    let bits = 11u32 << 16;
+    assert!(SmallRange::<u32>::try_read_from_bytes(&bits.to_ne_bytes()).is_ok());
+    assert!(SmallRange::<u32>::from_bits(bits).is_none());
+}
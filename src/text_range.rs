@@ -0,0 +1,62 @@
+//! Conversions between `SmallRange` and text-size's `TextRange`.
+//!
+//! `TextSize` converts to and from `u32` in text-size itself, so these impls
+//! only cover the ranges.
+
+use num_traits::{cast, AsPrimitive};
+use text_size::{TextRange, TextSize};
+
+use crate::{SmallRange, SmallRangeError, SmallRangeStorage};
+
+macro_rules! impl_into_text_range {
+    ($($t:ty),*) => {
+        $(
+            /// Converts to a `TextRange`. Every range with this storage fits.
+            impl From<SmallRange<$t>> for TextRange {
+                #[inline]
+                fn from(range: SmallRange<$t>) -> Self {
+                    TextRange::new(
+                        TextSize::from(u32::from(range.start())),
+                        TextSize::from(u32::from(range.end())),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_into_text_range!(u8, u16, u32);
+
+/// Converts a `TextRange`, checking the capacity of the storage type.
+///
+/// # Errors
+/// Returns [`SmallRangeError::StartTooLarge`] or
+/// [`SmallRangeError::LengthTooLarge`] if the range doesn't fit.
+///
+/// # Examples
+/// ```
+/// use small_range::{SmallRange, SmallRangeError};
+/// use text_size::{TextRange, TextSize};
+///
+/// let text = TextRange::new(TextSize::from(4), TextSize::from(9));
+/// let small = SmallRange::<u32>::try_from(text).unwrap();
+/// assert_eq!(small.to_range(), 4..9);
+/// assert_eq!(TextRange::from(small), text);
+///
+/// let long = TextRange::up_to(TextSize::from(70_000));
+/// assert_eq!(SmallRange::<u32>::try_from(long), Err(SmallRangeError::LengthTooLarge));
+/// ```
+impl<T: SmallRangeStorage> TryFrom<TextRange> for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    type Error = SmallRangeError;
+
+    fn try_from(range: TextRange) -> Result<Self, Self::Error> {
+        let start: T = cast(u32::from(range.start())).ok_or(SmallRangeError::StartTooLarge)?;
+        // With the start in range, an end too wide for T means a length too
+        // large for it as well
+        let end: T = cast(u32::from(range.end())).ok_or(SmallRangeError::LengthTooLarge)?;
+        SmallRange::try_new_checked(start, end)
+    }
+}
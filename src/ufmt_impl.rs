@@ -0,0 +1,31 @@
+//! ufmt `uDisplay` and `uDebug` for `SmallRange`.
+//!
+//! These mirror the `core::fmt` impls: `uDisplay` writes `start..end` and
+//! `uDebug` writes `SmallRange { start: 1, end: 4 }`, without pulling in
+//! `core::fmt`.
+
+use num_traits::AsPrimitive;
+use ufmt::{uDebug, uDisplay, uWrite, uwrite, Formatter};
+
+use crate::{SmallRange, SmallRangeStorage};
+
+impl<T: SmallRangeStorage + uDisplay> uDisplay for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        uwrite!(f, "{}..{}", self.start(), self.end())
+    }
+}
+
+impl<T: SmallRangeStorage + uDebug> uDebug for SmallRange<T>
+where
+    usize: AsPrimitive<T>,
+{
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.debug_struct("SmallRange")?
+            .field("start", &self.start())?
+            .field("end", &self.end())?
+            .finish()
+    }
+}